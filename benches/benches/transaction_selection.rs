@@ -0,0 +1,123 @@
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use fuel_core::txpool::{
+    transaction_selector::{
+        FifoStrategy,
+        GasPriceStrategy,
+        KnapsackStrategy,
+        PriorityLanesStrategy,
+        TransactionSelectionStrategy,
+    },
+    TxInfo,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::{
+        ConsensusParameters,
+        Output,
+        TransactionBuilder,
+    },
+    fuel_types::Word,
+    fuel_vm::checked_transaction::builder::TransactionBuilderExt,
+    services::txpool::ArcPoolTx,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+
+const TXS: usize = 2000;
+const BLOCK_GAS_LIMIT: Word = 10_000_000;
+
+/// Generates the pool with random gas prices, limits and arrival times,
+/// which needs several blocks to be included.
+fn pool(rng: &mut StdRng) -> Vec<TxInfo> {
+    let params = ConsensusParameters {
+        gas_price_factor: 1,
+        ..ConsensusParameters::default()
+    };
+    (0..TXS)
+        .map(|_| {
+            let tx = TransactionBuilder::script(
+                vec![op::ret(RegId::ONE)].into_iter().collect(),
+                vec![],
+            )
+            .gas_price(rng.gen_range(1..100))
+            .gas_limit(rng.gen_range(1_000..50_000))
+            .add_unsigned_coin_input(
+                rng.gen(),
+                rng.gen(),
+                1_000_000_000,
+                Default::default(),
+                Default::default(),
+                0,
+            )
+            .add_output(Output::Change {
+                to: Default::default(),
+                amount: 0,
+                asset_id: Default::default(),
+            })
+            .finalize_checked_basic(0, &params)
+            .into();
+            let submitted_time = Duration::from_millis(rng.gen_range(0..1_000_000));
+            TxInfo::with_submitted_time(Arc::new(tx), submitted_time)
+        })
+        .collect()
+}
+
+/// The share of the block gas used by the selected transactions and the sum of their fees.
+fn block_stats(selected: &[ArcPoolTx]) -> (f64, u128) {
+    let gas: Word = selected.iter().map(|tx| tx.max_gas()).sum();
+    let fees = selected
+        .iter()
+        .map(|tx| tx.price() as u128 * tx.max_gas() as u128)
+        .sum();
+    (gas as f64 / BLOCK_GAS_LIMIT as f64, fees)
+}
+
+fn transaction_selection(c: &mut Criterion) {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let txs = pool(rng);
+    // Every tenth transaction belongs to the whitelisted sender.
+    let senders = txs
+        .iter()
+        .step_by(10)
+        .filter_map(|info| info.tx().inputs()[0].input_owner().copied())
+        .collect();
+
+    let strategies: Vec<(&str, Box<dyn TransactionSelectionStrategy>)> = vec![
+        ("gas_price", Box::new(GasPriceStrategy)),
+        ("fifo", Box::new(FifoStrategy)),
+        ("knapsack", Box::new(KnapsackStrategy::default())),
+        (
+            "priority_lanes",
+            Box::new(PriorityLanesStrategy { senders }),
+        ),
+    ];
+
+    let mut group = c.benchmark_group("transaction_selection");
+    for (name, strategy) in strategies.iter() {
+        let (fill, fees) = block_stats(&strategy.select(txs.clone(), BLOCK_GAS_LIMIT));
+        println!("{name}: block fill {:.2}%, fees {fees}", fill * 100.0);
+
+        group.bench_function(*name, |b| {
+            b.iter(|| black_box(strategy.select(txs.clone(), BLOCK_GAS_LIMIT)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, transaction_selection);
+criterion_main!(benches);
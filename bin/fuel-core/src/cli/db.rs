@@ -0,0 +1,313 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use std::path::PathBuf;
+
+/// Manage the database of the node.
+#[derive(Debug, Parser)]
+pub struct Command {
+    #[clap(subcommand)]
+    pub command: DbCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum DbCommand {
+    Checkpoint(CheckpointCommand),
+    Restore(RestoreCommand),
+    Migrate(MigrateCommand),
+    Verify(VerifyCommand),
+    Stats(StatsCommand),
+}
+
+/// Create a checkpoint of the database. The database should not be used by a running node;
+/// use the `createCheckpoint` GraphQL mutation to create a checkpoint of the running node.
+#[derive(Debug, Clone, Parser)]
+pub struct CheckpointCommand {
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    pub database_path: PathBuf,
+
+    /// The directory of the checkpoint. It should not exist.
+    #[clap(name = "CHECKPOINT_PATH", long = "output", value_parser)]
+    pub checkpoint_path: PathBuf,
+}
+
+/// Restore the database from a checkpoint.
+#[derive(Debug, Clone, Parser)]
+pub struct RestoreCommand {
+    /// The directory of the checkpoint created by `fuel-core db checkpoint`
+    /// or by the `createCheckpoint` GraphQL mutation.
+    #[clap(name = "CHECKPOINT_PATH", long = "checkpoint", value_parser)]
+    pub checkpoint_path: PathBuf,
+
+    /// The directory of the restored database. It should be empty or not exist.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    pub database_path: PathBuf,
+}
+
+/// Migrate the database to the version supported by this build of fuel-core.
+/// The node migrates the database on start, this command allows to do it in advance
+/// or to check the pending migrations with `--dry-run`.
+#[derive(Debug, Clone, Parser)]
+pub struct MigrateCommand {
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    pub database_path: PathBuf,
+
+    /// Apply the pending migrations without committing them.
+    #[clap(long = "dry-run")]
+    pub dry_run: bool,
+}
+
+/// Check that the indexes and the merkle roots stored in the database match the data.
+/// The database should not be used by a running node.
+#[derive(Debug, Clone, Parser)]
+pub struct VerifyCommand {
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    pub database_path: PathBuf,
+
+    /// Rebuild the inconsistent indexes and merkle roots from the data.
+    #[clap(long = "repair")]
+    pub repair: bool,
+}
+
+/// Print the number of keys and the size of each column of the database.
+/// The database is opened read-only, so it can be used by a running node.
+#[derive(Debug, Clone, Parser)]
+pub struct StatsCommand {
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    pub database_path: PathBuf,
+
+    /// Print the internal statistics of RocksDB for each column.
+    #[clap(long = "internal")]
+    pub internal: bool,
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    match command.command {
+        DbCommand::Checkpoint(command) => checkpoint(command),
+        DbCommand::Restore(command) => restore(command),
+        DbCommand::Migrate(command) => migrate(command),
+        DbCommand::Verify(command) => verify(command),
+        DbCommand::Stats(command) => stats(command),
+    }
+}
+
+#[cfg(not(feature = "rocksdb"))]
+fn checkpoint(command: CheckpointCommand) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(feature = "rocksdb")]
+fn checkpoint(command: CheckpointCommand) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::database::Database;
+
+    let path = command.database_path;
+    let db = Database::open(&path).context(format!(
+        "failed to open database at path {}",
+        path.display()
+    ))?;
+
+    db.checkpoint(&command.checkpoint_path).context(format!(
+        "failed to create checkpoint at path {}",
+        command.checkpoint_path.display()
+    ))?;
+    tracing::info!(
+        "Created the checkpoint of the database at {}",
+        command.checkpoint_path.display()
+    );
+    Ok(())
+}
+
+#[cfg(not(feature = "rocksdb"))]
+fn restore(command: RestoreCommand) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(feature = "rocksdb")]
+fn restore(command: RestoreCommand) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::database::Database;
+
+    let db =
+        Database::restore_checkpoint(&command.checkpoint_path, &command.database_path)
+            .context(format!(
+                "failed to restore database from checkpoint {}",
+                command.checkpoint_path.display()
+            ))?;
+    let height = db.latest_height()?;
+    tracing::info!(
+        "Restored the database at {} with the latest block height {}",
+        command.database_path.display(),
+        height
+    );
+    Ok(())
+}
+
+#[cfg(not(feature = "rocksdb"))]
+fn migrate(command: MigrateCommand) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(feature = "rocksdb")]
+fn migrate(command: MigrateCommand) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::database::Database;
+
+    let path = command.database_path;
+    let db = Database::open(&path).context(format!(
+        "failed to open database at path {}",
+        path.display()
+    ))?;
+
+    let version = db.version()?;
+    let migrations = db
+        .migrate(command.dry_run)
+        .context("failed to migrate the database")?;
+    if migrations.is_empty() {
+        tracing::info!(
+            "The database at {} is up to date, version {:?}",
+            path.display(),
+            version
+        );
+    }
+    for migration in migrations {
+        tracing::info!(
+            "{} the migration to the version {:#x}: {}",
+            if command.dry_run {
+                "Checked"
+            } else {
+                "Applied"
+            },
+            migration.version,
+            migration.description
+        );
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "rocksdb"))]
+fn verify(command: VerifyCommand) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(feature = "rocksdb")]
+fn verify(command: VerifyCommand) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::database::Database;
+
+    let path = command.database_path;
+    let db = Database::open(&path).context(format!(
+        "failed to open database at path {}",
+        path.display()
+    ))?;
+
+    let report = db
+        .verify(command.repair)
+        .context("failed to verify the database")?;
+    for inconsistency in &report.inconsistencies {
+        tracing::warn!("{}", inconsistency);
+    }
+    if report.is_consistent() {
+        tracing::info!("The database at {} is consistent", path.display());
+        return Ok(())
+    }
+
+    let unrepaired = report.inconsistencies.len() - report.repaired;
+    if unrepaired > 0 {
+        return Err(anyhow::anyhow!(
+            "Found {} inconsistencies in the database at {}, {} of them are not repaired",
+            report.inconsistencies.len(),
+            path.display(),
+            unrepaired
+        ))
+    }
+    tracing::info!(
+        "Repaired {} inconsistencies in the database at {}",
+        report.repaired,
+        path.display()
+    );
+    Ok(())
+}
+
+#[cfg(not(feature = "rocksdb"))]
+fn stats(command: StatsCommand) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(feature = "rocksdb")]
+fn stats(command: StatsCommand) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::database::Database;
+
+    let path = command.database_path;
+    let db = Database::open_read_only(&path).context(format!(
+        "failed to open database at path {}",
+        path.display()
+    ))?;
+
+    let stats = db
+        .stats()
+        .context("failed to collect the database statistics")?;
+    println!(
+        "{:<40} {:>16} {:>16}",
+        "column", "keys (estimated)", "bytes"
+    );
+    for (column, column_stats) in &stats {
+        println!(
+            "{:<40} {:>16} {:>16}",
+            column.to_string(),
+            column_stats.keys,
+            column_stats.bytes
+        );
+    }
+    if command.internal {
+        for (column, column_stats) in stats {
+            if let Some(internal) = column_stats.internal {
+                println!("\n{column}:\n{internal}");
+            }
+        }
+    }
+    Ok(())
+}
@@ -55,8 +55,11 @@ pub const CONSENSUS_KEY_ENV: &str = "CONSENSUS_KEY_SECRET";
 mod p2p;
 
 mod consensus;
+mod graphql;
 #[cfg(feature = "relayer")]
 mod relayer;
+mod rocksdb;
+mod tx_selection;
 
 /// Run the Fuel client node locally.
 #[derive(Debug, Clone, Parser)]
@@ -67,6 +70,23 @@ pub struct Command {
     #[clap(long = "port", default_value = "4000", env)]
     pub port: u16,
 
+    /// The limits of the GraphQL API.
+    #[clap(flatten)]
+    pub graphql_args: graphql::GraphQLArgs,
+
+    /// The bearer token required by the admin GraphQL API at `/admin/graphql`, which serves
+    /// the block production, debugger and checkpoint operations. Without the token
+    /// the admin API is served only on the `--admin-addr`.
+    /// Setting via the `ADMIN_TOKEN` ENV var is preferred.
+    #[arg(long = "admin-token", env)]
+    pub admin_token: Option<String>,
+
+    /// The address of the separate listener serving only the admin GraphQL API.
+    /// The admin token isn't required on it unless `--admin-token` is set. Without the token,
+    /// only the loopback address is allowed. If neither is set, the admin API is disabled.
+    #[arg(long = "admin-addr", env)]
+    pub admin_addr: Option<net::SocketAddr>,
+
     #[clap(
         name = "DB_PATH",
         long = "db-path",
@@ -85,6 +105,35 @@ pub struct Command {
     )]
     pub database_type: DbType,
 
+    /// The tuning of the RocksDB database.
+    #[clap(flatten)]
+    pub rocksdb_args: rocksdb::RocksDbArgs,
+
+    /// The number of the latest blocks to keep in the database.
+    /// Older blocks with their transactions and receipts are pruned.
+    /// The state and the block merkle data are always kept.
+    /// If not set, all blocks are kept.
+    #[arg(long = "blocks-retention", value_parser = clap::value_parser!(u32).range(1..), env)]
+    pub blocks_retention: Option<u32>,
+
+    /// Record the history of the contracts, coins and messages state for each block,
+    /// so the balances can be queried at past block heights.
+    /// The history is available only for blocks imported with this option enabled.
+    #[arg(long = "state-history", env)]
+    pub state_history: bool,
+
+    /// The directory where the database checkpoints are created by the `createCheckpoint`
+    /// GraphQL mutation. If not set, the checkpoints of the running node are disabled.
+    #[arg(long = "checkpoints-dir", value_parser, env)]
+    pub checkpoints_dir: Option<PathBuf>,
+
+    /// Serve the queries from the database of another node running at the same `db-path`.
+    /// The database is opened as a read-only RocksDB secondary instance that periodically
+    /// catches up with the node. Block production, block import and transaction submission
+    /// are disabled.
+    #[arg(long = "read-only-db", env)]
+    pub read_only_db: bool,
+
     /// Specify either an alias to a built-in configuration or filepath to a JSON file.
     #[arg(
         name = "CHAIN_CONFIG",
@@ -94,6 +143,12 @@ pub struct Command {
     )]
     pub chain_config: String,
 
+    /// The directory of the snapshot created by `fuel-core snapshot --output-dir`.
+    /// The genesis state is imported from the snapshot chunk by chunk
+    /// instead of the initial state of the chain config.
+    #[arg(long = "genesis-snapshot", value_parser, env)]
+    pub genesis_snapshot: Option<PathBuf>,
+
     /// Allows GraphQL Endpoints to arbitrarily advanced blocks. Should be used for local development only
     #[arg(long = "manual_blocks_enabled", env)]
     pub manual_blocks_enabled: bool,
@@ -153,6 +208,40 @@ pub struct Command {
 
     #[clap(long = "tx-pool-ttl", default_value = "5m", env)]
     pub tx_pool_ttl: humantime::Duration,
+
+    /// Journal the transactions accepted by the transaction pool in the database,
+    /// so the pending transactions are restored after the restart.
+    #[clap(long = "tx-pool-journal", env)]
+    pub tx_pool_journal: bool,
+
+    /// The maximum number of pending transactions spending the coins or messages
+    /// of one owner. Not limited if not set.
+    #[clap(long = "tx-pool-max-per-owner", env)]
+    pub tx_pool_max_per_owner: Option<usize>,
+
+    /// The maximum number of pending transactions gossiped by one P2P peer.
+    /// Not limited if not set.
+    #[clap(long = "tx-pool-max-per-peer", env)]
+    pub tx_pool_max_per_peer: Option<usize>,
+
+    /// The maximum number of pending transactions submitted by one GraphQL client,
+    /// identified by the API key from `--api-keys` or by the IP address. Not limited if not set.
+    #[clap(long = "tx-pool-max-per-client", env)]
+    pub tx_pool_max_per_client: Option<usize>,
+
+    /// The minimum increase of the gas price, in percent, required to replace
+    /// the pending transaction spending the same inputs.
+    #[clap(long = "tx-pool-min-price-bump", default_value = "0", env)]
+    pub tx_pool_min_price_bump: u64,
+
+    /// The maximum number of pending transactions, including the dependent ones,
+    /// evicted by one replacement.
+    #[clap(long = "tx-pool-max-evicted", default_value = "100", env)]
+    pub tx_pool_max_evicted: usize,
+
+    /// The selection of the transactions for the next block.
+    #[clap(flatten)]
+    pub tx_selection_args: tx_selection::TxSelectionArgs,
 }
 
 impl Command {
@@ -160,9 +249,18 @@ impl Command {
         let Command {
             ip,
             port,
+            graphql_args,
+            admin_token,
+            admin_addr,
             database_path,
             database_type,
+            rocksdb_args,
+            blocks_retention,
+            state_history,
+            checkpoints_dir,
+            read_only_db,
             chain_config,
+            genesis_snapshot,
             vm_backtrace,
             manual_blocks_enabled,
             utxo_validation,
@@ -181,6 +279,13 @@ impl Command {
             max_da_lag,
             max_wait_time,
             tx_pool_ttl,
+            tx_pool_journal,
+            tx_pool_max_per_owner,
+            tx_pool_max_per_peer,
+            tx_pool_max_per_client,
+            tx_pool_min_price_bump,
+            tx_pool_max_evicted,
+            tx_selection_args,
         } = self;
 
         let addr = net::SocketAddr::new(ip, port);
@@ -237,22 +342,40 @@ impl Command {
 
         Ok(Config {
             addr,
+            api_limits: graphql_args.into(),
+            admin_token: admin_token.map(Secret::new),
+            admin_addr,
             database_path,
             database_type,
+            rocksdb: rocksdb_args.into(),
+            blocks_retention,
+            state_history,
+            checkpoints_dir,
+            read_only_db,
             chain_conf: chain_conf.clone(),
+            genesis_snapshot,
             utxo_validation,
             manual_blocks_enabled,
             block_production: trigger,
             vm: VMConfig {
                 backtrace: vm_backtrace,
             },
-            txpool: TxPoolConfig::new(
-                chain_conf,
-                min_gas_price,
-                utxo_validation,
-                metrics,
-                tx_pool_ttl.into(),
-            ),
+            txpool: TxPoolConfig {
+                journal: tx_pool_journal,
+                selection_strategy: tx_selection_args.into(),
+                max_txs_per_owner: tx_pool_max_per_owner,
+                max_txs_per_peer: tx_pool_max_per_peer,
+                max_txs_per_client: tx_pool_max_per_client,
+                min_price_bump_percent: tx_pool_min_price_bump,
+                max_evicted_by_replacement: tx_pool_max_evicted,
+                ..TxPoolConfig::new(
+                    chain_conf,
+                    min_gas_price,
+                    utxo_validation,
+                    metrics,
+                    tx_pool_ttl.into(),
+                )
+            },
             block_producer: ProducerConfig {
                 utxo_validation,
                 coinbase_recipient,
@@ -0,0 +1,155 @@
+//! Clap configuration related to the limits of the GraphQL API
+
+use clap::Args;
+use fuel_core::service::config::{
+    ApiLimits,
+    RateLimitConfig,
+};
+
+#[derive(Debug, Clone, Args)]
+pub struct GraphQLArgs {
+    /// The maximum nesting of the fields in the GraphQL query.
+    #[arg(
+        long = "max-query-depth",
+        default_value_t = ApiLimits::DEFAULT_MAX_DEPTH,
+        env
+    )]
+    pub max_query_depth: usize,
+
+    /// The maximum number of the fields in the GraphQL query.
+    #[arg(
+        long = "max-query-complexity",
+        default_value_t = ApiLimits::DEFAULT_MAX_COMPLEXITY,
+        env
+    )]
+    pub max_query_complexity: usize,
+
+    /// The maximum number of the elements requested by `first` or `last`
+    /// from the connection queries, like `blocks` or `transactions`.
+    #[arg(
+        long = "max-page-size",
+        default_value_t = ApiLimits::DEFAULT_MAX_PAGE_SIZE,
+        env
+    )]
+    pub max_page_size: usize,
+
+    /// The maximum size of the GraphQL request body in bytes.
+    #[arg(
+        long = "request-body-bytes-limit",
+        default_value_t = ApiLimits::DEFAULT_REQUEST_BODY_BYTES_LIMIT,
+        env
+    )]
+    pub request_body_bytes_limit: usize,
+
+    /// The maximum number of the queries in one batched GraphQL request.
+    #[arg(
+        long = "max-batch-size",
+        default_value_t = ApiLimits::DEFAULT_MAX_BATCH_SIZE,
+        env
+    )]
+    pub max_batch_size: usize,
+
+    /// The number of the automatic persisted queries cached by the node.
+    /// The least recently used queries are evicted from the cache.
+    #[arg(
+        long = "persisted-queries-cache-size",
+        default_value_t = ApiLimits::DEFAULT_PERSISTED_QUERIES_CACHE_SIZE,
+        env
+    )]
+    pub persisted_queries_cache_size: usize,
+
+    /// The number of GraphQL requests per second allowed for one client, identified
+    /// by the IP address or by the API key. If not set, the requests are not limited.
+    #[arg(long = "rate-limit", value_parser = clap::value_parser!(u32).range(1..), env)]
+    pub rate_limit: Option<u32>,
+
+    /// The number of GraphQL requests that one client can make at once before
+    /// the rate limit applies. Equals to the `rate-limit` if not set.
+    #[arg(
+        long = "rate-limit-burst",
+        requires = "rate_limit",
+        value_parser = clap::value_parser!(u32).range(1..),
+        env
+    )]
+    pub rate_limit_burst: Option<u32>,
+
+    /// The API keys passed in the `x-api-key` header that identify the clients
+    /// for the rate limit and the transaction pool quota instead of their IP addresses.
+    #[arg(long = "api-keys", requires = "rate_limit", value_delimiter = ',', env)]
+    pub api_keys: Vec<String>,
+}
+
+impl From<GraphQLArgs> for ApiLimits {
+    fn from(args: GraphQLArgs) -> Self {
+        ApiLimits {
+            max_depth: args.max_query_depth,
+            max_complexity: args.max_query_complexity,
+            max_page_size: args.max_page_size,
+            request_body_bytes_limit: args.request_body_bytes_limit,
+            max_batch_size: args.max_batch_size,
+            persisted_queries_cache_size: args.persisted_queries_cache_size,
+            rate_limit: args.rate_limit.map(|requests_per_second| RateLimitConfig {
+                requests_per_second,
+                burst: args.rate_limit_burst.unwrap_or(requests_per_second),
+                api_keys: args.api_keys.into_iter().collect(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct Command {
+        #[clap(flatten)]
+        graphql: GraphQLArgs,
+    }
+
+    fn parse(args: &[&str]) -> Result<ApiLimits, ()> {
+        Command::try_parse_from(args)
+            .map_err(|_| ())
+            .map(|c| c.graphql.into())
+    }
+
+    #[test]
+    fn defaults_to_default_limits() {
+        assert_eq!(parse(&[""]), Ok(ApiLimits::default()));
+    }
+
+    #[test]
+    fn parses_rate_limit() {
+        let limits = parse(&[
+            "",
+            "--rate-limit=10",
+            "--rate-limit-burst=20",
+            "--api-keys=first,second",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            limits.rate_limit,
+            Some(RateLimitConfig {
+                requests_per_second: 10,
+                burst: 20,
+                api_keys: ["first".to_string(), "second".to_string()]
+                    .into_iter()
+                    .collect(),
+            })
+        );
+    }
+
+    #[test]
+    fn burst_defaults_to_rate_limit() {
+        let limits = parse(&["", "--rate-limit=10"]).unwrap();
+
+        assert_eq!(limits.rate_limit.unwrap().burst, 10);
+    }
+
+    #[test]
+    fn api_keys_require_rate_limit() {
+        assert!(parse(&["", "--api-keys=first"]).is_err());
+    }
+}
@@ -0,0 +1,172 @@
+//! Clap configuration related to the tuning of RocksDB
+
+use anyhow::anyhow;
+use clap::Args;
+use fuel_core::{
+    database::Column,
+    service::config::{
+        ColumnOptions,
+        Compression,
+        RocksDbConfig,
+    },
+};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Args)]
+pub struct RocksDbArgs {
+    /// The size of the block cache in bytes shared by all columns of the database.
+    #[arg(long = "db-cache-size", env)]
+    pub cache_size: Option<usize>,
+
+    /// The compression of all columns of the database.
+    #[arg(long = "db-compression", value_enum, env)]
+    pub compression: Option<Compression>,
+
+    /// The number of bits per key of the bloom filter of all columns of the database.
+    /// The bloom filter is disabled if not set.
+    #[arg(long = "db-bloom-filter-bits", env)]
+    pub bloom_filter_bits_per_key: Option<f64>,
+
+    /// The size of the memtable in bytes of each column of the database.
+    #[arg(long = "db-write-buffer-size", env)]
+    pub write_buffer_size: Option<usize>,
+
+    /// The maximum number of the files opened by the database, `-1` means no limit.
+    #[arg(long = "db-max-open-files", allow_hyphen_values = true, env)]
+    pub max_open_files: Option<i32>,
+
+    /// The options of the specific column in the format `<column>:<option>=<value>,...`,
+    /// e.g. `contracts_state:cache-size=268435456,compression=lz4hc`.
+    /// The supported options are `cache-size`, `compression`, `bloom-filter-bits` and
+    /// `write-buffer-size`. The column with its own `cache-size` doesn't use the shared cache.
+    #[arg(
+        long = "db-column-options",
+        value_parser = parse_column_options,
+        value_delimiter = ';',
+        env
+    )]
+    pub column_options: Vec<(Column, ColumnOptions)>,
+}
+
+pub fn parse_column_options(input: &str) -> anyhow::Result<(Column, ColumnOptions)> {
+    let (column, options) = input
+        .split_once(':')
+        .ok_or_else(|| anyhow!("Expected `<column>:<option>=<value>,...`"))?;
+    let column =
+        Column::from_str(column).map_err(|_| anyhow!("Unknown column `{column}`"))?;
+
+    let mut result = ColumnOptions::default();
+    for option in options.split(',') {
+        let (name, value) = option
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Expected `<option>=<value>`, got `{option}`"))?;
+        match name {
+            "cache-size" => result.cache_size = Some(value.parse()?),
+            "compression" => result.compression = Some(Compression::from_str(value)?),
+            "bloom-filter-bits" => {
+                result.bloom_filter_bits_per_key = Some(value.parse()?)
+            }
+            "write-buffer-size" => result.write_buffer_size = Some(value.parse()?),
+            _ => return Err(anyhow!("Unknown column option `{name}`")),
+        }
+    }
+    Ok((column, result))
+}
+
+impl From<RocksDbArgs> for RocksDbConfig {
+    fn from(args: RocksDbArgs) -> Self {
+        RocksDbConfig {
+            max_open_files: args.max_open_files,
+            columns: ColumnOptions {
+                cache_size: args.cache_size,
+                compression: args.compression,
+                bloom_filter_bits_per_key: args.bloom_filter_bits_per_key,
+                write_buffer_size: args.write_buffer_size,
+            },
+            column_overrides: args.column_options.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use test_case::test_case;
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct Command {
+        #[clap(flatten)]
+        rocksdb: RocksDbArgs,
+    }
+
+    fn parse(args: &[&str]) -> Result<RocksDbConfig, ()> {
+        Command::try_parse_from(args)
+            .map_err(|_| ())
+            .map(|c| c.rocksdb.into())
+    }
+
+    #[test]
+    fn defaults_to_rocksdb_defaults() {
+        assert_eq!(parse(&[""]), Ok(RocksDbConfig::default()));
+    }
+
+    #[test]
+    fn parses_options_of_all_columns() {
+        let config = parse(&[
+            "",
+            "--db-cache-size=1024",
+            "--db-compression=lz4hc",
+            "--db-bloom-filter-bits=10",
+            "--db-write-buffer-size=2048",
+            "--db-max-open-files=-1",
+        ])
+        .unwrap();
+
+        assert_eq!(config.max_open_files, Some(-1));
+        assert_eq!(
+            config.columns,
+            ColumnOptions {
+                cache_size: Some(1024),
+                compression: Some(Compression::Lz4hc),
+                bloom_filter_bits_per_key: Some(10.0),
+                write_buffer_size: Some(2048),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_options_of_specific_columns() {
+        let config = parse(&[
+            "",
+            "--db-column-options=contracts_state:cache-size=1024,compression=none",
+            "--db-column-options=coins:bloom-filter-bits=10",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            config.column_overrides.get(&Column::ContractsState),
+            Some(&ColumnOptions {
+                cache_size: Some(1024),
+                compression: Some(Compression::None),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            config.column_overrides.get(&Column::Coins),
+            Some(&ColumnOptions {
+                bloom_filter_bits_per_key: Some(10.0),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test_case("contracts_state"; "without options")]
+    #[test_case("unknown:cache-size=1"; "unknown column")]
+    #[test_case("coins:unknown=1"; "unknown option")]
+    #[test_case("coins:compression=zstd"; "unsupported compression")]
+    #[test_case("coins:cache-size=big"; "invalid value")]
+    fn rejects_invalid_column_options(input: &str) {
+        assert!(parse_column_options(input).is_err());
+    }
+}
@@ -0,0 +1,104 @@
+//! Clap configuration related to the selection of the transactions for the next block
+
+use clap::{
+    Args,
+    ValueEnum,
+};
+use fuel_core::{
+    txpool::transaction_selector::{
+        FifoStrategy,
+        GasPriceStrategy,
+        KnapsackStrategy,
+        PriorityLanesStrategy,
+        TransactionSelectionStrategy,
+    },
+    types::fuel_tx::Address,
+};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Args)]
+pub struct TxSelectionArgs {
+    /// The strategy of selecting the transactions from the pool for the next block.
+    #[arg(
+        long = "tx-selection-strategy",
+        value_enum,
+        default_value = "gas-price",
+        env
+    )]
+    pub strategy: Strategy,
+
+    /// The senders whose transactions are selected first by the `priority-lanes` strategy.
+    #[arg(long = "tx-priority-senders", value_delimiter = ',', env)]
+    pub priority_senders: Vec<Address>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Strategy {
+    /// The transactions with the highest gas price first.
+    GasPrice,
+    /// The transactions in the order of their arrival.
+    Fifo,
+    /// The set of the transactions with the maximum sum of fees.
+    Knapsack,
+    /// The transactions of the `tx-priority-senders` first, then by the gas price.
+    PriorityLanes,
+}
+
+impl From<TxSelectionArgs> for Arc<dyn TransactionSelectionStrategy> {
+    fn from(args: TxSelectionArgs) -> Self {
+        match args.strategy {
+            Strategy::GasPrice => Arc::new(GasPriceStrategy),
+            Strategy::Fifo => Arc::new(FifoStrategy),
+            Strategy::Knapsack => Arc::new(KnapsackStrategy::default()),
+            Strategy::PriorityLanes => Arc::new(PriorityLanesStrategy {
+                senders: args.priority_senders.into_iter().collect(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct Command {
+        #[clap(flatten)]
+        tx_selection: TxSelectionArgs,
+    }
+
+    fn parse(args: &[&str]) -> Result<TxSelectionArgs, ()> {
+        Command::try_parse_from(args)
+            .map_err(|_| ())
+            .map(|c| c.tx_selection)
+    }
+
+    #[test]
+    fn defaults_to_gas_price() {
+        let args = parse(&[""]).unwrap();
+
+        assert_eq!(args.strategy, Strategy::GasPrice);
+        assert!(args.priority_senders.is_empty());
+    }
+
+    #[test]
+    fn parses_priority_senders() {
+        let first = Address::from([1; 32]);
+        let second = Address::from([2; 32]);
+        let args = parse(&[
+            "",
+            "--tx-selection-strategy=priority-lanes",
+            &format!("--tx-priority-senders={first:#x},{second:#x}"),
+        ])
+        .unwrap();
+
+        assert_eq!(args.strategy, Strategy::PriorityLanes);
+        assert_eq!(args.priority_senders, vec![first, second]);
+    }
+
+    #[test]
+    fn rejects_unknown_strategy() {
+        assert!(parse(&["", "--tx-selection-strategy=random"]).is_err());
+    }
+}
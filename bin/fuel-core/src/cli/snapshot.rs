@@ -2,7 +2,8 @@ use crate::cli::DEFAULT_DB_PATH;
 use clap::Parser;
 use std::path::PathBuf;
 
-/// Print a snapshot of blockchain state to stdout.
+/// Print a snapshot of blockchain state to stdout,
+/// or write it into a directory chunk by chunk with `--output-dir`.
 #[derive(Debug, Clone, Parser)]
 pub struct Command {
     #[clap(
@@ -16,6 +17,20 @@ pub struct Command {
     /// Specify either an alias to a built-in configuration or filepath to a JSON file.
     #[clap(name = "CHAIN_CONFIG", long = "chain", default_value = "local_testnet")]
     pub chain_config: String,
+
+    /// The directory where the snapshot is written as chunked tables with a manifest.
+    /// The directory can be used to start a new node with `fuel-core run --genesis-snapshot`.
+    /// It should be empty or not exist.
+    #[clap(name = "OUTPUT_DIR", long = "output-dir", value_parser)]
+    pub output_dir: Option<PathBuf>,
+
+    /// The maximum number of entries in one chunk of the snapshot.
+    #[clap(
+        long = "chunk-size",
+        default_value_t = fuel_core::chain_config::DEFAULT_SNAPSHOT_CHUNK_SIZE,
+        requires = "OUTPUT_DIR"
+    )]
+    pub chunk_size: usize,
 }
 
 #[cfg(not(feature = "rocksdb"))]
@@ -44,6 +59,21 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
         path.display()
     ))?;
 
+    if let Some(output_dir) = command.output_dir {
+        let manifest = db
+            .export_snapshot(&output_dir, command.chunk_size)
+            .context(format!(
+                "failed to write snapshot into {}",
+                output_dir.display()
+            ))?;
+        tracing::info!(
+            "Wrote the snapshot at height {} into {}",
+            manifest.height,
+            output_dir.display()
+        );
+        return Ok(())
+    }
+
     let state_conf = StateConfig::generate_state_config(db)?;
 
     let chain_conf = ChainConfig {
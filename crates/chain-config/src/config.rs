@@ -3,6 +3,7 @@ mod coin;
 mod consensus;
 mod contract;
 mod message;
+mod snapshot;
 mod state;
 
 pub use chain::*;
@@ -10,6 +11,7 @@ pub use coin::*;
 pub use consensus::*;
 pub use contract::*;
 pub use message::*;
+pub use snapshot::*;
 pub use state::*;
 
 #[cfg(test)]
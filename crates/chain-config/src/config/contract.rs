@@ -7,8 +7,10 @@ use fuel_core_types::{
     fuel_types::{
         AssetId,
         Bytes32,
+        ContractId,
         Salt,
     },
+    fuel_vm::Contract,
 };
 use serde::{
     Deserialize,
@@ -54,3 +56,13 @@ pub struct ContractConfig {
     #[serde(default)]
     pub tx_pointer_tx_idx: Option<u16>,
 }
+
+impl ContractConfig {
+    /// Returns the id of the contract created at genesis from this config.
+    /// The id doesn't depend on the initial state of the contract.
+    pub fn contract_id(&self) -> ContractId {
+        let contract = Contract::from(self.code.as_slice());
+        let root = contract.root();
+        contract.id(&self.salt, &root, &Contract::default_state_root())
+    }
+}
@@ -0,0 +1,424 @@
+use crate::{
+    serialization::{
+        HexNumber,
+        HexType,
+    },
+    CoinConfig,
+    ContractConfig,
+    MessageConfig,
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockHeight,
+    fuel_crypto::Hasher,
+    fuel_types::{
+        AssetId,
+        Bytes32,
+        ContractId,
+    },
+};
+use serde::{
+    de::DeserializeOwned,
+    Deserialize,
+    Serialize,
+};
+use serde_with::serde_as;
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{
+        BufWriter,
+        Write,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+/// The name of the file that describes the content of the snapshot directory.
+pub const SNAPSHOT_MANIFEST_FILE: &str = "manifest.json";
+/// The version of the snapshot format produced by the [`SnapshotWriter`].
+pub const SNAPSHOT_VERSION: u32 = 1;
+/// The default number of entries stored in one chunk of the snapshot.
+pub const DEFAULT_SNAPSHOT_CHUNK_SIZE: usize = 10_000;
+
+/// The tables of the state exported into the snapshot.
+///
+/// The order of the variants is the order in which the tables are imported at genesis:
+/// contracts are imported before their state and balances.
+#[derive(
+    Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SnapshotTable {
+    Coins,
+    Messages,
+    Contracts,
+    ContractState,
+    ContractBalances,
+}
+
+impl SnapshotTable {
+    /// All tables in the import order.
+    pub const ALL: [SnapshotTable; 5] = [
+        SnapshotTable::Coins,
+        SnapshotTable::Messages,
+        SnapshotTable::Contracts,
+        SnapshotTable::ContractState,
+        SnapshotTable::ContractBalances,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SnapshotTable::Coins => "coins",
+            SnapshotTable::Messages => "messages",
+            SnapshotTable::Contracts => "contracts",
+            SnapshotTable::ContractState => "contract_state",
+            SnapshotTable::ContractBalances => "contract_balances",
+        }
+    }
+}
+
+/// The entry of the snapshot table.
+pub trait SnapshotEntry: Serialize + DeserializeOwned {
+    /// The table that contains entries of this type.
+    const TABLE: SnapshotTable;
+}
+
+impl SnapshotEntry for CoinConfig {
+    const TABLE: SnapshotTable = SnapshotTable::Coins;
+}
+
+impl SnapshotEntry for MessageConfig {
+    const TABLE: SnapshotTable = SnapshotTable::Messages;
+}
+
+/// The contracts are exported without the state and balances,
+/// they are stored in the separate tables.
+impl SnapshotEntry for ContractConfig {
+    const TABLE: SnapshotTable = SnapshotTable::Contracts;
+}
+
+/// The slot of the contract's state.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct ContractStateConfig {
+    #[serde_as(as = "HexType")]
+    pub contract_id: ContractId,
+    #[serde_as(as = "HexType")]
+    pub key: Bytes32,
+    #[serde_as(as = "HexType")]
+    pub value: Bytes32,
+}
+
+impl SnapshotEntry for ContractStateConfig {
+    const TABLE: SnapshotTable = SnapshotTable::ContractState;
+}
+
+/// The balance of the contract.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct ContractBalanceConfig {
+    #[serde_as(as = "HexType")]
+    pub contract_id: ContractId,
+    #[serde_as(as = "HexType")]
+    pub asset_id: AssetId,
+    #[serde_as(as = "HexNumber")]
+    pub amount: u64,
+}
+
+impl SnapshotEntry for ContractBalanceConfig {
+    const TABLE: SnapshotTable = SnapshotTable::ContractBalances;
+}
+
+/// The file with the part of the snapshot table.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct SnapshotChunk {
+    /// The name of the file relative to the snapshot directory.
+    pub file: String,
+    /// The number of entries in the file.
+    pub entries: u64,
+    /// The hash of the file content.
+    #[serde_as(as = "HexType")]
+    pub checksum: Bytes32,
+}
+
+/// Describes the chunks of each table of the snapshot.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct SnapshotManifest {
+    pub version: u32,
+    /// The height of the block at which the snapshot was taken.
+    #[serde_as(as = "HexNumber")]
+    pub height: BlockHeight,
+    pub tables: BTreeMap<SnapshotTable, Vec<SnapshotChunk>>,
+}
+
+impl SnapshotManifest {
+    /// Returns the chunks of the `table` in the order they were written.
+    pub fn chunks(&self, table: SnapshotTable) -> &[SnapshotChunk] {
+        self.tables
+            .get(&table)
+            .map(|chunks| chunks.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+/// Writes the state into the snapshot directory table by table.
+/// Each table is split into chunks of JSON lines files, so neither the writer
+/// nor the reader has to keep the whole state in memory.
+pub struct SnapshotWriter {
+    dir: PathBuf,
+    chunk_size: usize,
+    manifest: SnapshotManifest,
+}
+
+impl SnapshotWriter {
+    /// Creates the writer of the snapshot at `height`.
+    /// The directory should be empty or not exist.
+    pub fn new(
+        dir: impl AsRef<Path>,
+        height: BlockHeight,
+        chunk_size: usize,
+    ) -> anyhow::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        if chunk_size == 0 {
+            return Err(anyhow!("The chunk size should be greater than zero"))
+        }
+        if dir.exists() && dir.read_dir()?.next().is_some() {
+            return Err(anyhow!(
+                "The snapshot directory {} is not empty",
+                dir.display()
+            ))
+        }
+        std::fs::create_dir_all(&dir)?;
+
+        Ok(Self {
+            dir,
+            chunk_size,
+            manifest: SnapshotManifest {
+                version: SNAPSHOT_VERSION,
+                height,
+                tables: Default::default(),
+            },
+        })
+    }
+
+    /// Writes all `entries` into the chunks of the table `T::TABLE`.
+    pub fn write_table<T, E, I>(&mut self, entries: I) -> anyhow::Result<()>
+    where
+        T: SnapshotEntry,
+        I: IntoIterator<Item = Result<T, E>>,
+        E: Into<anyhow::Error>,
+    {
+        let table = T::TABLE;
+        let mut chunks = vec![];
+        let mut entries = entries.into_iter().peekable();
+
+        while entries.peek().is_some() {
+            let file = format!("{}-{:06}.jsonl", table.name(), chunks.len());
+            let mut writer = BufWriter::new(File::create(self.dir.join(&file))?);
+            let mut hasher = Hasher::default();
+            let mut written = 0u64;
+
+            for entry in entries.by_ref().take(self.chunk_size) {
+                let mut line = serde_json::to_vec(&entry.map_err(Into::into)?)?;
+                line.push(b'\n');
+                hasher.input(&line);
+                writer.write_all(&line)?;
+                written += 1;
+            }
+            writer.flush()?;
+
+            chunks.push(SnapshotChunk {
+                file,
+                entries: written,
+                checksum: hasher.finalize(),
+            });
+        }
+
+        self.manifest.tables.insert(table, chunks);
+        Ok(())
+    }
+
+    /// Writes the manifest. The snapshot is complete only after this call.
+    pub fn finish(self) -> anyhow::Result<SnapshotManifest> {
+        let file = File::create(self.dir.join(SNAPSHOT_MANIFEST_FILE))?;
+        serde_json::to_writer_pretty(file, &self.manifest)?;
+        Ok(self.manifest)
+    }
+}
+
+/// Reads the snapshot written by the [`SnapshotWriter`] chunk by chunk.
+#[derive(Clone, Debug)]
+pub struct SnapshotReader {
+    dir: PathBuf,
+    manifest: SnapshotManifest,
+}
+
+impl SnapshotReader {
+    pub fn open(dir: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        let manifest_path = dir.join(SNAPSHOT_MANIFEST_FILE);
+        let manifest: SnapshotManifest =
+            serde_json::from_reader(File::open(&manifest_path).context(format!(
+                "failed to open the snapshot manifest {}",
+                manifest_path.display()
+            ))?)?;
+
+        if manifest.version != SNAPSHOT_VERSION {
+            return Err(anyhow!(
+                "Unsupported snapshot version {}, expected {}",
+                manifest.version,
+                SNAPSHOT_VERSION
+            ))
+        }
+
+        Ok(Self { dir, manifest })
+    }
+
+    pub fn manifest(&self) -> &SnapshotManifest {
+        &self.manifest
+    }
+
+    /// Returns the number of chunks of the table `T::TABLE`.
+    pub fn chunks_count<T: SnapshotEntry>(&self) -> usize {
+        self.manifest.chunks(T::TABLE).len()
+    }
+
+    /// Reads the chunk at `index` of the table `T::TABLE` and verifies its checksum.
+    pub fn read_chunk<T: SnapshotEntry>(&self, index: usize) -> anyhow::Result<Vec<T>> {
+        let chunk = self.manifest.chunks(T::TABLE).get(index).ok_or_else(|| {
+            anyhow!(
+                "The chunk {} of the table {} doesn't exist",
+                index,
+                T::TABLE.name()
+            )
+        })?;
+        let bytes = std::fs::read(self.dir.join(&chunk.file))
+            .context(format!("failed to read the snapshot chunk {}", chunk.file))?;
+
+        let checksum = Hasher::default().chain(&bytes).finalize();
+        if checksum != chunk.checksum {
+            return Err(anyhow!(
+                "The checksum of the snapshot chunk {} doesn't match the manifest",
+                chunk.file
+            ))
+        }
+
+        let entries = serde_json::Deserializer::from_slice(&bytes)
+            .into_iter::<T>()
+            .collect::<Result<Vec<_>, _>>()
+            .context(format!(
+                "failed to decode the snapshot chunk {}",
+                chunk.file
+            ))?;
+        if entries.len() as u64 != chunk.entries {
+            return Err(anyhow!(
+                "The snapshot chunk {} contains {} entries instead of {}",
+                chunk.file,
+                entries.len(),
+                chunk.entries
+            ))
+        }
+        Ok(entries)
+    }
+
+    /// Iterates over the entries of the table `T::TABLE`, reading one chunk at a time.
+    pub fn entries<'a, T: SnapshotEntry + 'a>(
+        &'a self,
+    ) -> impl Iterator<Item = anyhow::Result<T>> + 'a {
+        (0..self.chunks_count::<T>()).flat_map(move |index| {
+            let (entries, error) = match self.read_chunk::<T>(index) {
+                Ok(entries) => (entries, None),
+                Err(err) => (vec![], Some(Err(err))),
+            };
+            entries.into_iter().map(Ok).chain(error)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::blockchain::primitives::DaBlockHeight;
+
+    fn message(nonce: u64) -> MessageConfig {
+        MessageConfig {
+            nonce: nonce.into(),
+            amount: nonce,
+            da_height: DaBlockHeight(nonce),
+            ..Default::default()
+        }
+    }
+
+    fn write_messages(dir: &Path, count: u64, chunk_size: usize) -> SnapshotManifest {
+        let mut writer = SnapshotWriter::new(dir, 10u32.into(), chunk_size).unwrap();
+        writer
+            .write_table((0..count).map(|i| Ok::<_, anyhow::Error>(message(i))))
+            .unwrap();
+        writer
+            .write_table(std::iter::empty::<anyhow::Result<CoinConfig>>())
+            .unwrap();
+        writer.finish().unwrap()
+    }
+
+    #[test]
+    fn snapshot_tables_are_split_into_chunks() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifest = write_messages(dir.path(), 5, 2);
+
+        let chunks = manifest.chunks(SnapshotTable::Messages);
+        assert_eq!(
+            chunks.iter().map(|chunk| chunk.entries).collect::<Vec<_>>(),
+            vec![2, 2, 1]
+        );
+        assert!(manifest.chunks(SnapshotTable::Coins).is_empty());
+        assert!(manifest.chunks(SnapshotTable::Contracts).is_empty());
+
+        let reader = SnapshotReader::open(dir.path()).unwrap();
+        assert_eq!(reader.manifest(), &manifest);
+        assert_eq!(reader.manifest().height, 10u32.into());
+        assert_eq!(
+            reader.read_chunk::<MessageConfig>(1).unwrap(),
+            vec![message(2), message(3)]
+        );
+        let messages = reader
+            .entries::<MessageConfig>()
+            .collect::<anyhow::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(messages, (0..5).map(message).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn snapshot_reader_detects_modified_chunks() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifest = write_messages(dir.path(), 3, 2);
+
+        let chunk = &manifest.chunks(SnapshotTable::Messages)[1];
+        let path = dir.path().join(&chunk.file);
+        let modified = serde_json::to_string(&message(100)).unwrap() + "\n";
+        std::fs::write(path, modified).unwrap();
+
+        let reader = SnapshotReader::open(dir.path()).unwrap();
+        assert!(reader.read_chunk::<MessageConfig>(0).is_ok());
+        assert!(reader.read_chunk::<MessageConfig>(1).is_err());
+        let result = reader
+            .entries::<MessageConfig>()
+            .collect::<anyhow::Result<Vec<_>>>();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn snapshot_writer_requires_empty_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        write_messages(dir.path(), 1, 1);
+
+        assert!(SnapshotWriter::new(dir.path(), 0u32.into(), 1).is_err());
+    }
+}
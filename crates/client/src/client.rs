@@ -1,11 +1,18 @@
 use crate::client::schema::{
-    block::BlockByHeightArgs,
+    block::{
+        BlockByHeightArgs,
+        NewBlocksArgs,
+    },
     coins::{
         ExcludeInput,
         SpendQueryElementInput,
     },
     contract::ContractBalanceQueryArgs,
-    tx::DryRunArg,
+    tx::{
+        DryRunArg,
+        DryRunManyArg,
+        StateOverridesInput,
+    },
     Tai64Timestamp,
 };
 use anyhow::Context;
@@ -31,6 +38,7 @@ use fuel_core_types::{
     },
     fuel_types,
     fuel_types::bytes::SerializableVec,
+    services::executor::StateOverrides,
 };
 #[cfg(feature = "subscriptions")]
 use futures::StreamExt;
@@ -76,6 +84,8 @@ pub use schema::{
 };
 #[cfg(feature = "subscriptions")]
 use std::future;
+#[cfg(feature = "batching")]
+use std::time::Duration;
 use std::{
     convert::TryInto,
     io::{
@@ -93,6 +103,9 @@ use std::{
 use tai64::Tai64;
 use tracing as _;
 use types::{
+    DryRunResponse,
+    GasEstimateResponse,
+    IndexedReceiptResponse,
     TransactionResponse,
     TransactionStatus,
 };
@@ -102,14 +115,24 @@ use self::schema::{
     message::MessageProofArgs,
 };
 
+#[cfg(feature = "batching")]
+mod batch;
 pub mod schema;
 pub mod types;
+#[cfg(feature = "subscriptions")]
+mod ws;
 
 #[derive(Debug, Clone)]
 pub struct FuelClient {
     client: reqwest::Client,
     cookie: Arc<reqwest::cookie::Jar>,
     url: reqwest::Url,
+    admin_url: reqwest::Url,
+    admin_token: Option<String>,
+    #[cfg(feature = "batching")]
+    batch: Option<Arc<batch::BatchQueue>>,
+    #[cfg(feature = "subscriptions")]
+    websocket: Option<Arc<ws::WsConnection>>,
 }
 
 impl FromStr for FuelClient {
@@ -124,6 +147,8 @@ impl FromStr for FuelClient {
         let mut url = reqwest::Url::parse(&raw_url)
             .with_context(|| format!("Invalid fuel-core URL: {str}"))?;
         url.set_path("/graphql");
+        let mut admin_url = url.clone();
+        admin_url.set_path("/admin/graphql");
         let cookie = Arc::new(reqwest::cookie::Jar::default());
         let client = reqwest::Client::builder()
             .cookie_provider(cookie.clone())
@@ -132,6 +157,12 @@ impl FromStr for FuelClient {
             client,
             cookie,
             url,
+            admin_url,
+            admin_token: None,
+            #[cfg(feature = "batching")]
+            batch: None,
+            #[cfg(feature = "subscriptions")]
+            websocket: None,
         })
     }
 }
@@ -159,11 +190,64 @@ pub fn from_strings_errors_to_std_error(errors: Vec<String>) -> io::Error {
     io::Error::new(io::ErrorKind::Other, e)
 }
 
+/// The error with the `message`, shared by the batching and the subscriptions.
+#[cfg(any(feature = "batching", feature = "subscriptions"))]
+fn other(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, message)
+}
+
 impl FuelClient {
     pub fn new(url: impl AsRef<str>) -> anyhow::Result<Self> {
         Self::from_str(url.as_ref())
     }
 
+    /// Sets the bearer token sent to the admin API of the node. The admin API serves
+    /// the block production, debugger and checkpoint operations. The token is not
+    /// required by the node serving the admin API on its own address without the token.
+    pub fn with_admin_token(mut self, token: impl Into<String>) -> Self {
+        self.admin_token = Some(token.into());
+        self
+    }
+
+    /// Sends the operations of the admin API to the separate admin address of the node
+    /// instead of the address of the public API.
+    pub fn with_admin_address(mut self, address: impl Into<net::SocketAddr>) -> Self {
+        let address = address.into();
+        self.admin_url
+            .set_ip_host(address.ip())
+            .expect("The URL of the client has the host");
+        self.admin_url
+            .set_port(Some(address.port()))
+            .expect("The URL of the client has the host");
+        self
+    }
+
+    /// Serves the subscriptions over one WebSocket connection with the
+    /// `graphql-transport-ws` protocol instead of the separate SSE request
+    /// for each subscription. The connection is established with the first subscription
+    /// and is shared by the clones of the client.
+    #[cfg(feature = "subscriptions")]
+    pub fn with_websocket_subscriptions(mut self) -> Self {
+        self.websocket = Some(Arc::new(ws::WsConnection::new(self.url.clone())));
+        self
+    }
+
+    /// Combines the queries and mutations issued concurrently, for example, by
+    /// the clones of the client, into one request to the node. The operations issued
+    /// during the `delay` after the first one are sent together, up to
+    /// `max_batch_size` operations per request. The privileged operations of
+    /// the admin API and the subscriptions are not batched.
+    #[cfg(feature = "batching")]
+    pub fn with_query_batching(mut self, max_batch_size: usize, delay: Duration) -> Self {
+        self.batch = Some(Arc::new(batch::BatchQueue::new(
+            self.client.clone(),
+            self.url.clone(),
+            max_batch_size,
+            delay,
+        )));
+        self
+    }
+
     async fn query<ResponseData, Vars>(
         &self,
         q: Operation<ResponseData, Vars>,
@@ -172,6 +256,17 @@ impl FuelClient {
         Vars: serde::Serialize,
         ResponseData: serde::de::DeserializeOwned + 'static,
     {
+        #[cfg(feature = "batching")]
+        if let Some(batch) = &self.batch {
+            let response = batch.query(serde_json::to_value(&q)?).await?;
+            let response =
+                serde_json::from_value::<GraphQlResponse<ResponseData>>(response)
+                    .map_err(|e| {
+                        io::Error::new(io::ErrorKind::Other, format!("Json error: {e:?}"))
+                    })?;
+            return Self::decode_response(response)
+        }
+
         let response = self
             .client
             .post(self.url.clone())
@@ -182,6 +277,27 @@ impl FuelClient {
         Self::decode_response(response)
     }
 
+    /// Runs the privileged operation against the admin API.
+    async fn admin_query<ResponseData, Vars>(
+        &self,
+        q: Operation<ResponseData, Vars>,
+    ) -> io::Result<ResponseData>
+    where
+        Vars: serde::Serialize,
+        ResponseData: serde::de::DeserializeOwned + 'static,
+    {
+        let mut request = self.client.post(self.admin_url.clone());
+        if let Some(token) = &self.admin_token {
+            request = request.bearer_auth(token);
+        }
+        let response = request
+            .run_graphql(q)
+            .await
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+
+        Self::decode_response(response)
+    }
+
     fn decode_response<R>(response: GraphQlResponse<R>) -> io::Result<R>
     where
         R: serde::de::DeserializeOwned + 'static,
@@ -201,6 +317,44 @@ impl FuelClient {
         &self,
         q: StreamingOperation<ResponseData, Vars>,
     ) -> io::Result<impl futures::Stream<Item = io::Result<ResponseData>>>
+    where
+        Vars: serde::Serialize,
+        ResponseData: serde::de::DeserializeOwned + 'static,
+    {
+        use futures::future::Either;
+        match &self.websocket {
+            Some(websocket) => Ok(Either::Left(Self::subscribe_ws(websocket, q).await?)),
+            None => Ok(Either::Right(self.subscribe_sse(q).await?)),
+        }
+    }
+
+    #[cfg(feature = "subscriptions")]
+    async fn subscribe_ws<ResponseData, Vars>(
+        websocket: &ws::WsConnection,
+        q: StreamingOperation<ResponseData, Vars>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<ResponseData>>>
+    where
+        Vars: serde::Serialize,
+        ResponseData: serde::de::DeserializeOwned + 'static,
+    {
+        let operation = serde_json::to_value(&q)?;
+        let stream = websocket.subscribe(operation).await?.map(|result| {
+            let response =
+                serde_json::from_value::<GraphQlResponse<ResponseData>>(result?)
+                    .map_err(|e| {
+                        io::Error::new(io::ErrorKind::Other, format!("Json error: {e:?}"))
+                    })?;
+            Self::decode_response(response)
+        });
+
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    async fn subscribe_sse<ResponseData, Vars>(
+        &self,
+        q: StreamingOperation<ResponseData, Vars>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<ResponseData>>>
     where
         Vars: serde::Serialize,
         ResponseData: serde::de::DeserializeOwned + 'static,
@@ -308,6 +462,13 @@ impl FuelClient {
         self.query(query).await.map(|r| r.node_info)
     }
 
+    pub async fn database_stats(
+        &self,
+    ) -> io::Result<Vec<schema::node_info::DatabaseColumnStats>> {
+        let query = schema::node_info::QueryDatabaseStats::build(());
+        self.admin_query(query).await.map(|r| r.database_stats)
+    }
+
     pub async fn chain_info(&self) -> io::Result<schema::chain::ChainInfo> {
         let query = schema::chain::ChainQuery::build(());
         self.query(query).await.map(|r| r.chain)
@@ -324,11 +485,34 @@ impl FuelClient {
         tx: &Transaction,
         // Disable utxo input checks (exists, unspent, and valid signature)
         utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<Receipt>> {
+        self.dry_run_inner(tx, utxo_validation, None).await
+    }
+
+    /// Dry run against the state of the node changed by the `overrides`, like the fake
+    /// coins or the replaced bytecode of the contracts. The changes are never committed.
+    pub async fn dry_run_with_overrides(
+        &self,
+        tx: &Transaction,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        overrides: &StateOverrides,
+    ) -> io::Result<Vec<Receipt>> {
+        self.dry_run_inner(tx, utxo_validation, Some(overrides.into()))
+            .await
+    }
+
+    async fn dry_run_inner(
+        &self,
+        tx: &Transaction,
+        utxo_validation: Option<bool>,
+        state_overrides: Option<StateOverridesInput>,
     ) -> io::Result<Vec<Receipt>> {
         let tx = tx.clone().to_bytes();
         let query = schema::tx::DryRun::build(DryRunArg {
             tx: HexString(Bytes(tx)),
             utxo_validation,
+            state_overrides,
         });
         let receipts = self.query(query).await.map(|r| r.dry_run)?;
         receipts
@@ -337,6 +521,44 @@ impl FuelClient {
             .collect()
     }
 
+    /// Dry run of the transactions in one block. The transactions are executed in order,
+    /// so later transactions can spend the outputs of the earlier ones.
+    pub async fn dry_run_many(
+        &self,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<DryRunResponse>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.clone().to_bytes())))
+            .collect();
+        let query = schema::tx::DryRunMany::build(DryRunManyArg {
+            txs,
+            utxo_validation,
+            state_overrides: None,
+        });
+        let results = self.query(query).await?.dry_run_many;
+        results
+            .into_iter()
+            .map(|result| result.try_into().map_err(Into::into))
+            .collect()
+    }
+
+    /// Estimates the gas and the fees of the transaction. The transaction
+    /// doesn't need to be signed, and its gas limit is ignored.
+    pub async fn estimate_gas(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<GasEstimateResponse> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::EstimateGasQuery::build(TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+        let estimate = self.query(query).await?.estimate_gas.try_into()?;
+        Ok(estimate)
+    }
+
     pub async fn submit(&self, tx: &Transaction) -> io::Result<TransactionId> {
         let tx = tx.clone().to_bytes();
         let query = schema::tx::Submit::build(TxArg {
@@ -363,7 +585,7 @@ impl FuelClient {
     pub async fn start_session(&self) -> io::Result<String> {
         let query = schema::StartSession::build(());
 
-        self.query(query)
+        self.admin_query(query)
             .await
             .map(|r| r.start_session.into_inner())
     }
@@ -371,20 +593,20 @@ impl FuelClient {
     pub async fn end_session(&self, id: &str) -> io::Result<bool> {
         let query = schema::EndSession::build(IdArg { id: id.into() });
 
-        self.query(query).await.map(|r| r.end_session)
+        self.admin_query(query).await.map(|r| r.end_session)
     }
 
     pub async fn reset(&self, id: &str) -> io::Result<bool> {
         let query = schema::Reset::build(IdArg { id: id.into() });
 
-        self.query(query).await.map(|r| r.reset)
+        self.admin_query(query).await.map(|r| r.reset)
     }
 
     pub async fn execute(&self, id: &str, op: &Instruction) -> io::Result<bool> {
         let op = serde_json::to_string(op)?;
         let query = schema::Execute::build(schema::ExecuteArgs { id: id.into(), op });
 
-        self.query(query).await.map(|r| r.execute)
+        self.admin_query(query).await.map(|r| r.execute)
     }
 
     pub async fn register(&self, id: &str, register: RegisterId) -> io::Result<Word> {
@@ -393,7 +615,7 @@ impl FuelClient {
             register: register.into(),
         });
 
-        Ok(self.query(query).await?.register.0 as Word)
+        Ok(self.admin_query(query).await?.register.0 as Word)
     }
 
     pub async fn memory(
@@ -408,7 +630,7 @@ impl FuelClient {
             size: size.into(),
         });
 
-        let memory = self.query(query).await?.memory;
+        let memory = self.admin_query(query).await?.memory;
 
         Ok(serde_json::from_str(memory.as_str())?)
     }
@@ -427,7 +649,7 @@ impl FuelClient {
             },
         });
 
-        let response = self.query(operation).await?;
+        let response = self.admin_query(operation).await?;
         assert!(
             response.set_breakpoint,
             "Setting breakpoint returned invalid reply"
@@ -444,7 +666,7 @@ impl FuelClient {
             id: Id::new(session_id),
             enable,
         });
-        self.query(operation).await?;
+        self.admin_query(operation).await?;
         Ok(())
     }
 
@@ -457,7 +679,7 @@ impl FuelClient {
             id: Id::new(session_id),
             tx: serde_json::to_string(tx).expect("Couldn't serialize tx to json"),
         });
-        let response = self.query(operation).await?.start_tx;
+        let response = self.admin_query(operation).await?.start_tx;
         Ok(response)
     }
 
@@ -465,7 +687,7 @@ impl FuelClient {
         let operation = ContinueTx::build(ContinueTxArgs {
             id: Id::new(session_id),
         });
-        let response = self.query(operation).await?.continue_tx;
+        let response = self.admin_query(operation).await?.continue_tx;
         Ok(response)
     }
 
@@ -521,6 +743,64 @@ impl FuelClient {
         Ok(stream)
     }
 
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the transactions submitted into the transaction pool
+    pub async fn subscribe_new_transactions(
+        &self,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<TransactionResponse>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::tx::NewTransactionsSubscription::build(());
+
+        tracing::debug!("subscribing");
+        let stream = self.subscribe(s).await?.map(|tx| {
+            let tx = tx?.new_transactions.try_into()?;
+            Ok(tx)
+        });
+
+        Ok(stream)
+    }
+
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the submission, completion and squeezing out of the transactions
+    /// in the transaction pool
+    pub async fn subscribe_txpool_events(
+        &self,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<schema::tx::TxPoolEvent>>>
+    {
+        use cynic::SubscriptionBuilder;
+        let s = schema::tx::TxPoolEventsSubscription::build(());
+
+        tracing::debug!("subscribing");
+        let stream = self
+            .subscribe(s)
+            .await?
+            .map(|event| Ok(event?.txpool_events));
+
+        Ok(stream)
+    }
+
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the new blocks committed by the node. If `from_height` is set,
+    /// the stream starts from the block at this height, which allows resuming
+    /// the subscription without missing blocks.
+    pub async fn subscribe_new_blocks(
+        &self,
+        from_height: Option<u64>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<schema::block::Block>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::block::NewBlocksSubscription::build(NewBlocksArgs {
+            from_height: from_height.map(U64),
+        });
+
+        tracing::debug!("subscribing");
+        let stream = self.subscribe(s).await?.map(|block| Ok(block?.new_blocks));
+
+        Ok(stream)
+    }
+
     #[cfg(feature = "subscriptions")]
     /// Awaits for the transaction to be committed into a block
     ///
@@ -574,6 +854,50 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns the transactions that use the `contract` as an input or create it.
+    pub async fn transactions_by_contract(
+        &self,
+        contract: &str,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let contract: schema::ContractId = contract.parse()?;
+        let query =
+            schema::tx::TransactionsByContractQuery::build((contract, request).into());
+
+        let transactions = self
+            .query(query)
+            .await?
+            .transactions_by_contract
+            .try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns the transactions that spend or create coins of the `asset`.
+    pub async fn transactions_by_asset(
+        &self,
+        asset: &str,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let asset: schema::AssetId = asset.parse()?;
+        let query = schema::tx::TransactionsByAssetQuery::build((asset, request).into());
+
+        let transactions = self.query(query).await?.transactions_by_asset.try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns the receipts emitted by the contract, ordered by their position in the chain.
+    /// Only forward pagination is supported.
+    pub async fn receipts_by_contract(
+        &self,
+        filter: schema::tx::ReceiptFilterInput,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<IndexedReceiptResponse, String>> {
+        let query = schema::tx::ReceiptsQuery::build((filter, request).into());
+
+        let receipts = self.query(query).await?.receipts.try_into()?;
+        Ok(receipts)
+    }
+
     pub async fn receipts(&self, id: &str) -> io::Result<Option<Vec<Receipt>>> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: id.parse()? });
 
@@ -604,11 +928,96 @@ impl FuelClient {
                 .map(|timestamp| Tai64Timestamp::from(Tai64(timestamp))),
         });
 
-        let new_height = self.query(query).await?.produce_blocks;
+        let new_height = self.admin_query(query).await?.produce_blocks;
 
         Ok(new_height.into())
     }
 
+    /// Creates a checkpoint of the node's database and returns the path to it.
+    pub async fn create_checkpoint(&self) -> io::Result<String> {
+        let query = schema::CreateCheckpoint::build(());
+
+        self.admin_query(query).await.map(|r| r.create_checkpoint)
+    }
+
+    /// Returns the number of pending transactions and their combined gas limit.
+    pub async fn txpool_stats(&self) -> io::Result<schema::txpool::TxPoolStats> {
+        let query = schema::txpool::TxPoolStatsQuery::build(());
+        self.query(query).await.map(|r| r.txpool)
+    }
+
+    /// Returns a paginated set of pending transactions in the `order_by` order.
+    pub async fn txpool_transactions(
+        &self,
+        order_by: schema::txpool::TxPoolOrder,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let query =
+            schema::txpool::TxPoolTransactionsQuery::build((order_by, request).into());
+
+        let transactions = self.query(query).await?.txpool.transactions.try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns the pending transactions spending the coins or messages of the `owner`.
+    pub async fn txpool_transactions_by_owner(
+        &self,
+        owner: &str,
+    ) -> io::Result<Vec<TransactionResponse>> {
+        let owner: schema::Address = owner.parse()?;
+        let query = schema::txpool::TxPoolByOwnerQuery::build(
+            schema::txpool::TxPoolByOwnerArgs { owner },
+        );
+
+        let transactions = self
+            .query(query)
+            .await?
+            .txpool
+            .by_owner
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, ConversionError>>()?;
+        Ok(transactions)
+    }
+
+    /// Returns the pending transactions that must be included before the transaction
+    /// with the `id`, or `None` if the transaction is not in the pool.
+    pub async fn txpool_dependencies(
+        &self,
+        id: &str,
+    ) -> io::Result<Option<Vec<TransactionResponse>>> {
+        let query = schema::txpool::TxPoolDependenciesQuery::build(
+            schema::txpool::TxPoolDependenciesArgs { tx_id: id.parse()? },
+        );
+
+        let dependencies = self
+            .query(query)
+            .await?
+            .txpool
+            .dependencies
+            .map(|txs| {
+                txs.into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, ConversionError>>()
+            })
+            .transpose()?;
+        Ok(dependencies)
+    }
+
+    /// Removes the transactions and the ones depending on them from the pool of the node.
+    /// Returns the ids of all removed transactions.
+    pub async fn remove_transactions(
+        &self,
+        ids: &[&str],
+    ) -> io::Result<Vec<TransactionId>> {
+        let ids = ids.iter().map(|id| id.parse()).collect::<Result<_, _>>()?;
+        let query = schema::txpool::RemoveTransactions::build(
+            schema::txpool::RemoveTransactionsArgs { ids },
+        );
+
+        self.admin_query(query).await.map(|r| r.remove_transactions)
+    }
+
     pub async fn block(&self, id: &str) -> io::Result<Option<schema::block::Block>> {
         let query = schema::block::BlockByIdQuery::build(BlockByIdArgs {
             id: Some(id.parse()?),
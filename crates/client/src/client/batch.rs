@@ -0,0 +1,154 @@
+//! The queue combining the concurrent GraphQL operations of the client into
+//! the batched requests sent as the JSON array.
+
+use super::other;
+use serde_json::Value;
+use std::{
+    io,
+    time::Duration,
+};
+use tokio::sync::{
+    mpsc,
+    oneshot,
+    Mutex,
+};
+
+type Responder = oneshot::Sender<io::Result<Value>>;
+
+/// The queue shared by the clones of the client. The operations received during
+/// the `delay` after the first one are sent in one request. The task serving
+/// the queue is spawned with the first operation and is restarted if it stops.
+#[derive(Debug)]
+pub(crate) struct BatchQueue {
+    client: reqwest::Client,
+    url: reqwest::Url,
+    max_batch_size: usize,
+    delay: Duration,
+    operations: Mutex<Option<mpsc::UnboundedSender<(Value, Responder)>>>,
+}
+
+impl BatchQueue {
+    pub fn new(
+        client: reqwest::Client,
+        url: reqwest::Url,
+        max_batch_size: usize,
+        delay: Duration,
+    ) -> Self {
+        Self {
+            client,
+            url,
+            max_batch_size: max_batch_size.max(1),
+            delay,
+            operations: Mutex::new(None),
+        }
+    }
+
+    /// Adds the serialized GraphQL `operation` to the next batch
+    /// and waits for its response.
+    pub async fn query(&self, operation: Value) -> io::Result<Value> {
+        let sender = {
+            let mut operations = self.operations.lock().await;
+            match operations.as_ref() {
+                Some(sender) if !sender.is_closed() => sender.clone(),
+                _ => {
+                    let (sender, receiver) = mpsc::unbounded_channel();
+                    tokio::spawn(run(
+                        self.client.clone(),
+                        self.url.clone(),
+                        self.max_batch_size,
+                        self.delay,
+                        receiver,
+                    ));
+                    *operations = Some(sender.clone());
+                    sender
+                }
+            }
+        };
+
+        let (responder, response) = oneshot::channel();
+        sender
+            .send((operation, responder))
+            .map_err(|_| other("The batch queue is closed".to_string()))?;
+        response
+            .await
+            .map_err(|_| other("The batch was dropped".to_string()))?
+    }
+}
+
+/// Collects the operations into the batches until the queue is dropped.
+/// Each batch is sent by a separate task to not delay the next one.
+async fn run(
+    client: reqwest::Client,
+    url: reqwest::Url,
+    max_batch_size: usize,
+    delay: Duration,
+    mut operations: mpsc::UnboundedReceiver<(Value, Responder)>,
+) {
+    while let Some(first) = operations.recv().await {
+        let mut batch = vec![first];
+        let deadline = tokio::time::sleep(delay);
+        tokio::pin!(deadline);
+        while batch.len() < max_batch_size {
+            tokio::select! {
+                operation = operations.recv() => match operation {
+                    Some(operation) => batch.push(operation),
+                    None => break,
+                },
+                _ = &mut deadline => break,
+            }
+        }
+        tokio::spawn(send(client.clone(), url.clone(), batch));
+    }
+}
+
+/// Sends the batch and routes the responses to the operations. The single operation
+/// is sent without the array to be served by the nodes without the batching support.
+async fn send(
+    client: reqwest::Client,
+    url: reqwest::Url,
+    batch: Vec<(Value, Responder)>,
+) {
+    let (operations, responders): (Vec<_>, Vec<_>) = batch.into_iter().unzip();
+    let body = match <[Value; 1]>::try_from(operations) {
+        Ok([operation]) => operation,
+        Err(operations) => Value::Array(operations),
+    };
+
+    let result = async {
+        client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| other(format!("Request error {e:?}")))?
+            .json::<Value>()
+            .await
+            .map_err(|e| other(format!("Json error: {e:?}")))
+    }
+    .await;
+
+    match result {
+        Ok(Value::Array(responses)) if responses.len() == responders.len() => {
+            for (responder, response) in responders.into_iter().zip(responses) {
+                let _ = responder.send(Ok(response));
+            }
+        }
+        // The node rejected the whole request, the error belongs to all operations.
+        Ok(response @ Value::Object(_)) => {
+            for responder in responders {
+                let _ = responder.send(Ok(response.clone()));
+            }
+        }
+        Ok(response) => {
+            for responder in responders {
+                let _ = responder
+                    .send(Err(other(format!("Unexpected batch response {response}"))));
+            }
+        }
+        Err(e) => {
+            for responder in responders {
+                let _ = responder.send(Err(other(e.to_string())));
+            }
+        }
+    }
+}
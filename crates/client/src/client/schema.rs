@@ -4,6 +4,11 @@ pub mod schema {
     cynic::use_schema!("./assets/schema.sdl");
 }
 
+/// The schema of the admin API with the privileged operations.
+pub mod admin_schema {
+    cynic::use_schema!("./assets/admin_schema.sdl");
+}
+
 use fuel_core_types::fuel_tx;
 use hex::FromHexError;
 use std::{
@@ -28,6 +33,7 @@ pub mod message;
 pub mod node_info;
 pub mod primitives;
 pub mod tx;
+pub mod txpool;
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
@@ -36,19 +42,35 @@ pub struct Health {
 }
 
 #[derive(cynic::QueryFragment, Debug)]
-#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Mutation")]
+#[cynic(
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema",
+    graphql_type = "Mutation"
+)]
 pub struct StartSession {
     pub start_session: cynic::Id,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema",
+    graphql_type = "Mutation"
+)]
+pub struct CreateCheckpoint {
+    pub create_checkpoint: String,
+}
+
 #[derive(cynic::QueryVariables)]
+#[cynic(schema_module = "admin_schema")]
 pub struct IdArg {
     pub id: cynic::Id,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
-    schema_path = "./assets/schema.sdl",
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema",
     graphql_type = "Mutation",
     variables = "IdArg"
 )]
@@ -59,7 +81,8 @@ pub struct EndSession {
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
-    schema_path = "./assets/schema.sdl",
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema",
     graphql_type = "Mutation",
     variables = "IdArg"
 )]
@@ -69,6 +92,7 @@ pub struct Reset {
 }
 
 #[derive(cynic::QueryVariables)]
+#[cynic(schema_module = "admin_schema")]
 pub struct ExecuteArgs {
     pub id: cynic::Id,
     pub op: String,
@@ -76,7 +100,8 @@ pub struct ExecuteArgs {
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
-    schema_path = "./assets/schema.sdl",
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema",
     graphql_type = "Mutation",
     variables = "ExecuteArgs"
 )]
@@ -86,6 +111,7 @@ pub struct Execute {
 }
 
 #[derive(cynic::QueryVariables)]
+#[cynic(schema_module = "admin_schema")]
 pub struct RegisterArgs {
     pub id: cynic::Id,
     pub register: U64,
@@ -93,7 +119,8 @@ pub struct RegisterArgs {
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
-    schema_path = "./assets/schema.sdl",
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema",
     graphql_type = "Query",
     variables = "RegisterArgs"
 )]
@@ -103,6 +130,7 @@ pub struct Register {
 }
 
 #[derive(cynic::QueryVariables)]
+#[cynic(schema_module = "admin_schema")]
 pub struct MemoryArgs {
     pub id: cynic::Id,
     pub start: U64,
@@ -111,7 +139,8 @@ pub struct MemoryArgs {
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
-    schema_path = "./assets/schema.sdl",
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema",
     graphql_type = "Query",
     variables = "MemoryArgs"
 )]
@@ -121,6 +150,7 @@ pub struct Memory {
 }
 
 #[derive(cynic::QueryVariables, Debug)]
+#[cynic(schema_module = "admin_schema")]
 pub struct SetBreakpointArgs {
     pub id: cynic::Id,
     pub bp: Breakpoint,
@@ -128,7 +158,8 @@ pub struct SetBreakpointArgs {
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
-    schema_path = "./assets/schema.sdl",
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema",
     graphql_type = "Mutation",
     variables = "SetBreakpointArgs"
 )]
@@ -138,13 +169,17 @@ pub struct SetBreakpoint {
 }
 
 #[derive(cynic::InputObject, Debug)]
-#[cynic(schema_path = "./assets/schema.sdl")]
+#[cynic(
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema"
+)]
 pub struct Breakpoint {
     pub contract: ContractId,
     pub pc: U64,
 }
 
 #[derive(cynic::QueryVariables, Debug)]
+#[cynic(schema_module = "admin_schema")]
 pub struct SetSingleSteppingArgs {
     pub id: cynic::Id,
     pub enable: bool,
@@ -152,7 +187,8 @@ pub struct SetSingleSteppingArgs {
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
-    schema_path = "./assets/schema.sdl",
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema",
     graphql_type = "Mutation",
     variables = "SetSingleSteppingArgs"
 )]
@@ -162,6 +198,7 @@ pub struct SetSingleStepping {
 }
 
 #[derive(cynic::QueryVariables, Debug)]
+#[cynic(schema_module = "admin_schema")]
 pub struct StartTxArgs {
     pub id: cynic::Id,
     pub tx: String,
@@ -169,7 +206,8 @@ pub struct StartTxArgs {
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
-    schema_path = "./assets/schema.sdl",
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema",
     graphql_type = "Mutation",
     variables = "StartTxArgs"
 )]
@@ -179,13 +217,15 @@ pub struct StartTx {
 }
 
 #[derive(cynic::QueryVariables, Debug)]
+#[cynic(schema_module = "admin_schema")]
 pub struct ContinueTxArgs {
     pub id: cynic::Id,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
-    schema_path = "./assets/schema.sdl",
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema",
     graphql_type = "Mutation",
     variables = "ContinueTxArgs"
 )]
@@ -195,7 +235,10 @@ pub struct ContinueTx {
 }
 
 #[derive(cynic::QueryFragment, Debug)]
-#[cynic(schema_path = "./assets/schema.sdl")]
+#[cynic(
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema"
+)]
 pub struct RunResult {
     pub breakpoint: Option<OutputBreakpoint>,
     pub json_receipts: Vec<String>,
@@ -221,7 +264,10 @@ impl fmt::Display for RunResult {
 }
 
 #[derive(cynic::QueryFragment, Debug)]
-#[cynic(schema_path = "./assets/schema.sdl")]
+#[cynic(
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema"
+)]
 pub struct OutputBreakpoint {
     pub contract: ContractId,
     pub pc: U64,
@@ -1,5 +1,6 @@
 use crate::client::{
     schema::{
+        admin_schema,
         schema,
         BlockId,
         ConnectionArgs,
@@ -49,6 +50,22 @@ pub struct BlockByHeightQuery {
     pub block: Option<Block>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct NewBlocksArgs {
+    pub from_height: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "NewBlocksArgs"
+)]
+pub struct NewBlocksSubscription {
+    #[arguments(fromHeight: $from_height)]
+    pub new_blocks: Block,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -101,6 +118,7 @@ pub struct BlockIdFragment {
 }
 
 #[derive(cynic::QueryVariables, Debug)]
+#[cynic(schema_module = "admin_schema")]
 pub struct ProduceBlockArgs {
     pub start_timestamp: Option<Tai64Timestamp>,
     pub blocks_to_produce: U64,
@@ -108,7 +126,8 @@ pub struct ProduceBlockArgs {
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
-    schema_path = "./assets/schema.sdl",
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema",
     variables = "ProduceBlockArgs",
     graphql_type = "Mutation"
 )]
@@ -194,6 +213,15 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn new_blocks_subscription_gql_output() {
+        use cynic::SubscriptionBuilder;
+        let operation = NewBlocksSubscription::build(NewBlocksArgs {
+            from_height: Some(U64(0)),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn block_mutation_query_gql_output() {
         use cynic::MutationBuilder;
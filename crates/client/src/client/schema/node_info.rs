@@ -1,4 +1,5 @@
 use crate::client::schema::{
+    admin_schema,
     schema,
     U64,
 };
@@ -12,6 +13,24 @@ pub struct NodeInfo {
     pub max_tx: U64,
     pub max_depth: U64,
     pub node_version: String,
+    pub database_options: Option<DatabaseOptions>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DatabaseOptions {
+    pub max_open_files: Option<i32>,
+    pub columns: Vec<DatabaseColumnOptions>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DatabaseColumnOptions {
+    pub column: String,
+    pub cache_size: Option<U64>,
+    pub compression: String,
+    pub bloom_filter_bits_per_key: Option<f64>,
+    pub write_buffer_size: Option<U64>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -20,6 +39,28 @@ pub struct QueryNodeInfo {
     pub node_info: NodeInfo,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema"
+)]
+pub struct DatabaseColumnStats {
+    pub column: String,
+    pub keys: U64,
+    pub bytes: U64,
+    pub internal_stats: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema",
+    graphql_type = "Query"
+)]
+pub struct QueryDatabaseStats {
+    pub database_stats: Vec<DatabaseColumnStats>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,4 +71,11 @@ mod tests {
         let operation = QueryNodeInfo::build(());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn database_stats_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryDatabaseStats::build(());
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -1,4 +1,7 @@
-use super::schema;
+use super::{
+    admin_schema,
+    schema,
+};
 use crate::client::schema::{
     ConversionError,
     ConversionError::HexStringPrefixError,
@@ -112,6 +115,8 @@ fuel_type_scalar!(TransactionId, Bytes32);
 fuel_type_scalar!(MessageId, MessageId);
 fuel_type_scalar!(Signature, Bytes64);
 fuel_type_scalar!(Nonce, Nonce);
+impl_scalar!(ContractId, admin_schema::ContractId);
+impl_scalar!(TransactionId, admin_schema::TransactionId);
 
 impl LowerHex for Nonce {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -237,6 +242,7 @@ impl Deref for Bytes {
 )]
 pub struct U64(pub u64);
 impl_scalar!(U64, schema::U64);
+impl_scalar!(U64, admin_schema::U64);
 
 impl Serialize for U64 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -275,6 +281,7 @@ impl From<U64> for InstructionResult {
 )]
 pub struct Tai64Timestamp(pub Tai64);
 impl_scalar!(Tai64Timestamp, schema::Tai64Timestamp);
+impl_scalar!(Tai64Timestamp, admin_schema::Tai64Timestamp);
 
 impl Tai64Timestamp {
     /// Convert Unix timestamp to `Tai64Timestamp`.
@@ -3,25 +3,37 @@ use crate::client::{
     schema::{
         schema,
         Address,
+        AssetId,
+        Bytes,
+        Bytes32,
         ConnectionArgs,
+        ContractId,
         ConversionError,
+        HexFormatted,
         HexString,
+        Nonce,
         PageInfo,
         Tai64Timestamp,
         TransactionId,
+        UtxoId,
+        U64,
+    },
+    types::{
+        DryRunResponse,
+        GasEstimateResponse,
+        IndexedReceiptResponse,
+        PredicateGasUsedResponse,
+        TransactionResponse,
     },
-    types::TransactionResponse,
     PageDirection,
     PaginatedResult,
     PaginationRequest,
 };
 use fuel_core_types::{
     fuel_tx,
-    fuel_types::{
-        bytes::Deserializable,
-        Bytes32,
-    },
+    fuel_types::bytes::Deserializable,
     fuel_vm,
+    services::executor::StateOverrides,
 };
 use std::convert::{
     TryFrom,
@@ -31,6 +43,8 @@ use std::convert::{
 pub mod transparent_receipt;
 pub mod transparent_tx;
 
+use transparent_receipt::ReceiptType;
+
 #[derive(cynic::QueryVariables, Debug)]
 pub struct TxIdArgs {
     pub id: TransactionId,
@@ -156,7 +170,7 @@ impl TryFrom<ProgramState> for fuel_vm::ProgramState {
                 u64::from_be_bytes(b)
             }),
             ReturnType::ReturnData => fuel_vm::ProgramState::ReturnData({
-                Bytes32::try_from(state.data.0 .0.as_slice())?
+                fuel_tx::Bytes32::try_from(state.data.0 .0.as_slice())?
             }),
             ReturnType::Revert => fuel_vm::ProgramState::Revert({
                 let b = state.data.0 .0;
@@ -256,6 +270,280 @@ pub struct TransactionsByOwnerQuery {
     pub transactions_by_owner: TransactionConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsByContractConnectionArgs {
+    /// Select transactions that use the `contract`
+    pub contract: ContractId,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(ContractId, PaginationRequest<String>)>
+    for TransactionsByContractConnectionArgs
+{
+    fn from(r: (ContractId, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => TransactionsByContractConnectionArgs {
+                contract: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results as i32),
+                last: None,
+            },
+            PageDirection::Backward => TransactionsByContractConnectionArgs {
+                contract: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results as i32),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsByContractConnectionArgs"
+)]
+pub struct TransactionsByContractQuery {
+    #[arguments(contract: $contract, after: $after, before: $before, first: $first, last: $last)]
+    pub transactions_by_contract: TransactionConnection,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsByAssetConnectionArgs {
+    /// Select transactions that move the `asset`
+    pub asset: AssetId,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(AssetId, PaginationRequest<String>)> for TransactionsByAssetConnectionArgs {
+    fn from(r: (AssetId, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => TransactionsByAssetConnectionArgs {
+                asset: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results as i32),
+                last: None,
+            },
+            PageDirection::Backward => TransactionsByAssetConnectionArgs {
+                asset: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results as i32),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsByAssetConnectionArgs"
+)]
+pub struct TransactionsByAssetQuery {
+    #[arguments(asset: $asset, after: $after, before: $before, first: $first, last: $last)]
+    pub transactions_by_asset: TransactionConnection,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ReceiptFilterInput {
+    /// Filter receipts emitted by the contract
+    pub contract: ContractId,
+    /// Filter receipts based on the `receipt_type` field
+    pub receipt_types: Option<Vec<ReceiptType>>,
+    /// Filter receipts based on the `rb` field
+    pub rb: Option<U64>,
+    /// Filter receipts included in the blocks starting from this height
+    pub from_height: Option<U64>,
+    /// Filter receipts included in the blocks up to this height
+    pub to_height: Option<U64>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ReceiptsConnectionArgs {
+    /// Filter receipts based on a filter
+    filter: ReceiptFilterInput,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n receipts in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n receipts in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(ReceiptFilterInput, PaginationRequest<String>)> for ReceiptsConnectionArgs {
+    fn from(r: (ReceiptFilterInput, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => ReceiptsConnectionArgs {
+                filter: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results as i32),
+                last: None,
+            },
+            PageDirection::Backward => ReceiptsConnectionArgs {
+                filter: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results as i32),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ReceiptsConnectionArgs"
+)]
+pub struct ReceiptsQuery {
+    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last)]
+    pub receipts: IndexedReceiptConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct IndexedReceiptConnection {
+    pub edges: Vec<IndexedReceiptEdge>,
+    pub page_info: PageInfo,
+    pub scan_cursor: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct IndexedReceiptEdge {
+    pub cursor: String,
+    pub node: IndexedReceipt,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct IndexedReceipt {
+    pub transaction_id: TransactionId,
+    pub block_height: U64,
+    pub receipt: OpaqueReceipt,
+}
+
+impl TryFrom<IndexedReceiptConnection>
+    for PaginatedResult<IndexedReceiptResponse, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(conn: IndexedReceiptConnection) -> Result<Self, Self::Error> {
+        let results: Result<Vec<IndexedReceiptResponse>, Self::Error> =
+            conn.edges.into_iter().map(|e| e.node.try_into()).collect();
+
+        Ok(PaginatedResult {
+            cursor: conn.scan_cursor.or(conn.page_info.end_cursor),
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: results?,
+        })
+    }
+}
+
+impl TryFrom<IndexedReceipt> for IndexedReceiptResponse {
+    type Error = ConversionError;
+
+    fn try_from(receipt: IndexedReceipt) -> Result<Self, Self::Error> {
+        Ok(Self {
+            transaction_id: receipt.transaction_id.into(),
+            block_height: receipt.block_height.0,
+            receipt: receipt.receipt.try_into()?,
+        })
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxArg"
+)]
+pub struct EstimateGasQuery {
+    #[arguments(tx: $tx)]
+    pub estimate_gas: GasEstimate,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct GasEstimate {
+    pub gas_used: U64,
+    pub predicate_gas_used: Vec<PredicateGasUsed>,
+    pub gas_limit: U64,
+    pub min_fee: U64,
+    pub max_fee: U64,
+    pub receipts: Vec<OpaqueReceipt>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PredicateGasUsed {
+    pub input_index: i32,
+    pub gas_used: U64,
+}
+
+impl TryFrom<PredicateGasUsed> for PredicateGasUsedResponse {
+    type Error = ConversionError;
+
+    fn try_from(predicate: PredicateGasUsed) -> Result<Self, Self::Error> {
+        Ok(Self {
+            input_index: predicate.input_index.try_into()?,
+            gas_used: predicate.gas_used.0,
+        })
+    }
+}
+
+impl TryFrom<GasEstimate> for GasEstimateResponse {
+    type Error = ConversionError;
+
+    fn try_from(estimate: GasEstimate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            gas_used: estimate.gas_used.0,
+            predicate_gas_used: estimate
+                .predicate_gas_used
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            gas_limit: estimate.gas_limit.0,
+            min_fee: estimate.min_fee.0,
+            max_fee: estimate.max_fee.0,
+            receipts: estimate
+                .receipts
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -267,6 +555,34 @@ pub struct StatusChangeSubscription {
     pub status_change: TransactionStatus,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Subscription")]
+pub struct NewTransactionsSubscription {
+    pub new_transactions: OpaqueTransaction,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Subscription")]
+pub struct TxPoolEventsSubscription {
+    pub txpool_events: TxPoolEvent,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolEvent {
+    pub transaction_id: TransactionId,
+    pub kind: TxPoolEventKind,
+    pub reason: Option<String>,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, Eq, PartialEq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum TxPoolEventKind {
+    Submitted,
+    Completed,
+    SqueezedOut,
+}
+
 // mutations
 
 #[derive(cynic::QueryVariables)]
@@ -278,6 +594,7 @@ pub struct TxArg {
 pub struct DryRunArg {
     pub tx: HexString,
     pub utxo_validation: Option<bool>,
+    pub state_overrides: Option<StateOverridesInput>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -287,10 +604,181 @@ pub struct DryRunArg {
     variables = "DryRunArg"
 )]
 pub struct DryRun {
-    #[arguments(tx: $tx, utxoValidation: $utxo_validation)]
+    #[arguments(tx: $tx, utxoValidation: $utxo_validation, stateOverrides: $state_overrides)]
     pub dry_run: Vec<transparent_receipt::Receipt>,
 }
 
+#[derive(cynic::QueryVariables)]
+pub struct DryRunManyArg {
+    pub txs: Vec<HexString>,
+    pub utxo_validation: Option<bool>,
+    pub state_overrides: Option<StateOverridesInput>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunManyArg"
+)]
+pub struct DryRunMany {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, stateOverrides: $state_overrides)]
+    pub dry_run_many: Vec<DryRunTransactionResult>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StateOverridesInput {
+    pub coins: Option<Vec<CoinOverrideInput>>,
+    pub messages: Option<Vec<MessageOverrideInput>>,
+    pub contract_state: Option<Vec<ContractStateOverrideInput>>,
+    pub contract_balances: Option<Vec<ContractBalanceOverrideInput>>,
+    pub contract_code: Option<Vec<ContractCodeOverrideInput>>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinOverrideInput {
+    pub utxo_id: UtxoId,
+    pub owner: Address,
+    pub amount: U64,
+    pub asset_id: AssetId,
+    pub maturity: Option<U64>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MessageOverrideInput {
+    pub sender: Address,
+    pub recipient: Address,
+    pub nonce: Nonce,
+    pub amount: U64,
+    pub data: Option<HexString>,
+    pub da_height: Option<U64>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStateOverrideInput {
+    pub contract_id: ContractId,
+    pub key: Bytes32,
+    pub value: Bytes32,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractBalanceOverrideInput {
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractCodeOverrideInput {
+    pub contract_id: ContractId,
+    pub bytecode: HexString,
+}
+
+impl From<&StateOverrides> for StateOverridesInput {
+    fn from(overrides: &StateOverrides) -> Self {
+        let coins = overrides
+            .coins
+            .iter()
+            .map(|coin| CoinOverrideInput {
+                utxo_id: UtxoId(HexFormatted(coin.utxo_id)),
+                owner: coin.owner.into(),
+                amount: coin.amount.into(),
+                asset_id: coin.asset_id.into(),
+                maturity: Some(u64::from(coin.maturity).into()),
+            })
+            .collect();
+        let messages = overrides
+            .messages
+            .iter()
+            .map(|message| MessageOverrideInput {
+                sender: message.sender.into(),
+                recipient: message.recipient.into(),
+                nonce: message.nonce.into(),
+                amount: message.amount.into(),
+                data: Some(HexString(Bytes(message.data.clone()))),
+                da_height: Some(message.da_height.0.into()),
+            })
+            .collect();
+        let contract_state = overrides
+            .contract_state
+            .iter()
+            .map(|(contract_id, key, value)| ContractStateOverrideInput {
+                contract_id: (*contract_id).into(),
+                key: (*key).into(),
+                value: (*value).into(),
+            })
+            .collect();
+        let contract_balances = overrides
+            .contract_balances
+            .iter()
+            .map(
+                |(contract_id, asset_id, amount)| ContractBalanceOverrideInput {
+                    contract_id: (*contract_id).into(),
+                    asset_id: (*asset_id).into(),
+                    amount: (*amount).into(),
+                },
+            )
+            .collect();
+        let contract_code = overrides
+            .contract_code
+            .iter()
+            .map(|(contract_id, code)| ContractCodeOverrideInput {
+                contract_id: (*contract_id).into(),
+                bytecode: HexString(Bytes(code.clone())),
+            })
+            .collect();
+
+        Self {
+            coins: Some(coins),
+            messages: Some(messages),
+            contract_state: Some(contract_state),
+            contract_balances: Some(contract_balances),
+            contract_code: Some(contract_code),
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunTransactionResult {
+    pub transaction_id: TransactionId,
+    pub status: DryRunStatus,
+    pub reason: Option<String>,
+    pub receipts: Vec<OpaqueReceipt>,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, Eq, PartialEq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum DryRunStatus {
+    Success,
+    Failure,
+    Invalid,
+}
+
+impl TryFrom<DryRunTransactionResult> for DryRunResponse {
+    type Error = ConversionError;
+
+    fn try_from(result: DryRunTransactionResult) -> Result<Self, Self::Error> {
+        Ok(Self {
+            transaction_id: result.transaction_id.into(),
+            success: result.status == DryRunStatus::Success,
+            executed: result.status != DryRunStatus::Invalid,
+            reason: result.reason,
+            receipts: result
+                .receipts
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -305,7 +793,6 @@ pub struct Submit {
 #[cfg(test)]
 pub mod tests {
     use super::*;
-    use crate::client::schema::Bytes;
     use fuel_core_types::fuel_types::bytes::SerializableVec;
 
     #[test]
@@ -352,6 +839,70 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn transactions_by_contract_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            TransactionsByContractQuery::build(TransactionsByContractConnectionArgs {
+                contract: Default::default(),
+                after: None,
+                before: None,
+                first: None,
+                last: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn transactions_by_asset_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            TransactionsByAssetQuery::build(TransactionsByAssetConnectionArgs {
+                asset: Default::default(),
+                after: None,
+                before: None,
+                first: None,
+                last: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn estimate_gas_query_gql_output() {
+        use cynic::QueryBuilder;
+        let mut tx = fuel_tx::Transaction::default();
+        let operation = EstimateGasQuery::build(TxArg {
+            tx: HexString(Bytes(tx.to_bytes())),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn receipts_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ReceiptsQuery::build(ReceiptsConnectionArgs {
+            filter: ReceiptFilterInput {
+                contract: Default::default(),
+                receipt_types: Some(vec![ReceiptType::Log, ReceiptType::LogData]),
+                rb: None,
+                from_height: None,
+                to_height: None,
+            },
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn txpool_events_subscription_gql_output() {
+        use cynic::SubscriptionBuilder;
+        let operation = TxPoolEventsSubscription::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn dry_run_tx_gql_output() {
         use cynic::MutationBuilder;
@@ -359,6 +910,19 @@ pub mod tests {
         let query = DryRun::build(DryRunArg {
             tx: HexString(Bytes(tx.to_bytes())),
             utxo_validation: None,
+            state_overrides: None,
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
+    #[test]
+    fn dry_run_many_gql_output() {
+        use cynic::MutationBuilder;
+        let mut tx = fuel_tx::Transaction::default();
+        let query = DryRunMany::build(DryRunManyArg {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+            utxo_validation: None,
+            state_overrides: None,
         });
         insta::assert_snapshot!(query.query)
     }
@@ -0,0 +1,205 @@
+use crate::client::{
+    schema::{
+        admin_schema,
+        schema,
+        tx::{
+            OpaqueTransaction,
+            TransactionConnection,
+        },
+        Address,
+        TransactionId,
+        U64,
+    },
+    PageDirection,
+    PaginationRequest,
+};
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct TxPoolStatsQuery {
+    pub txpool: TxPoolStats,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "TxPoolInfo")]
+pub struct TxPoolStats {
+    pub pending_count: U64,
+    pub consumable_gas: U64,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, Eq, PartialEq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum TxPoolOrder {
+    Price,
+    Time,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TxPoolTransactionsArgs {
+    /// The order of the transactions
+    pub order_by: TxPoolOrder,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(TxPoolOrder, PaginationRequest<String>)> for TxPoolTransactionsArgs {
+    fn from(r: (TxPoolOrder, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => TxPoolTransactionsArgs {
+                order_by: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results as i32),
+                last: None,
+            },
+            PageDirection::Backward => TxPoolTransactionsArgs {
+                order_by: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results as i32),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxPoolTransactionsArgs"
+)]
+pub struct TxPoolTransactionsQuery {
+    pub txpool: TxPoolTransactions,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "TxPoolInfo",
+    variables = "TxPoolTransactionsArgs"
+)]
+pub struct TxPoolTransactions {
+    #[arguments(orderBy: $order_by, after: $after, before: $before, first: $first, last: $last)]
+    pub transactions: TransactionConnection,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TxPoolByOwnerArgs {
+    pub owner: Address,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxPoolByOwnerArgs"
+)]
+pub struct TxPoolByOwnerQuery {
+    pub txpool: TxPoolByOwner,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "TxPoolInfo",
+    variables = "TxPoolByOwnerArgs"
+)]
+pub struct TxPoolByOwner {
+    #[arguments(owner: $owner)]
+    pub by_owner: Vec<OpaqueTransaction>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TxPoolDependenciesArgs {
+    pub tx_id: TransactionId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxPoolDependenciesArgs"
+)]
+pub struct TxPoolDependenciesQuery {
+    pub txpool: TxPoolDependencies,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "TxPoolInfo",
+    variables = "TxPoolDependenciesArgs"
+)]
+pub struct TxPoolDependencies {
+    #[arguments(txId: $tx_id)]
+    pub dependencies: Option<Vec<OpaqueTransaction>>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+#[cynic(schema_module = "admin_schema")]
+pub struct RemoveTransactionsArgs {
+    pub ids: Vec<TransactionId>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/admin_schema.sdl",
+    schema_module = "admin_schema",
+    variables = "RemoveTransactionsArgs",
+    graphql_type = "Mutation"
+)]
+pub struct RemoveTransactions {
+    #[arguments(ids: $ids)]
+    pub remove_transactions: Vec<TransactionId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn txpool_stats_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TxPoolStatsQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn txpool_transactions_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TxPoolTransactionsQuery::build(TxPoolTransactionsArgs {
+            order_by: TxPoolOrder::Price,
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn txpool_dependencies_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TxPoolDependenciesQuery::build(TxPoolDependenciesArgs {
+            tx_id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn remove_transactions_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = RemoveTransactions::build(RemoveTransactionsArgs {
+            ids: vec![TransactionId::default()],
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
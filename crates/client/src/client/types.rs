@@ -6,7 +6,11 @@ use crate::client::schema::{
     ConversionError,
 };
 use fuel_core_types::{
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Bytes32,
+        Receipt,
+        Transaction,
+    },
     fuel_types::bytes::Deserializable,
     fuel_vm::ProgramState,
 };
@@ -22,6 +26,53 @@ pub struct TransactionResponse {
     pub status: TransactionStatus,
 }
 
+/// The receipt with its position in the chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexedReceiptResponse {
+    pub transaction_id: Bytes32,
+    pub block_height: u64,
+    pub receipt: Receipt,
+}
+
+/// The result of the transaction executed by the dry run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DryRunResponse {
+    pub transaction_id: Bytes32,
+    /// `false` if the script of the transaction reverted or panicked,
+    /// or the transaction wasn't executed.
+    pub success: bool,
+    /// `false` if the transaction is invalid in the block and wasn't executed.
+    pub executed: bool,
+    /// The reason of the failure.
+    pub reason: Option<String>,
+    pub receipts: Vec<Receipt>,
+}
+
+/// The gas used by the predicate of the transaction input.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PredicateGasUsedResponse {
+    /// The index of the input with the predicate.
+    pub input_index: u8,
+    pub gas_used: u64,
+}
+
+/// The gas and the fees of the transaction estimated by the node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GasEstimateResponse {
+    /// The gas used by the script of the transaction.
+    pub gas_used: u64,
+    /// The gas used by each predicate of the transaction, in the order of the inputs.
+    pub predicate_gas_used: Vec<PredicateGasUsedResponse>,
+    /// The gas limit sufficient for the script and the predicates.
+    pub gas_limit: u64,
+    /// The fee for the metered bytes of the transaction.
+    pub min_fee: u64,
+    /// The fee for the metered bytes and the `gas_limit` of the transaction.
+    pub max_fee: u64,
+    /// The receipts of the dry run.
+    pub receipts: Vec<Receipt>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TransactionStatus {
     Submitted {
@@ -0,0 +1,267 @@
+//! The GraphQL subscriptions multiplexed over one WebSocket connection with the
+//! [`graphql-transport-ws`](https://github.com/enisdenjo/graphql-ws/blob/master/PROTOCOL.md)
+//! protocol.
+
+use super::other;
+use futures::{
+    SinkExt,
+    Stream,
+    StreamExt,
+};
+use serde::Deserialize;
+use serde_json::{
+    json,
+    Value,
+};
+use std::{
+    collections::HashMap,
+    io,
+    pin::Pin,
+    sync::atomic::{
+        AtomicU64,
+        Ordering,
+    },
+    task::{
+        Context,
+        Poll,
+    },
+};
+use tokio::{
+    net::TcpStream,
+    sync::{
+        mpsc,
+        Mutex,
+    },
+};
+use tokio_tungstenite::{
+    tungstenite::{
+        client::IntoClientRequest,
+        http::{
+            header::SEC_WEBSOCKET_PROTOCOL,
+            HeaderValue,
+        },
+        Message,
+    },
+    MaybeTlsStream,
+    WebSocketStream,
+};
+
+/// The path of the GraphQL WebSocket endpoint of the node.
+pub const GRAPHQL_WS_PATH: &str = "/graphql-ws";
+
+/// The WebSocket subprotocol used by the client.
+const GRAPHQL_TRANSPORT_WS: &str = "graphql-transport-ws";
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+type Subscriber = mpsc::UnboundedSender<io::Result<Value>>;
+
+/// The WebSocket connection shared by all subscriptions of the client. The connection
+/// is established with the first subscription and re-established after it is closed.
+#[derive(Debug)]
+pub(crate) struct WsConnection {
+    url: reqwest::Url,
+    commands: Mutex<Option<mpsc::UnboundedSender<Command>>>,
+    next_id: AtomicU64,
+}
+
+#[derive(Debug)]
+enum Command {
+    Subscribe {
+        id: String,
+        payload: Value,
+        subscriber: Subscriber,
+    },
+    Complete {
+        id: String,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ServerMessage {
+    ConnectionAck,
+    Next { id: String, payload: Value },
+    Error { id: String, payload: Value },
+    Complete { id: String },
+    Ping,
+    Pong,
+}
+
+impl WsConnection {
+    /// Creates the connection to the GraphQL endpoint at `url`. The scheme of the `url`
+    /// is replaced with the WebSocket one.
+    pub fn new(mut url: reqwest::Url) -> Self {
+        let scheme = if url.scheme() == "https" { "wss" } else { "ws" };
+        url.set_scheme(scheme)
+            .expect("Both HTTP and WebSocket schemes are special");
+        url.set_path(GRAPHQL_WS_PATH);
+        Self {
+            url,
+            commands: Mutex::new(None),
+            next_id: AtomicU64::new(0),
+        }
+    }
+
+    /// Starts the subscription with the serialized GraphQL `operation`.
+    /// The stream returns the GraphQL responses and ends when the server completes
+    /// the subscription or the connection is closed. Dropping the stream
+    /// completes the subscription on the server.
+    pub async fn subscribe(&self, operation: Value) -> io::Result<WsSubscription> {
+        let mut commands = self.commands.lock().await;
+        let sender = match commands.as_ref() {
+            Some(sender) if !sender.is_closed() => sender.clone(),
+            _ => {
+                let sender = connect(&self.url).await?;
+                *commands = Some(sender.clone());
+                sender
+            }
+        };
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed).to_string();
+        let (subscriber, receiver) = mpsc::unbounded_channel();
+        sender
+            .send(Command::Subscribe {
+                id: id.clone(),
+                payload: operation,
+                subscriber,
+            })
+            .map_err(|_| connection_closed())?;
+
+        Ok(WsSubscription {
+            id,
+            receiver,
+            commands: sender,
+        })
+    }
+}
+
+/// The stream of the GraphQL responses of one subscription.
+pub(crate) struct WsSubscription {
+    id: String,
+    receiver: mpsc::UnboundedReceiver<io::Result<Value>>,
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+impl Stream for WsSubscription {
+    type Item = io::Result<Value>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for WsSubscription {
+    fn drop(&mut self) {
+        let id = core::mem::take(&mut self.id);
+        let _ = self.commands.send(Command::Complete { id });
+    }
+}
+
+/// Opens the connection, waits for the acknowledgement of the server
+/// and spawns the task serving the subscriptions.
+async fn connect(url: &reqwest::Url) -> io::Result<mpsc::UnboundedSender<Command>> {
+    let mut request = url
+        .as_str()
+        .into_client_request()
+        .map_err(|e| other(format!("Invalid WebSocket request {e:?}")))?;
+    request.headers_mut().insert(
+        SEC_WEBSOCKET_PROTOCOL,
+        HeaderValue::from_static(GRAPHQL_TRANSPORT_WS),
+    );
+    let (mut socket, _) = tokio_tungstenite::connect_async(request)
+        .await
+        .map_err(|e| other(format!("Failed to connect {e:?}")))?;
+
+    send(&mut socket, json!({ "type": "connection_init" })).await?;
+    loop {
+        match socket.next().await {
+            Some(Ok(Message::Text(text))) => match serde_json::from_str(&text) {
+                Ok(ServerMessage::ConnectionAck) => break,
+                _ => return Err(other(format!("Unexpected message {text}"))),
+            },
+            Some(Ok(Message::Close(frame))) => {
+                return Err(other(format!("The connection was rejected {frame:?}")))
+            }
+            Some(Ok(_)) => continue,
+            Some(Err(e)) => return Err(other(format!("WebSocket error {e:?}"))),
+            None => return Err(connection_closed()),
+        }
+    }
+
+    let (sender, receiver) = mpsc::unbounded_channel();
+    tokio::spawn(run(socket, receiver));
+    Ok(sender)
+}
+
+/// Forwards the subscriptions of the client to the server and routes the responses
+/// of the server to the subscriptions until the connection is closed.
+async fn run(mut socket: Socket, mut commands: mpsc::UnboundedReceiver<Command>) {
+    let mut subscribers = HashMap::<String, Subscriber>::new();
+
+    let result: io::Result<()> = async {
+        loop {
+            tokio::select! {
+                command = commands.recv() => match command {
+                    Some(Command::Subscribe { id, payload, subscriber }) => {
+                        let message = json!({ "id": id, "type": "subscribe", "payload": payload });
+                        subscribers.insert(id, subscriber);
+                        send(&mut socket, message).await?;
+                    }
+                    Some(Command::Complete { id }) => {
+                        if subscribers.remove(&id).is_some() {
+                            send(&mut socket, json!({ "id": id, "type": "complete" })).await?;
+                        }
+                    }
+                    // All clients sharing the connection are dropped.
+                    None => return Ok(()),
+                },
+                message = socket.next() => match message {
+                    Some(Ok(Message::Text(text))) => match serde_json::from_str(&text) {
+                        Ok(ServerMessage::Next { id, payload }) => {
+                            if let Some(subscriber) = subscribers.get(&id) {
+                                let _ = subscriber.send(Ok(payload));
+                            }
+                        }
+                        Ok(ServerMessage::Error { id, payload }) => {
+                            if let Some(subscriber) = subscribers.remove(&id) {
+                                let _ = subscriber.send(Err(other(format!("Graphql error: {payload}"))));
+                            }
+                        }
+                        Ok(ServerMessage::Complete { id }) => {
+                            subscribers.remove(&id);
+                        }
+                        Ok(ServerMessage::Ping) => {
+                            send(&mut socket, json!({ "type": "pong" })).await?;
+                        }
+                        Ok(ServerMessage::ConnectionAck | ServerMessage::Pong) => {}
+                        Err(e) => tracing::warn!("Unexpected WebSocket message {text}: {e:?}"),
+                    },
+                    Some(Ok(Message::Close(_))) | None => return Ok(()),
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => return Err(other(format!("WebSocket error {e:?}"))),
+                },
+            }
+        }
+    }
+    .await;
+
+    if let Err(e) = result {
+        for subscriber in subscribers.into_values() {
+            let _ = subscriber.send(Err(other(e.to_string())));
+        }
+    }
+}
+
+async fn send(socket: &mut Socket, message: Value) -> io::Result<()> {
+    socket
+        .send(Message::Text(message.to_string()))
+        .await
+        .map_err(|e| other(format!("WebSocket error {e:?}")))
+}
+
+fn connection_closed() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::ConnectionAborted,
+        "The WebSocket connection was closed",
+    )
+}
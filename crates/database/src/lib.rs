@@ -35,6 +35,20 @@ pub enum Error {
         /// the database version expected by this build of fuel-core
         expected: u32,
     },
+    /// The database was created by a newer version of fuel-core.
+    #[error("The database version {found:#x} is newer than the latest supported version {supported:#x}")]
+    UnsupportedDatabaseVersion {
+        /// the current database version
+        found: u32,
+        /// the latest database version supported by this build of fuel-core
+        supported: u32,
+    },
+    /// The migration of the database to the version is not registered.
+    #[error("The migration of the database to the version {version:#x} is missing")]
+    MissingMigration {
+        /// the version of the database after the missing migration
+        version: u32,
+    },
     /// Not related to database error.
     #[error(transparent)]
     Other(#[from] anyhow::Error),
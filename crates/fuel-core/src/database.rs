@@ -2,6 +2,7 @@ use crate::{
     database::transaction::DatabaseTransaction,
     state::{
         in_memory::memory_store::MemoryStore,
+        ColumnStats,
         DataSource,
     },
 };
@@ -31,6 +32,7 @@ use std::{
         Formatter,
     },
     marker::Send,
+    path::Path,
     sync::Arc,
 };
 
@@ -42,9 +44,10 @@ type DatabaseResult<T> = Result<T>;
 
 // TODO: Extract `Database` and all belongs into `fuel-core-database`.
 #[cfg(feature = "rocksdb")]
-use crate::state::rocks_db::RocksDb;
-#[cfg(feature = "rocksdb")]
-use std::path::Path;
+use crate::state::{
+    rocks_db::RocksDb,
+    rocks_db_config::RocksDbConfig,
+};
 #[cfg(feature = "rocksdb")]
 use tempfile::TempDir;
 
@@ -54,26 +57,42 @@ mod block;
 mod code_root;
 mod contracts;
 mod message;
-mod receipts;
+mod pruning;
 #[cfg(feature = "relayer")]
 mod relayer;
 mod sealed_block;
+mod snapshot;
 mod state;
+mod state_history;
 
 pub(crate) mod coin;
 
 pub mod balances;
 pub mod metadata;
+pub mod migration;
+pub mod receipts;
 pub mod storage;
 pub mod transaction;
 pub mod transactions;
+pub mod txpool;
+pub mod verify;
 pub mod vm_database;
 
 /// Database tables column ids to the corresponding [`fuel_core_storage::Mappable`] table.
 #[repr(u32)]
 #[derive(
-    Copy, Clone, Debug, strum_macros::EnumCount, PartialEq, Eq, enum_iterator::Sequence,
+    Copy,
+    Clone,
+    Debug,
+    strum_macros::EnumCount,
+    strum_macros::Display,
+    strum_macros::EnumString,
+    PartialEq,
+    Eq,
+    Hash,
+    enum_iterator::Sequence,
 )]
+#[strum(serialize_all = "snake_case")]
 pub enum Column {
     /// The column id of metadata about the blockchain
     Metadata = 0,
@@ -128,6 +147,24 @@ pub enum Column {
     ContractsStateMerkleData = 23,
     /// See [`ContractsStateMerkleMetadata`](storage::ContractsStateMerkleMetadata)
     ContractsStateMerkleMetadata = 24,
+    /// The previous values of the versioned columns.
+    /// See [`HistoricalView`](crate::state::historical::HistoricalView)
+    StateHistory = 25,
+    /// The column of the table of all receipts emitted by the contract
+    ReceiptsByContractBlockIdx = 26,
+    /// The column of the table of all transactions that use the contract
+    TransactionsByContractBlockIdx = 27,
+    /// The column of the table of all transactions that move the asset
+    TransactionsByAssetBlockIdx = 28,
+    /// The journal of the transactions accepted by the transaction pool,
+    /// used to restore the pool after the restart
+    TxPoolJournal = 29,
+    /// The keys of the [`Column::StateHistory`] prefixed by the height of the block
+    /// that recorded them, used to prune the history of the pruned blocks
+    StateHistoryByHeight = 30,
+    /// The heights of the pruned blocks by the ids of the blocks and their transactions,
+    /// used to tell the pruned ids from the unknown ones
+    PrunedIds = 31,
 }
 
 #[derive(Clone, Debug)]
@@ -172,8 +209,36 @@ impl Drop for DropResources {
 impl Database {
     #[cfg(feature = "rocksdb")]
     pub fn open(path: &Path) -> DatabaseResult<Self> {
+        Self::open_with_config(path, &RocksDbConfig::default())
+    }
+
+    /// Opens the database at `path` with the columns tuned by the `config`.
+    #[cfg(feature = "rocksdb")]
+    pub fn open_with_config(path: &Path, config: &RocksDbConfig) -> DatabaseResult<Self> {
+        use anyhow::Context;
+        let db = RocksDb::open(path, enum_iterator::all::<Column>().collect(), config)
+            .context(format!("Failed to open rocksdb at {}", path.display()))?;
+
+        Ok(Database {
+            data: Arc::new(db),
+            _drop: Default::default(),
+        })
+    }
+
+    /// Opens the existing database at `path` without the write access.
+    /// The database doesn't see the changes made after opening.
+    #[cfg(feature = "rocksdb")]
+    pub fn open_read_only(path: &Path) -> DatabaseResult<Self> {
         use anyhow::Context;
-        let db = RocksDb::default_open(path).context("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db `rm -rf ~/.fuel/db`")?;
+        let db = RocksDb::open_read_only(
+            path,
+            enum_iterator::all::<Column>().collect(),
+            &RocksDbConfig::default(),
+        )
+        .context(format!(
+            "Failed to open rocksdb at {} in the read-only mode",
+            path.display()
+        ))?;
 
         Ok(Database {
             data: Arc::new(db),
@@ -181,6 +246,37 @@ impl Database {
         })
     }
 
+    /// Opens the database at `path` as a secondary instance next to the running node.
+    /// The database is read-only and follows the node via [`Self::try_catch_up_with_primary`].
+    #[cfg(feature = "rocksdb")]
+    pub fn open_secondary(path: &Path, config: &RocksDbConfig) -> DatabaseResult<Self> {
+        use anyhow::Context;
+        let secondary_dir = TempDir::new().map_err(|e| DatabaseError::Other(e.into()))?;
+        let db = RocksDb::open_secondary(
+            path,
+            secondary_dir.path(),
+            enum_iterator::all::<Column>().collect(),
+            config,
+        )
+        .context(format!(
+            "Failed to open rocksdb at {} as a secondary instance",
+            path.display()
+        ))?;
+
+        Ok(Database {
+            data: Arc::new(db),
+            _drop: Arc::new(
+                {
+                    move || {
+                        // cleanup the directory of the secondary instance
+                        drop(secondary_dir);
+                    }
+                }
+                .into(),
+            ),
+        })
+    }
+
     pub fn in_memory() -> Self {
         Self {
             data: Arc::new(MemoryStore::default()),
@@ -191,6 +287,52 @@ impl Database {
     pub fn transaction(&self) -> DatabaseTransaction {
         self.into()
     }
+
+    /// Creates a consistent copy of the database in the `path` directory.
+    /// The database continues to serve reads and writes during the checkpoint.
+    pub fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        self.data.checkpoint(path)
+    }
+
+    /// Applies the changes made by the primary instance of the database
+    /// to the secondary instance opened with [`Self::open_secondary`].
+    pub fn try_catch_up_with_primary(&self) -> DatabaseResult<()> {
+        self.data.try_catch_up_with_primary()
+    }
+
+    /// Returns the statistics of each column of the database.
+    /// The statistics are also exported as the metrics of the database.
+    pub fn stats(&self) -> DatabaseResult<Vec<(Column, ColumnStats)>> {
+        let stats = enum_iterator::all::<Column>()
+            .map(|column| Ok((column, self.data.column_stats(column)?)))
+            .collect::<DatabaseResult<Vec<_>>>()?;
+
+        #[cfg(feature = "metrics")]
+        for (column, column_stats) in &stats {
+            let labels = vec![("column".to_string(), column.to_string())];
+            fuel_core_metrics::core_metrics::DATABASE_METRICS
+                .column_keys
+                .get_or_create(&labels)
+                .set(column_stats.keys);
+            fuel_core_metrics::core_metrics::DATABASE_METRICS
+                .column_bytes
+                .get_or_create(&labels)
+                .set(column_stats.bytes);
+        }
+
+        Ok(stats)
+    }
+
+    /// Restores the database at `path` from the `checkpoint` and opens it.
+    #[cfg(feature = "rocksdb")]
+    pub fn restore_checkpoint(checkpoint: &Path, path: &Path) -> DatabaseResult<Self> {
+        let db = RocksDb::restore_checkpoint(checkpoint, path)?;
+
+        Ok(Database {
+            data: Arc::new(db),
+            _drop: Default::default(),
+        })
+    }
 }
 
 /// Mutable methods.
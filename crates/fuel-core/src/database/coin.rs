@@ -140,7 +140,17 @@ impl Database {
 
     pub fn get_coin_config(&self) -> DatabaseResult<Option<Vec<CoinConfig>>> {
         let configs = self
-            .iter_all::<Vec<u8>, CompressedCoin>(Column::Coins, None)
+            .iter_coin_configs()
+            .collect::<DatabaseResult<Vec<CoinConfig>>>()?;
+
+        Ok(Some(configs))
+    }
+
+    /// Iterates over *all* unspent coins without loading them into memory.
+    pub fn iter_coin_configs(
+        &self,
+    ) -> impl Iterator<Item = DatabaseResult<CoinConfig>> + '_ {
+        self.iter_all::<Vec<u8>, CompressedCoin>(Column::Coins, None)
             .map(|raw_coin| -> DatabaseResult<CoinConfig> {
                 let coin = raw_coin?;
 
@@ -161,8 +171,5 @@ impl Database {
                     asset_id: coin.1.asset_id,
                 })
             })
-            .collect::<DatabaseResult<Vec<CoinConfig>>>()?;
-
-        Ok(Some(configs))
     }
 }
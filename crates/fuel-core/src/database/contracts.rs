@@ -5,9 +5,14 @@ use crate::database::{
     Error as DatabaseError,
     Result as DatabaseResult,
 };
-use fuel_core_chain_config::ContractConfig;
+use fuel_core_chain_config::{
+    ContractBalanceConfig,
+    ContractConfig,
+    ContractStateConfig,
+};
 use fuel_core_storage::{
     iter::IterDirection,
+    not_found,
     tables::{
         ContractsInfo,
         ContractsLatestUtxo,
@@ -181,37 +186,9 @@ impl Database {
 
     pub fn get_contract_config(&self) -> StorageResult<Option<Vec<ContractConfig>>> {
         let configs = self
-            .iter_all::<Vec<u8>, Word>(Column::ContractsRawCode, None)
-            .map(|raw_contract_id| -> StorageResult<ContractConfig> {
-                let contract_id = ContractId::new(
-                    raw_contract_id.unwrap().0[..32]
-                        .try_into()
-                        .map_err(DatabaseError::from)?,
-                );
-
-                let code: Vec<u8> = self
-                    .storage::<ContractsRawCode>()
-                    .get(&contract_id)?
-                    .unwrap()
-                    .into_owned()
-                    .into();
-
-                let (salt, _) = self
-                    .storage::<ContractsInfo>()
-                    .get(&contract_id)
-                    .unwrap()
-                    .expect("Contract does not exist")
-                    .into_owned();
-
-                let ContractUtxoInfo {
-                    utxo_id,
-                    tx_pointer,
-                } = self
-                    .storage::<ContractsLatestUtxo>()
-                    .get(&contract_id)
-                    .unwrap()
-                    .expect("contract does not exist")
-                    .into_owned();
+            .iter_contract_configs()
+            .map(|config| -> StorageResult<ContractConfig> {
+                let (contract_id, config) = config?;
 
                 let state = Some(
                     self.iter_all_by_prefix::<Vec<u8>, Bytes32, _>(
@@ -252,20 +229,95 @@ impl Database {
                 );
 
                 Ok(ContractConfig {
-                    code,
-                    salt,
                     state,
                     balances,
-                    tx_id: Some(*utxo_id.tx_id()),
-                    output_index: Some(utxo_id.output_index()),
-                    tx_pointer_block_height: Some(tx_pointer.block_height().into()),
-                    tx_pointer_tx_idx: Some(tx_pointer.tx_index()),
+                    ..config
                 })
             })
             .collect::<StorageResult<Vec<ContractConfig>>>()?;
 
         Ok(Some(configs))
     }
+
+    /// Iterates over *alive* contracts without their state and balances.
+    pub fn iter_contract_configs(
+        &self,
+    ) -> impl Iterator<Item = StorageResult<(ContractId, ContractConfig)>> + '_ {
+        self.iter_all::<Vec<u8>, Word>(Column::ContractsRawCode, None)
+            .map(
+                |raw_contract_id| -> StorageResult<(ContractId, ContractConfig)> {
+                    let contract_id = ContractId::new(
+                        raw_contract_id?.0[..32]
+                            .try_into()
+                            .map_err(DatabaseError::from)?,
+                    );
+
+                    let code: Vec<u8> = self
+                        .storage::<ContractsRawCode>()
+                        .get(&contract_id)?
+                        .ok_or(not_found!(ContractsRawCode))?
+                        .into_owned()
+                        .into();
+
+                    let (salt, _) = self
+                        .storage::<ContractsInfo>()
+                        .get(&contract_id)?
+                        .ok_or(not_found!(ContractsInfo))?
+                        .into_owned();
+
+                    let ContractUtxoInfo {
+                        utxo_id,
+                        tx_pointer,
+                    } = self
+                        .storage::<ContractsLatestUtxo>()
+                        .get(&contract_id)?
+                        .ok_or(not_found!(ContractsLatestUtxo))?
+                        .into_owned();
+
+                    let config = ContractConfig {
+                        code,
+                        salt,
+                        state: None,
+                        balances: None,
+                        tx_id: Some(*utxo_id.tx_id()),
+                        output_index: Some(utxo_id.output_index()),
+                        tx_pointer_block_height: Some(tx_pointer.block_height().into()),
+                        tx_pointer_tx_idx: Some(tx_pointer.tx_index()),
+                    };
+                    Ok((contract_id, config))
+                },
+            )
+    }
+
+    /// Iterates over the state of all contracts.
+    pub fn iter_contract_state_configs(
+        &self,
+    ) -> impl Iterator<Item = DatabaseResult<ContractStateConfig>> + '_ {
+        self.iter_all::<Vec<u8>, Bytes32>(Column::ContractsState, None)
+            .map(|res| -> DatabaseResult<ContractStateConfig> {
+                let (key, value) = res?;
+                Ok(ContractStateConfig {
+                    contract_id: ContractId::new(key[..32].try_into()?),
+                    key: Bytes32::new(key[32..].try_into()?),
+                    value,
+                })
+            })
+    }
+
+    /// Iterates over the balances of all contracts.
+    pub fn iter_contract_balance_configs(
+        &self,
+    ) -> impl Iterator<Item = DatabaseResult<ContractBalanceConfig>> + '_ {
+        self.iter_all::<Vec<u8>, u64>(Column::ContractsAssets, None)
+            .map(|res| -> DatabaseResult<ContractBalanceConfig> {
+                let (key, amount) = res?;
+                Ok(ContractBalanceConfig {
+                    contract_id: ContractId::new(key[..32].try_into()?),
+                    asset_id: AssetId::new(key[32..].try_into()?),
+                    amount,
+                })
+            })
+    }
 }
 
 #[cfg(test)]
@@ -120,7 +120,17 @@ impl Database {
 
     pub fn get_message_config(&self) -> StorageResult<Option<Vec<MessageConfig>>> {
         let configs = self
-            .all_messages(None, None)
+            .iter_message_configs()
+            .collect::<StorageResult<Vec<MessageConfig>>>()?;
+
+        Ok(Some(configs))
+    }
+
+    /// Iterates over *all* unspent messages without loading them into memory.
+    pub fn iter_message_configs(
+        &self,
+    ) -> impl Iterator<Item = StorageResult<MessageConfig>> + '_ {
+        self.all_messages(None, None)
             .filter_map(|msg| {
                 // Return only unspent messages
                 if let Ok(msg) = msg {
@@ -145,9 +155,6 @@ impl Database {
                     da_height: msg.da_height,
                 })
             })
-            .collect::<StorageResult<Vec<MessageConfig>>>()?;
-
-        Ok(Some(configs))
     }
 
     pub fn is_message_spent(&self, id: &Nonce) -> StorageResult<bool> {
@@ -157,7 +164,10 @@ impl Database {
 
 // TODO: Reuse `fuel_vm::storage::double_key` macro.
 /// Get a Key by chaining Owner + Nonce
-fn owner_msg_id_key(owner: &Address, nonce: &Nonce) -> [u8; Address::LEN + Nonce::LEN] {
+pub(crate) fn owner_msg_id_key(
+    owner: &Address,
+    nonce: &Nonce,
+) -> [u8; Address::LEN + Nonce::LEN] {
     let mut default = [0u8; Address::LEN + Nonce::LEN];
     default[0..Address::LEN].copy_from_slice(owner.as_ref());
     default[Address::LEN..].copy_from_slice(nonce.as_ref());
@@ -9,12 +9,17 @@ use fuel_core_chain_config::ChainConfig;
 pub(crate) const DB_VERSION_KEY: &[u8] = b"version";
 pub(crate) const CHAIN_NAME_KEY: &[u8] = b"chain_name";
 
-/// Can be used to perform migrations in the future.
-pub(crate) const DB_VERSION: u32 = 0x00;
+/// The version of the database schema supported by this build.
+/// The database of the previous versions is migrated on start,
+/// see [`MIGRATIONS`](crate::database::migration::MIGRATIONS).
+pub(crate) const DB_VERSION: u32 = 0x02;
 
 impl Database {
     /// Ensures the database is initialized and that the database version is correct
     pub fn init(&self, config: &ChainConfig) -> DatabaseResult<()> {
+        // Migrate the database to the current version or refuse to use a newer one
+        self.migrate(false)?;
+
         // initialize chain name if not set
         if self.get_chain_name()?.is_none() {
             self.insert(CHAIN_NAME_KEY, Column::Metadata, &config.chain_name)
@@ -26,20 +31,28 @@ impl Database {
                     }
                 })?;
         }
+        Ok(())
+    }
 
-        // Ensure the database version is correct
-        if let Some(version) = self.get::<u32>(DB_VERSION_KEY, Column::Metadata)? {
-            if version != DB_VERSION {
-                return Err(DatabaseError::InvalidDatabaseVersion {
+    /// Ensures the database initialized by another node can be used without writes.
+    /// Unlike [`Self::init`], doesn't migrate the database.
+    pub fn init_read_only(&self) -> DatabaseResult<()> {
+        match self.version()? {
+            Some(version) if version == DB_VERSION => Ok(()),
+            Some(version) if version > DB_VERSION => {
+                Err(DatabaseError::UnsupportedDatabaseVersion {
                     found: version,
-                    expected: DB_VERSION,
-                })?
+                    supported: DB_VERSION,
+                })
             }
-        } else {
-            let _: Option<u32> =
-                self.insert(DB_VERSION_KEY, Column::Metadata, &DB_VERSION)?;
+            Some(version) => Err(DatabaseError::InvalidDatabaseVersion {
+                found: version,
+                expected: DB_VERSION,
+            }),
+            None => Err(DatabaseError::Other(anyhow::anyhow!(
+                "The database is not initialized"
+            ))),
         }
-        Ok(())
     }
 
     pub fn get_chain_name(&self) -> DatabaseResult<Option<String>> {
@@ -0,0 +1,523 @@
+use crate::database::{
+    metadata::{
+        DB_VERSION,
+        DB_VERSION_KEY,
+    },
+    Column,
+    Database,
+    Error as DatabaseError,
+    Result as DatabaseResult,
+};
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_types::blockchain::{
+    block::CompressedBlock,
+    primitives::{
+        BlockHeight,
+        BlockId,
+    },
+};
+use itertools::Itertools;
+use std::time::Instant;
+
+/// The height of the next block to be processed by the running backfill migration.
+pub(crate) const MIGRATION_PROGRESS_KEY: &[u8] = b"migration_progress";
+
+/// The number of blocks processed by the backfill migrations in one database transaction.
+const BACKFILL_BATCH_SIZE: usize = 1000;
+
+/// The result of one step of the migration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationStep {
+    /// The step is done and the migration requires more steps.
+    Continue,
+    /// The migration is finished.
+    Done,
+}
+
+/// Migrates the database from `version - 1` to `version`.
+pub struct Migration {
+    /// The version of the database after the migration.
+    pub version: u32,
+    /// The description of the changes, used for logging.
+    pub description: &'static str,
+    /// Updates the data of the database. The version is updated by the caller.
+    /// The migration is called until it returns [`MigrationStep::Done`], each step is
+    /// committed separately, so the long migrations don't accumulate all changes in memory.
+    pub migrate: fn(&mut Database) -> DatabaseResult<MigrationStep>,
+}
+
+/// The registry of the migrations sorted by the version.
+/// The version of the last migration is the [`DB_VERSION`] of this build.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Index the receipts by the contract",
+        migrate: |database| database.index_receipts_by_contract(),
+    },
+    Migration {
+        version: 2,
+        description: "Index the transactions by the contract and by the asset",
+        migrate: |database| database.index_transactions_by_contract_and_asset(),
+    },
+];
+
+impl Database {
+    /// Returns the version of the schema of the database.
+    /// `None` means that the database is not initialized yet.
+    pub fn version(&self) -> DatabaseResult<Option<u32>> {
+        self.get(DB_VERSION_KEY, Column::Metadata)
+    }
+
+    /// Applies all migrations required to bring the database to the [`DB_VERSION`].
+    /// Returns the applied migrations.
+    ///
+    /// Each step of the migration is committed separately and the last one is committed
+    /// along with the new version, so the interrupted process continues from the next step.
+    /// The `dry_run` applies the migrations without committing them.
+    pub fn migrate(&self, dry_run: bool) -> DatabaseResult<Vec<&'static Migration>> {
+        self.migrate_with(MIGRATIONS, DB_VERSION, dry_run)
+    }
+
+    fn migrate_with<'a>(
+        &self,
+        migrations: &'a [Migration],
+        latest_version: u32,
+        dry_run: bool,
+    ) -> DatabaseResult<Vec<&'a Migration>> {
+        let version = match self.version()? {
+            Some(version) => version,
+            None => {
+                // The new database doesn't require migrations.
+                if !dry_run {
+                    let _: Option<u32> =
+                        self.insert(DB_VERSION_KEY, Column::Metadata, &latest_version)?;
+                }
+                return Ok(vec![])
+            }
+        };
+
+        if version > latest_version {
+            return Err(DatabaseError::UnsupportedDatabaseVersion {
+                found: version,
+                supported: latest_version,
+            })
+        }
+
+        let mut applied = vec![];
+        let mut transaction = self.transaction();
+        for next_version in version + 1..=latest_version {
+            let migration = migrations
+                .iter()
+                .find(|migration| migration.version == next_version)
+                .ok_or(DatabaseError::MissingMigration {
+                    version: next_version,
+                })?;
+
+            tracing::info!(
+                "Migrating the database to the version {:#x} of {:#x}: {}",
+                migration.version,
+                latest_version,
+                migration.description
+            );
+            let start = Instant::now();
+            while (migration.migrate)(transaction.as_mut())? == MigrationStep::Continue {
+                if !dry_run {
+                    transaction.changes.commit()?;
+                    transaction = self.transaction();
+                }
+            }
+            let _: Option<u32> = transaction.insert(
+                DB_VERSION_KEY,
+                Column::Metadata,
+                &migration.version,
+            )?;
+            if !dry_run {
+                transaction.changes.commit()?;
+                transaction = self.transaction();
+            }
+            tracing::info!(
+                "Migrated the database to the version {:#x} in {:?}",
+                migration.version,
+                start.elapsed()
+            );
+            applied.push(migration);
+        }
+
+        if dry_run && !applied.is_empty() {
+            tracing::info!(
+                "Dry run: {} migrations were applied without committing",
+                applied.len()
+            );
+        }
+        Ok(applied)
+    }
+
+    /// Calls `index_block` for the next batch of blocks of the database. The height of
+    /// the next block is persisted, so the next call continues from it. Returns
+    /// [`MigrationStep::Done`] when all blocks are processed.
+    /// The blocks removed by the pruning are skipped.
+    pub(crate) fn backfill_blocks<F>(
+        &self,
+        index_block: F,
+    ) -> DatabaseResult<MigrationStep>
+    where
+        F: FnMut(&Database, BlockHeight, CompressedBlock) -> DatabaseResult<()>,
+    {
+        self.backfill_blocks_in_batches(BACKFILL_BATCH_SIZE, index_block)
+    }
+
+    fn backfill_blocks_in_batches<F>(
+        &self,
+        batch_size: usize,
+        mut index_block: F,
+    ) -> DatabaseResult<MigrationStep>
+    where
+        F: FnMut(&Database, BlockHeight, CompressedBlock) -> DatabaseResult<()>,
+    {
+        let start: Option<BlockHeight> =
+            self.get(MIGRATION_PROGRESS_KEY, Column::Metadata)?;
+        let mut blocks: Vec<(BlockHeight, BlockId)> = self
+            .all_block_ids(start, IterDirection::Forward)
+            .take(batch_size + 1)
+            .try_collect()?;
+        let next = if blocks.len() > batch_size {
+            blocks.pop().map(|(height, _)| height)
+        } else {
+            None
+        };
+
+        for (height, block_id) in blocks {
+            let block: Option<CompressedBlock> =
+                self.get(block_id.as_slice(), Column::FuelBlocks)?;
+            if let Some(block) = block {
+                index_block(self, height, block)?;
+            }
+        }
+
+        match next {
+            Some(next) => {
+                let _: Option<BlockHeight> =
+                    self.insert(MIGRATION_PROGRESS_KEY, Column::Metadata, &next)?;
+                Ok(MigrationStep::Continue)
+            }
+            None => {
+                let _: Option<BlockHeight> =
+                    self.remove(MIGRATION_PROGRESS_KEY, Column::Metadata)?;
+                Ok(MigrationStep::Done)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::{
+            FuelBlocks,
+            Receipts,
+            Transactions,
+        },
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        fuel_tx::{
+            Output,
+            Receipt,
+            Transaction,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+        fuel_types::{
+            AssetId,
+            ContractId,
+        },
+    };
+
+    const COUNTER_KEY: &[u8] = b"counter";
+
+    fn increment(database: &mut Database) -> DatabaseResult<MigrationStep> {
+        let counter: u32 = database
+            .get(COUNTER_KEY, Column::Metadata)?
+            .unwrap_or_default();
+        let _: Option<u32> =
+            database.insert(COUNTER_KEY, Column::Metadata, &(counter + 1))?;
+        Ok(MigrationStep::Done)
+    }
+
+    const TEST_MIGRATIONS: &[Migration] = &[
+        Migration {
+            version: 1,
+            description: "first",
+            migrate: increment,
+        },
+        Migration {
+            version: 2,
+            description: "second",
+            migrate: increment,
+        },
+    ];
+
+    fn database_at_version(version: u32) -> Database {
+        let database = Database::default();
+        let _: Option<u32> = database
+            .insert(DB_VERSION_KEY, Column::Metadata, &version)
+            .unwrap();
+        database
+    }
+
+    fn counter(database: &Database) -> Option<u32> {
+        database.get(COUNTER_KEY, Column::Metadata).unwrap()
+    }
+
+    /// Inserts the block with the transaction the way the executor of the version 0 did,
+    /// without the indexes added by the migrations.
+    fn insert_block(
+        database: &mut Database,
+        height: u32,
+        tx: &Transaction,
+        receipts: &[Receipt],
+    ) {
+        let header = PartialBlockHeader {
+            application: Default::default(),
+            consensus: ConsensusHeader::<Empty> {
+                height: height.into(),
+                ..Default::default()
+            },
+        };
+        let block = PartialFuelBlock::new(header, vec![tx.clone()]).generate(&[]);
+        database
+            .storage::<FuelBlocks>()
+            .insert(&block.id(), &block.compress())
+            .unwrap();
+        database
+            .storage::<Transactions>()
+            .insert(&tx.id(), tx)
+            .unwrap();
+        database
+            .storage::<Receipts>()
+            .insert(&tx.id(), receipts)
+            .unwrap();
+    }
+
+    #[test]
+    fn migrations_registry_ends_with_the_latest_version() {
+        for (index, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.version, index as u32 + 1);
+        }
+        assert_eq!(MIGRATIONS.len() as u32, DB_VERSION);
+    }
+
+    #[test]
+    fn new_database_gets_the_latest_version_without_migrations() {
+        let database = Database::default();
+
+        let applied = database.migrate_with(TEST_MIGRATIONS, 2, false).unwrap();
+
+        assert!(applied.is_empty());
+        assert_eq!(database.version().unwrap(), Some(2));
+        assert_eq!(counter(&database), None);
+    }
+
+    #[test]
+    fn pending_migrations_are_applied_in_order() {
+        let database = database_at_version(0);
+
+        let applied = database.migrate_with(TEST_MIGRATIONS, 2, false).unwrap();
+
+        assert_eq!(
+            applied.iter().map(|m| m.version).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(database.version().unwrap(), Some(2));
+        assert_eq!(counter(&database), Some(2));
+
+        // The migrated database doesn't require migrations anymore.
+        let applied = database.migrate_with(TEST_MIGRATIONS, 2, false).unwrap();
+        assert!(applied.is_empty());
+        assert_eq!(counter(&database), Some(2));
+    }
+
+    #[test]
+    fn only_migrations_after_the_current_version_are_applied() {
+        let database = database_at_version(1);
+
+        let applied = database.migrate_with(TEST_MIGRATIONS, 2, false).unwrap();
+
+        assert_eq!(applied.len(), 1);
+        assert_eq!(applied[0].version, 2);
+        assert_eq!(counter(&database), Some(1));
+    }
+
+    #[test]
+    fn dry_run_does_not_change_the_database() {
+        let database = database_at_version(0);
+
+        let applied = database.migrate_with(TEST_MIGRATIONS, 2, true).unwrap();
+
+        assert_eq!(applied.len(), 2);
+        assert_eq!(database.version().unwrap(), Some(0));
+        assert_eq!(counter(&database), None);
+    }
+
+    #[test]
+    fn newer_database_is_refused() {
+        let database = database_at_version(3);
+
+        let result = database.migrate_with(TEST_MIGRATIONS, 2, false);
+
+        assert!(matches!(
+            result,
+            Err(DatabaseError::UnsupportedDatabaseVersion {
+                found: 3,
+                supported: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn missing_migration_is_an_error() {
+        let database = database_at_version(0);
+
+        let result = database.migrate_with(&TEST_MIGRATIONS[1..], 2, false);
+
+        assert!(matches!(
+            result,
+            Err(DatabaseError::MissingMigration { version: 1 })
+        ));
+        assert_eq!(database.version().unwrap(), Some(0));
+    }
+
+    #[test]
+    fn gap_in_the_migrations_is_reported_with_the_missing_version() {
+        let migrations = &[
+            Migration {
+                version: 1,
+                description: "first",
+                migrate: increment,
+            },
+            Migration {
+                version: 3,
+                description: "third",
+                migrate: increment,
+            },
+        ];
+        let database = database_at_version(0);
+
+        let result = database.migrate_with(migrations, 3, false);
+
+        assert!(matches!(
+            result,
+            Err(DatabaseError::MissingMigration { version: 2 })
+        ));
+        // The migrations before the gap are kept.
+        assert_eq!(database.version().unwrap(), Some(1));
+        assert_eq!(counter(&database), Some(1));
+    }
+
+    #[test]
+    fn init_refuses_to_use_newer_database() {
+        let database = database_at_version(DB_VERSION + 1);
+
+        let result = database.init(&fuel_core_chain_config::ChainConfig::local_testnet());
+
+        assert!(matches!(
+            result,
+            Err(DatabaseError::UnsupportedDatabaseVersion { .. })
+        ));
+        assert_eq!(database.get_chain_name().unwrap(), None);
+    }
+
+    #[test]
+    fn backfill_processes_blocks_in_batches() {
+        let mut database = database_at_version(0);
+        for height in 0..5 {
+            let tx = TransactionBuilder::script(vec![], vec![])
+                .gas_limit(height as u64)
+                .finalize_as_transaction();
+            insert_block(&mut database, height, &tx, &[]);
+        }
+
+        let mut steps = vec![];
+        loop {
+            let mut batch = vec![];
+            let step = database
+                .backfill_blocks_in_batches(2, |_, height, _| {
+                    batch.push(u32::from(height));
+                    Ok(())
+                })
+                .unwrap();
+            steps.push((batch, step));
+            if step == MigrationStep::Done {
+                break
+            }
+        }
+
+        assert_eq!(
+            steps,
+            vec![
+                (vec![0, 1], MigrationStep::Continue),
+                (vec![2, 3], MigrationStep::Continue),
+                (vec![4], MigrationStep::Done),
+            ]
+        );
+        let progress: Option<BlockHeight> = database
+            .get(MIGRATION_PROGRESS_KEY, Column::Metadata)
+            .unwrap();
+        assert_eq!(progress, None);
+    }
+
+    #[test]
+    fn migration_from_v0_indexes_receipts_by_contract() {
+        let mut database = database_at_version(0);
+        let contract = ContractId::from([1; 32]);
+        let tx = TransactionBuilder::script(vec![], vec![]).finalize_as_transaction();
+        insert_block(
+            &mut database,
+            0,
+            &tx,
+            &[Receipt::log(contract, 0, 0, 0, 0, 0, 0)],
+        );
+
+        let applied = database.migrate(false).unwrap();
+
+        assert_eq!(applied.len(), MIGRATIONS.len());
+        assert_eq!(database.version().unwrap(), Some(DB_VERSION));
+        let receipts: Vec<_> = database
+            .contract_receipts(contract, None, None)
+            .map(|result| result.unwrap().1)
+            .collect();
+        assert_eq!(receipts, vec![tx.id()]);
+    }
+
+    #[test]
+    fn migration_from_v1_indexes_transactions_by_asset() {
+        let mut database = database_at_version(1);
+        let asset = AssetId::from([2; 32]);
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_output(Output::coin(Default::default(), 1, asset))
+            .finalize_as_transaction();
+        insert_block(&mut database, 0, &tx, &[]);
+
+        let applied = database.migrate(false).unwrap();
+
+        assert_eq!(
+            applied.iter().map(|m| m.version).collect::<Vec<_>>(),
+            vec![2]
+        );
+        assert_eq!(database.version().unwrap(), Some(DB_VERSION));
+        let txs: Vec<_> = database
+            .asset_transactions(asset, None, None)
+            .map(|result| result.unwrap().1)
+            .collect();
+        assert_eq!(txs, vec![tx.id()]);
+    }
+}
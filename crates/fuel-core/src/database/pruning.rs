@@ -0,0 +1,346 @@
+use crate::database::{
+    transactions::TransactionIndex,
+    Column,
+    Database,
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    tables::{
+        Receipts,
+        SealedBlockConsensus,
+        Transactions,
+    },
+    transactional::Transaction,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::CompressedBlock,
+        consensus::Consensus,
+        primitives::{
+            BlockHeight,
+            BlockId,
+        },
+    },
+    services::txpool::TransactionStatus,
+};
+use itertools::Itertools;
+
+pub(crate) const PRUNED_HEIGHT_KEY: &[u8] = b"pruned_height";
+
+/// The number of block ids loaded into memory at once during the pruning.
+const PRUNING_CHUNK_SIZE: usize = 1000;
+
+impl Database {
+    /// Returns the height of the last pruned block. All blocks at or below this height
+    /// were removed from the database along with their transactions and receipts.
+    pub fn pruned_height(&self) -> StorageResult<Option<BlockHeight>> {
+        self.get(PRUNED_HEIGHT_KEY, Column::Metadata)
+            .map_err(Into::into)
+    }
+
+    /// Returns the height of the pruned block with the `id`, or of the pruned block
+    /// that included the transaction with the `id`.
+    pub fn pruned_id_height(&self, id: &[u8]) -> StorageResult<Option<BlockHeight>> {
+        self.get(id, Column::PrunedIds).map_err(Into::into)
+    }
+
+    /// Returns `true` if the block at `height` was removed by the pruning.
+    pub fn is_pruned(&self, height: &BlockHeight) -> StorageResult<bool> {
+        Ok(matches!(self.pruned_height()?, Some(pruned) if *height <= pruned))
+    }
+
+    /// Removes all blocks below the `height` with their transactions, receipts, statuses
+    /// and their index entries: the transactions by owner, contract and asset, and the
+    /// receipts by contract. The state history is kept only for the heights above the
+    /// pruned blocks. Returns the number of pruned blocks.
+    ///
+    /// The UTXO and contract state is not affected. The block merkle tree and the
+    /// `BlockHeight -> BlockId` index are kept, so it is still possible to build the
+    /// proof of inclusion for pruned blocks. The consensus of the genesis block is kept
+    /// as well because it identifies the chain. The ids of the pruned blocks and
+    /// transactions are mapped to the height of the block, see [`Self::pruned_id_height`].
+    ///
+    /// Each block is removed in a separate database transaction together with the update of the
+    /// pruned height, so the interrupted pruning can be safely continued.
+    pub fn prune_blocks_below(&self, height: BlockHeight) -> StorageResult<u64> {
+        let mut pruned = 0;
+        loop {
+            let start = self.pruned_height()?.map(|pruned| pruned + 1u32.into());
+            let blocks: Vec<(BlockHeight, BlockId)> = self
+                .all_block_ids(start, IterDirection::Forward)
+                .take_while(|result| {
+                    !matches!(result, Ok((block_height, _)) if *block_height >= height)
+                })
+                .take(PRUNING_CHUNK_SIZE)
+                .try_collect()?;
+            if blocks.is_empty() {
+                break
+            }
+
+            for (block_height, block_id) in blocks {
+                let mut transaction = self.transaction();
+                transaction.as_mut().prune_block(&block_height, &block_id)?;
+                transaction.commit()?;
+                pruned += 1;
+            }
+        }
+
+        if pruned > 0 {
+            tracing::debug!("Pruned {} blocks below height {}", pruned, height);
+        }
+
+        Ok(pruned)
+    }
+
+    fn prune_block(
+        &mut self,
+        height: &BlockHeight,
+        block_id: &BlockId,
+    ) -> StorageResult<()> {
+        // `StorageMutate<FuelBlocks>::remove` also cleans up the height index and the merkle
+        // metadata, which should survive the pruning. So remove the block from the column directly.
+        let block: Option<CompressedBlock> =
+            Database::remove(self, block_id.as_slice(), Column::FuelBlocks)?;
+
+        if let Some(block) = block {
+            for (tx_idx, tx_id) in block.transactions().iter().enumerate() {
+                let tx_idx = tx_idx as TransactionIndex;
+                if let Some(tx) = self.storage::<Transactions>().remove(tx_id)? {
+                    self.remove_tx_id_owners(&tx, *height, tx_idx)?;
+                    self.remove_tx_id_contracts_and_assets(&tx, *height, tx_idx)?;
+                }
+                if let Some(receipts) = self.storage::<Receipts>().remove(tx_id)? {
+                    self.remove_contract_receipts(*height, tx_idx, &receipts)?;
+                }
+                let _: Option<TransactionStatus> =
+                    Database::remove(self, tx_id.as_ref(), Column::TransactionStatus)?;
+                let _: Option<BlockHeight> =
+                    self.insert(tx_id.as_ref(), Column::PrunedIds, height)?;
+            }
+        }
+        let _: Option<BlockHeight> =
+            self.insert(block_id.as_slice(), Column::PrunedIds, height)?;
+
+        let consensus = StorageAsRef::storage::<SealedBlockConsensus>(self)
+            .get(block_id)?
+            .map(|consensus| consensus.into_owned());
+        if !matches!(consensus, Some(Consensus::Genesis(_))) {
+            self.storage::<SealedBlockConsensus>().remove(block_id)?;
+        }
+
+        self.prune_state_history(height)?;
+
+        let _: Option<BlockHeight> =
+            self.insert(PRUNED_HEIGHT_KEY, Column::Metadata, height)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::FuelBlocks,
+        StorageInspect,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::{
+                Block,
+                PartialFuelBlock,
+            },
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        fuel_tx::{
+            Address,
+            Output,
+            Receipt,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+        fuel_vm::crypto::ephemeral_merkle_root,
+    };
+
+    const OWNER: Address = Address::new([1; 32]);
+
+    fn insert_blocks(database: &mut Database, count: u32) -> Vec<Block> {
+        (0..count)
+            .map(|height| {
+                let header = PartialBlockHeader {
+                    application: Default::default(),
+                    consensus: ConsensusHeader::<Empty> {
+                        height: height.into(),
+                        ..Default::default()
+                    },
+                };
+                let tx = TransactionBuilder::script(vec![], vec![])
+                    .gas_limit(height as u64)
+                    .add_output(Output::coin(OWNER, 1, Default::default()))
+                    .finalize_as_transaction();
+                let block = PartialFuelBlock::new(header, vec![tx.clone()]).generate(&[]);
+                let tx_id = tx.id();
+                database
+                    .storage::<FuelBlocks>()
+                    .insert(&block.id(), &block.compress())
+                    .unwrap();
+                database
+                    .storage::<Transactions>()
+                    .insert(&tx_id, &tx)
+                    .unwrap();
+                let receipts = [Receipt::ret(Default::default(), 0, 0, 0)];
+                database
+                    .storage::<Receipts>()
+                    .insert(&tx_id, &receipts)
+                    .unwrap();
+                database
+                    .record_contract_receipts(height.into(), 0, &tx_id, &receipts)
+                    .unwrap();
+                database
+                    .record_tx_id_owner(&OWNER, height.into(), 0, &tx_id)
+                    .unwrap();
+                database
+                    .record_tx_id_contracts_and_assets(&tx, height.into(), 0, &tx_id)
+                    .unwrap();
+                block
+            })
+            .collect()
+    }
+
+    #[test]
+    fn prune_blocks_below_removes_only_old_blocks() {
+        let mut database = Database::default();
+        let blocks = insert_blocks(&mut database, 5);
+
+        let pruned = database.prune_blocks_below(3u32.into()).unwrap();
+
+        assert_eq!(pruned, 3);
+        assert_eq!(database.pruned_height().unwrap(), Some(2u32.into()));
+        for block in &blocks[..3] {
+            assert!(!database
+                .storage::<FuelBlocks>()
+                .contains_key(&block.id())
+                .unwrap());
+            assert!(database.is_pruned(block.header().height()).unwrap());
+        }
+        for block in &blocks[3..] {
+            assert!(database
+                .storage::<FuelBlocks>()
+                .contains_key(&block.id())
+                .unwrap());
+            assert!(!database.is_pruned(block.header().height()).unwrap());
+        }
+    }
+
+    #[test]
+    fn prune_blocks_below_removes_transactions_and_receipts() {
+        let mut database = Database::default();
+        let blocks = insert_blocks(&mut database, 2);
+        let tx_id = blocks[0].transactions()[0].id();
+
+        database.prune_blocks_below(1u32.into()).unwrap();
+
+        assert!(
+            !StorageInspect::<Transactions>::contains_key(&database, &tx_id).unwrap()
+        );
+        assert!(!StorageInspect::<Receipts>::contains_key(&database, &tx_id).unwrap());
+    }
+
+    #[test]
+    fn prune_blocks_below_keeps_heights_of_pruned_ids() {
+        let mut database = Database::default();
+        let blocks = insert_blocks(&mut database, 3);
+
+        database.prune_blocks_below(2u32.into()).unwrap();
+
+        for block in &blocks {
+            let height = *block.header().height();
+            let expected = database.is_pruned(&height).unwrap().then_some(height);
+            let tx_id = block.transactions()[0].id();
+            assert_eq!(
+                database.pruned_id_height(block.id().as_slice()).unwrap(),
+                expected
+            );
+            assert_eq!(database.pruned_id_height(tx_id.as_ref()).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn prune_blocks_below_removes_owner_index_entries() {
+        let mut database = Database::default();
+        let blocks = insert_blocks(&mut database, 3);
+
+        database.prune_blocks_below(2u32.into()).unwrap();
+
+        let owned: Vec<_> = database
+            .owned_transactions(OWNER, None, None)
+            .map(|result| result.unwrap().1)
+            .collect();
+        assert_eq!(owned, vec![blocks[2].transactions()[0].id()]);
+    }
+
+    #[test]
+    fn prune_blocks_below_removes_asset_index_entries() {
+        let mut database = Database::default();
+        let blocks = insert_blocks(&mut database, 3);
+
+        database.prune_blocks_below(2u32.into()).unwrap();
+
+        let txs: Vec<_> = database
+            .asset_transactions(Default::default(), None, None)
+            .map(|result| result.unwrap().1)
+            .collect();
+        assert_eq!(txs, vec![blocks[2].transactions()[0].id()]);
+    }
+
+    #[test]
+    fn prune_blocks_below_removes_contract_receipts_index_entries() {
+        let mut database = Database::default();
+        let blocks = insert_blocks(&mut database, 3);
+
+        database.prune_blocks_below(2u32.into()).unwrap();
+
+        let receipts: Vec<_> = database
+            .contract_receipts(Default::default(), None, None)
+            .map(|result| result.unwrap().1)
+            .collect();
+        assert_eq!(receipts, vec![blocks[2].transactions()[0].id()]);
+    }
+
+    #[test]
+    fn prune_blocks_below_keeps_block_merkle_data() {
+        let mut database = Database::default();
+        let blocks = insert_blocks(&mut database, 4);
+
+        database.prune_blocks_below(3u32.into()).unwrap();
+
+        let expected_root = ephemeral_merkle_root(blocks.iter().map(|block| block.id()));
+        let actual_root = fuel_core_storage::MerkleRootStorage::<_, FuelBlocks>::root(
+            &database,
+            &3u32.into(),
+        )
+        .unwrap();
+        assert_eq!(expected_root, actual_root.into());
+        assert_eq!(
+            database.get_block_id(&0u32.into()).unwrap(),
+            Some(blocks[0].id())
+        );
+    }
+
+    #[test]
+    fn prune_blocks_below_continues_from_the_last_pruned_height() {
+        let mut database = Database::default();
+        insert_blocks(&mut database, 6);
+
+        assert_eq!(database.prune_blocks_below(2u32.into()).unwrap(), 2);
+        assert_eq!(database.prune_blocks_below(2u32.into()).unwrap(), 0);
+        assert_eq!(database.prune_blocks_below(5u32.into()).unwrap(), 3);
+        assert_eq!(database.pruned_height().unwrap(), Some(4u32.into()));
+    }
+}
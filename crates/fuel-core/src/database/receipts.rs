@@ -1,11 +1,262 @@
 use crate::database::{
+    migration::MigrationStep,
     storage::DatabaseColumn,
+    transactions::TransactionIndex,
     Column,
+    Database,
+    Result as DatabaseResult,
 };
-use fuel_core_storage::tables::Receipts;
+use fuel_core_storage::{
+    iter::IterDirection,
+    tables::Receipts,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockHeight,
+    fuel_tx::{
+        Bytes32,
+        Receipt,
+    },
+    fuel_types::ContractId,
+};
+use std::mem::size_of;
 
 impl DatabaseColumn for Receipts {
     fn column() -> Column {
         Column::Receipts
     }
 }
+
+impl Database {
+    /// Iterates over a KV mapping of `[contract id + block height + tx idx + receipt idx] => transaction id`.
+    /// This allows for efficient lookup of receipts emitted by the contract, sorted by
+    /// block age, ordering within a block and ordering within a transaction. The cursor tracks
+    /// the `[block height + tx idx + receipt idx]` for pagination purposes.
+    pub fn contract_receipts(
+        &self,
+        contract: ContractId,
+        start: Option<ContractReceiptIndexCursor>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = DatabaseResult<(ContractReceiptIndexCursor, Bytes32)>> + '_
+    {
+        let start = start.map(|cursor| {
+            contract_receipt_index_key(
+                &contract,
+                cursor.block_height,
+                cursor.tx_idx,
+                cursor.receipt_idx,
+            )
+        });
+        self.iter_all_filtered::<ContractReceiptIndexCursor, Bytes32, _, _>(
+            Column::ReceiptsByContractBlockIdx,
+            Some(contract),
+            start,
+            direction,
+        )
+    }
+
+    /// Associates the receipts of the transaction with the contracts that emitted them.
+    pub fn record_contract_receipts(
+        &self,
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+        tx_id: &Bytes32,
+        receipts: &[Receipt],
+    ) -> DatabaseResult<()> {
+        for (receipt_idx, receipt) in receipts.iter().enumerate() {
+            if let Some(contract) = receipt_contract(receipt) {
+                let _: Option<Bytes32> = self.insert(
+                    contract_receipt_index_key(
+                        &contract,
+                        block_height,
+                        tx_idx,
+                        receipt_idx as ReceiptIndex,
+                    ),
+                    Column::ReceiptsByContractBlockIdx,
+                    tx_id,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the index entries of the receipts of the transaction.
+    pub fn remove_contract_receipts(
+        &self,
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+        receipts: &[Receipt],
+    ) -> DatabaseResult<()> {
+        for (receipt_idx, receipt) in receipts.iter().enumerate() {
+            if let Some(contract) = receipt_contract(receipt) {
+                let _: Option<Bytes32> = self.remove(
+                    &contract_receipt_index_key(
+                        &contract,
+                        block_height,
+                        tx_idx,
+                        receipt_idx as ReceiptIndex,
+                    ),
+                    Column::ReceiptsByContractBlockIdx,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds the index of the receipts by the contract for the next batch of blocks
+    /// of the database. Receipts removed by the pruning are skipped.
+    pub(crate) fn index_receipts_by_contract(&self) -> DatabaseResult<MigrationStep> {
+        self.backfill_blocks(|database, height, block| {
+            for (tx_idx, tx_id) in block.transactions().iter().enumerate() {
+                let receipts: Vec<Receipt> =
+                    match database.get(tx_id.as_ref(), Column::Receipts)? {
+                        Some(receipts) => receipts,
+                        None => continue,
+                    };
+                database.record_contract_receipts(
+                    height,
+                    tx_idx as TransactionIndex,
+                    tx_id,
+                    &receipts,
+                )?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Returns the contract that emitted the receipt. The receipts emitted by the script
+/// belong to the zeroed contract id. The `MessageOut` receipt belongs to its sender.
+pub fn receipt_contract(receipt: &Receipt) -> Option<ContractId> {
+    match receipt {
+        Receipt::MessageOut { sender, .. } => Some(ContractId::from(**sender)),
+        receipt => receipt.id().copied(),
+    }
+}
+
+const RECEIPT_INDEX_SIZE: usize = size_of::<ReceiptIndex>();
+const TX_INDEX_SIZE: usize = size_of::<TransactionIndex>();
+const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
+const INDEX_SIZE: usize =
+    ContractId::LEN + BLOCK_HEIGHT + TX_INDEX_SIZE + RECEIPT_INDEX_SIZE;
+
+fn contract_receipt_index_key(
+    contract: &ContractId,
+    height: BlockHeight,
+    tx_idx: TransactionIndex,
+    receipt_idx: ReceiptIndex,
+) -> [u8; INDEX_SIZE] {
+    const TX_INDEX_OFFSET: usize = ContractId::LEN + BLOCK_HEIGHT;
+    const RECEIPT_INDEX_OFFSET: usize = TX_INDEX_OFFSET + TX_INDEX_SIZE;
+
+    let mut default = [0u8; INDEX_SIZE];
+    // generate prefix to enable sorted indexing of receipts by contract
+    // contract + block_height + tx_idx + receipt_idx
+    default[0..ContractId::LEN].copy_from_slice(contract.as_ref());
+    default[ContractId::LEN..TX_INDEX_OFFSET].copy_from_slice(height.to_bytes().as_ref());
+    default[TX_INDEX_OFFSET..RECEIPT_INDEX_OFFSET]
+        .copy_from_slice(tx_idx.to_be_bytes().as_ref());
+    default[RECEIPT_INDEX_OFFSET..].copy_from_slice(receipt_idx.to_be_bytes().as_ref());
+    default
+}
+
+////////////////////////////////////// Not storage part //////////////////////////////////////
+
+pub type ReceiptIndex = u32;
+
+/// The position of the receipt in the chain.
+#[derive(Clone, Copy, Debug, PartialOrd, Ord, Eq, PartialEq)]
+pub struct ContractReceiptIndexCursor {
+    pub block_height: BlockHeight,
+    pub tx_idx: TransactionIndex,
+    pub receipt_idx: ReceiptIndex,
+}
+
+impl From<Vec<u8>> for ContractReceiptIndexCursor {
+    fn from(bytes: Vec<u8>) -> Self {
+        // the first 32 bytes are the contract, which is already known when querying
+        let bytes = &bytes[ContractId::LEN..];
+        let mut block_height_bytes: [u8; 4] = Default::default();
+        block_height_bytes.copy_from_slice(&bytes[..4]);
+        let mut tx_idx_bytes: [u8; 2] = Default::default();
+        tx_idx_bytes.copy_from_slice(&bytes[4..6]);
+        let mut receipt_idx_bytes: [u8; 4] = Default::default();
+        receipt_idx_bytes.copy_from_slice(&bytes[6..10]);
+
+        Self {
+            block_height: u32::from_be_bytes(block_height_bytes).into(),
+            tx_idx: u16::from_be_bytes(tx_idx_bytes),
+            receipt_idx: u32::from_be_bytes(receipt_idx_bytes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_types::Address;
+
+    fn log(contract: ContractId, rb: u64) -> Receipt {
+        Receipt::log(contract, 0, rb, 0, 0, 0, 0)
+    }
+
+    #[test]
+    fn contract_receipts_are_sorted_by_position() {
+        let db = Database::default();
+        let contract = ContractId::from([1; 32]);
+        let other = ContractId::from([2; 32]);
+        let tx_1 = Bytes32::from([1; 32]);
+        let tx_2 = Bytes32::from([2; 32]);
+
+        db.record_contract_receipts(
+            2u32.into(),
+            1,
+            &tx_2,
+            &[log(contract, 3), log(other, 0), log(contract, 4)],
+        )
+        .unwrap();
+        db.record_contract_receipts(1u32.into(), 1, &tx_1, &[log(contract, 1)])
+            .unwrap();
+
+        let receipts: Vec<_> = db
+            .contract_receipts(contract, None, None)
+            .map(|result| {
+                let (cursor, tx_id) = result.unwrap();
+                (u32::from(cursor.block_height), cursor.receipt_idx, tx_id)
+            })
+            .collect();
+        assert_eq!(receipts, vec![(1, 0, tx_1), (2, 0, tx_2), (2, 2, tx_2)]);
+
+        let start = ContractReceiptIndexCursor {
+            block_height: 2u32.into(),
+            tx_idx: 1,
+            receipt_idx: 1,
+        };
+        let receipts: Vec<_> = db
+            .contract_receipts(contract, Some(start), None)
+            .map(|result| result.unwrap().0.receipt_idx)
+            .collect();
+        assert_eq!(receipts, vec![2]);
+    }
+
+    #[test]
+    fn message_out_belongs_to_the_sender() {
+        let sender = Address::from([3; 32]);
+        let receipt = Receipt::message_out(
+            sender,
+            Address::default(),
+            0,
+            Default::default(),
+            Default::default(),
+            vec![],
+        );
+
+        assert_eq!(receipt_contract(&receipt), Some(ContractId::from([3; 32])));
+        assert_eq!(
+            receipt_contract(&Receipt::script_result(
+                fuel_core_types::fuel_tx::ScriptExecutionResult::Success,
+                0
+            )),
+            None
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use crate::database::{
+    Column,
+    Database,
+    Result as DatabaseResult,
+};
+use fuel_core_chain_config::{
+    ContractBalanceConfig,
+    ContractConfig,
+    ContractStateConfig,
+    SnapshotManifest,
+    SnapshotTable,
+    SnapshotWriter,
+};
+use fuel_core_storage::{
+    not_found,
+    tables::{
+        ContractsInfo,
+        ContractsRawCode,
+    },
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::fuel_types::ContractId;
+use std::path::Path;
+
+/// The last chunk of the snapshot imported at genesis.
+const GENESIS_SNAPSHOT_PROGRESS_KEY: &[u8] = b"genesis_snapshot_progress";
+
+impl Database {
+    /// Exports the latest state into the snapshot directory chunk by chunk.
+    ///
+    /// The state and balances of the contracts refer to the ids of the contracts
+    /// created from the snapshot at genesis.
+    pub fn export_snapshot(
+        &self,
+        dir: &Path,
+        chunk_size: usize,
+    ) -> anyhow::Result<SnapshotManifest> {
+        let mut writer = SnapshotWriter::new(dir, self.latest_height()?, chunk_size)?;
+        writer.write_table(self.iter_coin_configs())?;
+        writer.write_table(self.iter_message_configs())?;
+        writer.write_table(
+            self.iter_contract_configs()
+                .map(|config| config.map(|(_, config)| config)),
+        )?;
+
+        let mut ids = GenesisContractIds::new(self);
+        writer.write_table(self.iter_contract_state_configs().map(|config| {
+            let config = config?;
+            anyhow::Ok(ContractStateConfig {
+                contract_id: ids.genesis_id(&config.contract_id)?,
+                ..config
+            })
+        }))?;
+        writer.write_table(self.iter_contract_balance_configs().map(|config| {
+            let config = config?;
+            anyhow::Ok(ContractBalanceConfig {
+                contract_id: ids.genesis_id(&config.contract_id)?,
+                ..config
+            })
+        }))?;
+
+        writer.finish()
+    }
+
+    /// Returns the last chunk of the snapshot imported into the database at genesis.
+    pub fn genesis_snapshot_progress(
+        &self,
+    ) -> DatabaseResult<Option<(SnapshotTable, usize)>> {
+        self.get(GENESIS_SNAPSHOT_PROGRESS_KEY, Column::Metadata)
+    }
+
+    /// Marks the chunk at `index` of the `table` as imported.
+    pub fn set_genesis_snapshot_progress(
+        &self,
+        table: SnapshotTable,
+        index: usize,
+    ) -> DatabaseResult<()> {
+        let _: Option<(SnapshotTable, usize)> = self.insert(
+            GENESIS_SNAPSHOT_PROGRESS_KEY,
+            Column::Metadata,
+            &(table, index),
+        )?;
+        Ok(())
+    }
+}
+
+/// Maps the ids of the contracts in the database to the ids they get at genesis.
+/// The entries of the contract tables are sorted by the contract id,
+/// so it is enough to remember the last contract.
+struct GenesisContractIds<'a> {
+    database: &'a Database,
+    last: Option<(ContractId, ContractId)>,
+}
+
+impl<'a> GenesisContractIds<'a> {
+    fn new(database: &'a Database) -> Self {
+        Self {
+            database,
+            last: None,
+        }
+    }
+
+    fn genesis_id(&mut self, contract_id: &ContractId) -> StorageResult<ContractId> {
+        match self.last {
+            Some((id, genesis_id)) if &id == contract_id => Ok(genesis_id),
+            _ => {
+                let code = self
+                    .database
+                    .storage::<ContractsRawCode>()
+                    .get(contract_id)?
+                    .ok_or(not_found!(ContractsRawCode))?
+                    .into_owned();
+                let (salt, _) = self
+                    .database
+                    .storage::<ContractsInfo>()
+                    .get(contract_id)?
+                    .ok_or(not_found!(ContractsInfo))?
+                    .into_owned();
+                let genesis_id = ContractConfig {
+                    code: code.into(),
+                    salt,
+                    state: None,
+                    balances: None,
+                    tx_id: None,
+                    output_index: None,
+                    tx_pointer_block_height: None,
+                    tx_pointer_tx_idx: None,
+                }
+                .contract_id();
+                self.last = Some((*contract_id, genesis_id));
+                Ok(genesis_id)
+            }
+        }
+    }
+}
@@ -0,0 +1,390 @@
+use crate::{
+    database::{
+        transaction::DatabaseTransaction,
+        Column,
+        Database,
+        Error as DatabaseError,
+    },
+    state::historical::{
+        history_key,
+        HistoricalView,
+        VERSIONED_COLUMNS,
+    },
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    Result as StorageResult,
+};
+use fuel_core_types::blockchain::primitives::BlockHeight;
+use itertools::Itertools;
+use std::{
+    mem::size_of,
+    sync::Arc,
+};
+
+/// The height of the first block that recorded its changes into the state history.
+pub(crate) const STATE_HISTORY_START_KEY: &[u8] = b"state_history_start";
+
+const HEIGHT_SIZE: usize = size_of::<u32>();
+
+/// Returns the key of the [`Column::StateHistoryByHeight`], `height ++ history_key`.
+fn history_by_height_key(height: u32, history_key: &[u8]) -> Vec<u8> {
+    let mut key = height.to_be_bytes().to_vec();
+    key.extend_from_slice(history_key);
+    key
+}
+
+impl Database {
+    /// Returns the read-only view of the state at the end of the block at `height`.
+    ///
+    /// The state is available for any height starting from the block before the first block
+    /// that recorded the state history.
+    pub fn state_at_height(&self, height: &BlockHeight) -> StorageResult<Database> {
+        let latest_height = self.latest_height()?;
+        if *height > latest_height {
+            return Err(anyhow::anyhow!(
+                "The block at height {} doesn't exist yet, the latest height is {}",
+                height,
+                latest_height
+            )
+            .into())
+        }
+
+        if *height < latest_height {
+            let start: Option<BlockHeight> =
+                self.get(STATE_HISTORY_START_KEY, Column::Metadata)?;
+            let available = matches!(start, Some(start) if **height + 1 >= *start);
+            if !available {
+                return Err(anyhow::anyhow!(
+                    "The state history is not available at height {}",
+                    height
+                )
+                .into())
+            }
+        }
+
+        Ok(Database {
+            data: Arc::new(HistoricalView::new(self.data.clone(), *height)),
+            _drop: self._drop.clone(),
+        })
+    }
+
+    /// Removes the state history required only to query the state at or below the
+    /// pruned `height` and moves the start of the history after it.
+    pub(crate) fn prune_state_history(&self, height: &BlockHeight) -> StorageResult<()> {
+        // The state at `height` is restored from the entries recorded by `height + 1`.
+        let last_height = **height + 1;
+        let keys: Vec<Vec<u8>> = self
+            .data
+            .iter_all(
+                Column::StateHistoryByHeight,
+                None,
+                None,
+                IterDirection::Forward,
+            )
+            .map(|result| result.map(|(key, _)| key))
+            .take_while(|result| {
+                let last_key = last_height.to_be_bytes();
+                !matches!(result, Ok(key) if key.get(..HEIGHT_SIZE) > Some(&last_key[..]))
+            })
+            .try_collect()?;
+        for key in keys {
+            self.data.delete(&key, Column::StateHistoryByHeight)?;
+            if let Some(history_key) = key.get(HEIGHT_SIZE..) {
+                self.data.delete(history_key, Column::StateHistory)?;
+            }
+        }
+
+        let start: Option<BlockHeight> =
+            self.get(STATE_HISTORY_START_KEY, Column::Metadata)?;
+        if matches!(start, Some(start) if *start <= last_height) {
+            let start: BlockHeight = (last_height + 1).into();
+            let _: Option<BlockHeight> =
+                self.insert(STATE_HISTORY_START_KEY, Column::Metadata, &start)?;
+        }
+        Ok(())
+    }
+}
+
+impl DatabaseTransaction {
+    /// Records the values of the versioned columns changed by the block at `height`
+    /// as they were before the block, so the state can be queried at previous heights.
+    pub fn record_state_history(&self, height: &BlockHeight) -> StorageResult<()> {
+        for column in VERSIONED_COLUMNS {
+            for (key, original_value) in self.changes.original_values(column)? {
+                let value = postcard::to_stdvec(&original_value)
+                    .map_err(|_| DatabaseError::Codec)?;
+                let history_key = history_key(column, &key, **height);
+                self.write(
+                    &history_by_height_key(**height, &history_key),
+                    Column::StateHistoryByHeight,
+                    vec![],
+                )?;
+                self.write(&history_key, Column::StateHistory, value)?;
+            }
+        }
+
+        if self
+            .get::<BlockHeight>(STATE_HISTORY_START_KEY, Column::Metadata)?
+            .is_none()
+        {
+            let _: Option<BlockHeight> =
+                self.insert(STATE_HISTORY_START_KEY, Column::Metadata, height)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::{
+            Coins,
+            ContractsAssets,
+            FuelBlocks,
+        },
+        transactional::Transaction,
+        StorageAsMut,
+        StorageAsRef,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::UtxoId,
+        fuel_types::{
+            Address,
+            AssetId,
+            ContractId,
+        },
+    };
+
+    /// Applies the `changes` as the block at `height`.
+    fn apply_block<F>(database: &Database, height: u32, record_history: bool, changes: F)
+    where
+        F: FnOnce(&mut Database),
+    {
+        let mut transaction = database.transaction();
+        changes(transaction.as_mut());
+        if record_history {
+            transaction.record_state_history(&height.into()).unwrap();
+        }
+
+        let header = PartialBlockHeader {
+            application: Default::default(),
+            consensus: ConsensusHeader::<Empty> {
+                height: height.into(),
+                ..Default::default()
+            },
+        };
+        let block = PartialFuelBlock::new(header, vec![]).generate(&[]);
+        transaction
+            .as_mut()
+            .storage::<FuelBlocks>()
+            .insert(&block.id(), &block.compress())
+            .unwrap();
+        transaction.commit().unwrap();
+    }
+
+    fn balance(database: &Database, contract: &ContractId, asset: &AssetId) -> u64 {
+        database
+            .storage::<ContractsAssets>()
+            .get(&(contract, asset).into())
+            .unwrap()
+            .map(|balance| *balance)
+            .unwrap_or_default()
+    }
+
+    fn owned_coins(database: &Database, owner: &Address) -> Vec<UtxoId> {
+        database
+            .owned_coins_ids(owner, None, None)
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn state_at_height_returns_values_at_the_end_of_the_block() {
+        let database = Database::default();
+        let contract = ContractId::from([1; 32]);
+        let asset = AssetId::from([2; 32]);
+        for height in 0u32..4 {
+            apply_block(&database, height, true, |db| {
+                db.storage::<ContractsAssets>()
+                    .insert(&(&contract, &asset).into(), &(height as u64 * 10))
+                    .unwrap();
+            });
+        }
+
+        for height in 0u32..4 {
+            let historical = database.state_at_height(&height.into()).unwrap();
+            assert_eq!(balance(&historical, &contract, &asset), height as u64 * 10);
+        }
+        assert_eq!(balance(&database, &contract, &asset), 30);
+    }
+
+    #[test]
+    fn state_at_height_restores_removed_and_hides_created_entries() {
+        let database = Database::default();
+        let owner = Address::from([3; 32]);
+        let old_utxo = UtxoId::new([1; 32].into(), 0);
+        let new_utxo = UtxoId::new([2; 32].into(), 0);
+        let coin = CompressedCoin {
+            owner,
+            amount: 100,
+            asset_id: Default::default(),
+            maturity: Default::default(),
+            tx_pointer: Default::default(),
+        };
+        apply_block(&database, 0, true, |db| {
+            db.storage::<Coins>().insert(&old_utxo, &coin).unwrap();
+        });
+        apply_block(&database, 1, true, |db| {
+            db.storage::<Coins>().remove(&old_utxo).unwrap();
+            db.storage::<Coins>().insert(&new_utxo, &coin).unwrap();
+        });
+
+        let historical = database.state_at_height(&0u32.into()).unwrap();
+        assert!(historical
+            .storage::<Coins>()
+            .contains_key(&old_utxo)
+            .unwrap());
+        assert!(!historical
+            .storage::<Coins>()
+            .contains_key(&new_utxo)
+            .unwrap());
+        assert_eq!(owned_coins(&historical, &owner), vec![old_utxo]);
+
+        let latest = database.state_at_height(&1u32.into()).unwrap();
+        assert_eq!(owned_coins(&latest, &owner), vec![new_utxo]);
+    }
+
+    #[test]
+    fn historical_iteration_merges_latest_and_restored_entries() {
+        let database = Database::default();
+        let coin = |amount| CompressedCoin {
+            owner: Default::default(),
+            amount,
+            asset_id: Default::default(),
+            maturity: Default::default(),
+            tx_pointer: Default::default(),
+        };
+        let utxo = |byte: u8| UtxoId::new([byte; 32].into(), 0);
+        apply_block(&database, 0, true, |db| {
+            db.storage::<Coins>().insert(&utxo(1), &coin(1)).unwrap();
+            db.storage::<Coins>().insert(&utxo(2), &coin(2)).unwrap();
+        });
+        apply_block(&database, 1, true, |db| {
+            db.storage::<Coins>().remove(&utxo(1)).unwrap();
+            db.storage::<Coins>().insert(&utxo(2), &coin(20)).unwrap();
+            db.storage::<Coins>().insert(&utxo(3), &coin(3)).unwrap();
+        });
+        apply_block(&database, 2, true, |db| {
+            db.storage::<Coins>().remove(&utxo(2)).unwrap();
+        });
+
+        let coins = |database: &Database, start: Option<Vec<u8>>, direction| {
+            database
+                .iter_all_filtered::<Vec<u8>, CompressedCoin, [u8; 0], _>(
+                    Column::Coins,
+                    None,
+                    start,
+                    Some(direction),
+                )
+                .map(|result| result.map(|(key, coin)| (key, coin.amount)))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        };
+
+        let historical = database.state_at_height(&0u32.into()).unwrap();
+        let forward = coins(&historical, None, IterDirection::Forward);
+        assert_eq!(
+            forward
+                .iter()
+                .map(|(_, amount)| *amount)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        let reverse = coins(&historical, None, IterDirection::Reverse);
+        assert_eq!(
+            reverse
+                .iter()
+                .map(|(_, amount)| *amount)
+                .collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+        let from_second = coins(
+            &historical,
+            Some(forward[1].0.clone()),
+            IterDirection::Forward,
+        );
+        assert_eq!(from_second, vec![forward[1].clone()]);
+
+        let historical = database.state_at_height(&1u32.into()).unwrap();
+        let amounts: Vec<_> = coins(&historical, None, IterDirection::Forward)
+            .into_iter()
+            .map(|(_, amount)| amount)
+            .collect();
+        assert_eq!(amounts, vec![20, 3]);
+    }
+
+    #[test]
+    fn state_at_height_is_available_only_since_the_history_start() {
+        let database = Database::default();
+        apply_block(&database, 0, false, |_| {});
+        apply_block(&database, 1, false, |_| {});
+        apply_block(&database, 2, true, |_| {});
+        apply_block(&database, 3, true, |_| {});
+
+        assert!(database.state_at_height(&0u32.into()).is_err());
+        assert!(database.state_at_height(&1u32.into()).is_ok());
+        assert!(database.state_at_height(&3u32.into()).is_ok());
+        assert!(database.state_at_height(&4u32.into()).is_err());
+    }
+
+    #[test]
+    fn pruned_state_history_is_not_available() {
+        let database = Database::default();
+        let contract = ContractId::from([1; 32]);
+        let asset = AssetId::from([2; 32]);
+        for height in 0u32..5 {
+            apply_block(&database, height, true, |db| {
+                db.storage::<ContractsAssets>()
+                    .insert(&(&contract, &asset).into(), &(height as u64 * 10))
+                    .unwrap();
+            });
+        }
+
+        database.prune_state_history(&1u32.into()).unwrap();
+
+        assert!(database.state_at_height(&1u32.into()).is_err());
+        for height in 2u32..5 {
+            let historical = database.state_at_height(&height.into()).unwrap();
+            assert_eq!(balance(&historical, &contract, &asset), height as u64 * 10);
+        }
+        // Only the entries recorded by the blocks 3 and 4 are left.
+        let history = database
+            .data
+            .iter_all(Column::StateHistory, None, None, IterDirection::Forward)
+            .count();
+        assert_eq!(history, 2);
+    }
+
+    #[test]
+    fn historical_state_is_read_only() {
+        let database = Database::default();
+        apply_block(&database, 0, true, |_| {});
+        let mut historical = database.state_at_height(&0u32.into()).unwrap();
+
+        let result = historical
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&(&ContractId::default(), &AssetId::default()).into(), &1);
+        assert!(result.is_err());
+    }
+}
@@ -18,7 +18,7 @@ use std::{
 #[derive(Clone, Debug)]
 pub struct DatabaseTransaction {
     // The primary datastores
-    changes: Arc<MemoryTransactionView>,
+    pub(super) changes: Arc<MemoryTransactionView>,
     // The inner db impl using these stores
     database: Database,
 }
@@ -1,4 +1,5 @@
 use crate::database::{
+    migration::MigrationStep,
     storage::DatabaseColumn,
     Column,
     Database,
@@ -12,11 +13,25 @@ use fuel_core_types::{
     self,
     blockchain::primitives::BlockHeight,
     fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        input::coin::{
+            CoinPredicate,
+            CoinSigned,
+        },
         Bytes32,
+        Input,
+        Output,
         Transaction,
         TxPointer,
     },
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        AssetId,
+        ContractId,
+    },
     services::txpool::TransactionStatus,
 };
 use std::{
@@ -55,11 +70,58 @@ impl Database {
         start: Option<OwnedTransactionIndexCursor>,
         direction: Option<IterDirection>,
     ) -> impl Iterator<Item = DatabaseResult<(TxPointer, Bytes32)>> + '_ {
-        let start = start
-            .map(|cursor| owned_tx_index_key(&owner, cursor.block_height, cursor.tx_idx));
-        self.iter_all_filtered::<OwnedTransactionIndexKey, Bytes32, _, _>(
+        self.indexed_transactions(
             Column::TransactionsByOwnerBlockIdx,
-            Some(owner),
+            *owner,
+            start,
+            direction,
+        )
+    }
+
+    /// Iterates over the transactions that use the contract as an input or create it,
+    /// sorted the same way as [`Database::owned_transactions`].
+    pub fn contract_transactions(
+        &self,
+        contract: ContractId,
+        start: Option<OwnedTransactionIndexCursor>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = DatabaseResult<(TxPointer, Bytes32)>> + '_ {
+        self.indexed_transactions(
+            Column::TransactionsByContractBlockIdx,
+            *contract,
+            start,
+            direction,
+        )
+    }
+
+    /// Iterates over the transactions that spend or create coins of the asset,
+    /// sorted the same way as [`Database::owned_transactions`].
+    pub fn asset_transactions(
+        &self,
+        asset: AssetId,
+        start: Option<OwnedTransactionIndexCursor>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = DatabaseResult<(TxPointer, Bytes32)>> + '_ {
+        self.indexed_transactions(
+            Column::TransactionsByAssetBlockIdx,
+            *asset,
+            start,
+            direction,
+        )
+    }
+
+    fn indexed_transactions(
+        &self,
+        column: Column,
+        prefix: [u8; 32],
+        start: Option<OwnedTransactionIndexCursor>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = DatabaseResult<(TxPointer, Bytes32)>> + '_ {
+        let start =
+            start.map(|cursor| tx_index_key(&prefix, cursor.block_height, cursor.tx_idx));
+        self.iter_all_filtered::<OwnedTransactionIndexKey, Bytes32, _, _>(
+            column,
+            Some(prefix),
             start,
             direction,
         )
@@ -78,12 +140,101 @@ impl Database {
         tx_id: &Bytes32,
     ) -> DatabaseResult<Option<Bytes32>> {
         self.insert(
-            owned_tx_index_key(owner, block_height, tx_idx),
+            tx_index_key(owner, block_height, tx_idx),
             Column::TransactionsByOwnerBlockIdx,
             tx_id,
         )
     }
 
+    /// Removes the transaction from the index of the owners of its coins.
+    pub fn remove_tx_id_owners(
+        &self,
+        tx: &Transaction,
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+    ) -> DatabaseResult<()> {
+        for owner in tx_owners(tx) {
+            let _: Option<Bytes32> = self.remove(
+                &tx_index_key(owner, block_height, tx_idx),
+                Column::TransactionsByOwnerBlockIdx,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Associates the transaction with the contracts it uses or creates
+    /// and with the assets of the coins it spends or creates.
+    pub fn record_tx_id_contracts_and_assets(
+        &self,
+        tx: &Transaction,
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+        tx_id: &Bytes32,
+    ) -> DatabaseResult<()> {
+        let (contracts, assets) = tx_contracts_and_assets(tx);
+        for contract in contracts {
+            let _: Option<Bytes32> = self.insert(
+                tx_index_key(contract, block_height, tx_idx),
+                Column::TransactionsByContractBlockIdx,
+                tx_id,
+            )?;
+        }
+        for asset in assets {
+            let _: Option<Bytes32> = self.insert(
+                tx_index_key(asset, block_height, tx_idx),
+                Column::TransactionsByAssetBlockIdx,
+                tx_id,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Removes the index entries of the transaction by the contracts and by the assets.
+    pub fn remove_tx_id_contracts_and_assets(
+        &self,
+        tx: &Transaction,
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+    ) -> DatabaseResult<()> {
+        let (contracts, assets) = tx_contracts_and_assets(tx);
+        for contract in contracts {
+            let _: Option<Bytes32> = self.remove(
+                &tx_index_key(contract, block_height, tx_idx),
+                Column::TransactionsByContractBlockIdx,
+            )?;
+        }
+        for asset in assets {
+            let _: Option<Bytes32> = self.remove(
+                &tx_index_key(asset, block_height, tx_idx),
+                Column::TransactionsByAssetBlockIdx,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Builds the indexes of the transactions by the contract and by the asset for the
+    /// next batch of blocks of the database. Transactions removed by the pruning are skipped.
+    pub(crate) fn index_transactions_by_contract_and_asset(
+        &self,
+    ) -> DatabaseResult<MigrationStep> {
+        self.backfill_blocks(|database, height, block| {
+            for (tx_idx, tx_id) in block.transactions().iter().enumerate() {
+                let tx: Transaction =
+                    match database.get(tx_id.as_ref(), Column::Transactions)? {
+                        Some(tx) => tx,
+                        None => continue,
+                    };
+                database.record_tx_id_contracts_and_assets(
+                    &tx,
+                    height,
+                    tx_idx as TransactionIndex,
+                    tx_id,
+                )?;
+            }
+            Ok(())
+        })
+    }
+
     pub fn update_tx_status(
         &self,
         id: &Bytes32,
@@ -100,19 +251,81 @@ impl Database {
     }
 }
 
+fn inputs_and_outputs(tx: &Transaction) -> (&[Input], &[Output]) {
+    match tx {
+        Transaction::Script(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+        Transaction::Create(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+        Transaction::Mint(tx) => (&[][..], tx.outputs().as_slice()),
+    }
+}
+
+/// Returns the owners of the coins spent or created by the transaction, the same
+/// owners the executor indexes the transaction by.
+fn tx_owners(tx: &Transaction) -> Vec<&Address> {
+    let (inputs, outputs) = inputs_and_outputs(tx);
+    let mut owners: Vec<_> = inputs
+        .iter()
+        .filter_map(|input| match input {
+            Input::CoinSigned(CoinSigned { owner, .. })
+            | Input::CoinPredicate(CoinPredicate { owner, .. }) => Some(owner),
+            _ => None,
+        })
+        .chain(outputs.iter().filter_map(|output| match output {
+            Output::Coin { to, .. }
+            | Output::Change { to, .. }
+            | Output::Variable { to, .. } => Some(to),
+            Output::Contract { .. } | Output::ContractCreated { .. } => None,
+        }))
+        .collect();
+    owners.sort();
+    owners.dedup();
+    owners
+}
+
+/// Returns the contracts used or created by the transaction and the assets of the coins
+/// it spends or creates.
+fn tx_contracts_and_assets(tx: &Transaction) -> (Vec<&ContractId>, Vec<&AssetId>) {
+    let (inputs, outputs) = inputs_and_outputs(tx);
+
+    let mut contracts: Vec<_> = inputs
+        .iter()
+        .filter_map(Input::contract_id)
+        .chain(outputs.iter().filter_map(Output::contract_id))
+        .collect();
+    contracts.sort();
+    contracts.dedup();
+
+    // Skip the empty `Variable` outputs and the message inputs without a value.
+    let mut assets: Vec<_> = inputs
+        .iter()
+        .filter(|input| input.amount().unwrap_or_default() > 0)
+        .filter_map(Input::asset_id)
+        .chain(
+            outputs
+                .iter()
+                .filter(|output| output.amount().unwrap_or_default() > 0)
+                .filter_map(Output::asset_id),
+        )
+        .collect();
+    assets.sort();
+    assets.dedup();
+
+    (contracts, assets)
+}
+
 const TX_INDEX_SIZE: usize = size_of::<TransactionIndex>();
 const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
 const INDEX_SIZE: usize = Address::LEN + BLOCK_HEIGHT + TX_INDEX_SIZE;
 
-fn owned_tx_index_key(
-    owner: &Address,
+fn tx_index_key(
+    prefix: &[u8; 32],
     height: BlockHeight,
     tx_idx: TransactionIndex,
 ) -> [u8; INDEX_SIZE] {
     let mut default = [0u8; INDEX_SIZE];
-    // generate prefix to enable sorted indexing of transactions by owner
-    // owner + block_height + tx_idx
-    default[0..Address::LEN].copy_from_slice(owner.as_ref());
+    // generate prefix to enable sorted indexing of transactions by owner, contract or asset
+    // prefix + block_height + tx_idx
+    default[0..Address::LEN].copy_from_slice(prefix.as_ref());
     default[Address::LEN..Address::LEN + BLOCK_HEIGHT]
         .copy_from_slice(height.to_bytes().as_ref());
     default[Address::LEN + BLOCK_HEIGHT..].copy_from_slice(tx_idx.to_be_bytes().as_ref());
@@ -162,6 +375,15 @@ impl From<OwnedTransactionIndexKey> for OwnedTransactionIndexCursor {
     }
 }
 
+impl From<TxPointer> for OwnedTransactionIndexCursor {
+    fn from(tx_pointer: TxPointer) -> Self {
+        OwnedTransactionIndexCursor {
+            block_height: tx_pointer.block_height().into(),
+            tx_idx: tx_pointer.tx_index(),
+        }
+    }
+}
+
 impl From<Vec<u8>> for OwnedTransactionIndexCursor {
     fn from(bytes: Vec<u8>) -> Self {
         let mut block_height_bytes: [u8; 4] = Default::default();
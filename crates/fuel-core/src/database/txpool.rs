@@ -0,0 +1,45 @@
+use crate::database::{
+    Column,
+    Database,
+    Result as DatabaseResult,
+};
+use fuel_core_types::fuel_tx::{
+    Transaction,
+    TxId,
+};
+use std::time::Duration;
+
+/// The transaction accepted by the transaction pool with the time of its submission
+/// since the `UNIX_EPOCH`.
+pub type JournaledTransaction = (Transaction, Duration);
+
+impl Database {
+    /// Returns all transactions of the transaction pool journal.
+    pub fn journaled_transactions(&self) -> DatabaseResult<Vec<JournaledTransaction>> {
+        self.iter_all::<Vec<u8>, JournaledTransaction>(Column::TxPoolJournal, None)
+            .map(|result| result.map(|(_, journaled)| journaled))
+            .collect()
+    }
+
+    /// Adds the transaction accepted by the transaction pool to the journal.
+    pub fn journal_transaction(
+        &self,
+        tx_id: &TxId,
+        transaction: &Transaction,
+        submitted_time: Duration,
+    ) -> DatabaseResult<()> {
+        let _: Option<JournaledTransaction> = self.insert(
+            tx_id.as_ref(),
+            Column::TxPoolJournal,
+            &(transaction, submitted_time),
+        )?;
+        Ok(())
+    }
+
+    /// Removes the transaction that left the transaction pool from the journal.
+    pub fn remove_journaled_transaction(&self, tx_id: &TxId) -> DatabaseResult<()> {
+        let _: Option<JournaledTransaction> =
+            self.remove(tx_id.as_ref(), Column::TxPoolJournal)?;
+        Ok(())
+    }
+}
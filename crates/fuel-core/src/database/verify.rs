@@ -0,0 +1,860 @@
+use crate::database::{
+    coin::owner_coin_id_key,
+    message::owner_msg_id_key,
+    storage::{
+        ContractsAssetsMerkleMetadata,
+        ContractsStateMerkleMetadata,
+        DenseMerkleMetadata,
+        FuelBlockMerkleData,
+        FuelBlockMerkleMetadata,
+        FuelBlockSecondaryKeyBlockHeights,
+        SparseMerkleMetadata,
+    },
+    Column,
+    Database,
+};
+use fuel_core_storage::{
+    tables::{
+        Coins,
+        ContractsAssets,
+        ContractsState,
+        FuelBlocks,
+        Messages,
+    },
+    transactional::Transaction,
+    ContractsAssetKey,
+    ContractsStateKey,
+    Error as StorageError,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    StorageMutate,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::CompressedBlock,
+        primitives::{
+            BlockHeight,
+            BlockId,
+        },
+    },
+    entities::{
+        coins::coin::CompressedCoin,
+        message::Message,
+    },
+    fuel_merkle::{
+        binary,
+        sparse,
+    },
+    fuel_tx::UtxoId,
+    fuel_types::{
+        Address,
+        Bytes32,
+        ContractId,
+        Nonce,
+        Word,
+    },
+};
+use itertools::Itertools;
+use std::{
+    collections::BTreeMap,
+    fmt,
+};
+
+/// The violation of an invariant between the tables found by [`Database::verify`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inconsistency {
+    /// The coin is not indexed by its owner in `OwnedCoins`.
+    MissingOwnedCoin { owner: Address, utxo_id: UtxoId },
+    /// The `OwnedCoins` entry refers to the coin that doesn't exist or has another owner.
+    DanglingOwnedCoin { owner: Address, utxo_id: UtxoId },
+    /// The message is not indexed by its recipient in `OwnedMessageIds`.
+    MissingOwnedMessage { owner: Address, nonce: Nonce },
+    /// The `OwnedMessageIds` entry refers to the message that doesn't exist
+    /// or has another recipient.
+    DanglingOwnedMessage { owner: Address, nonce: Nonce },
+    /// The root in `ContractsAssetsMerkleMetadata` doesn't match the balances of the contract.
+    ContractAssetsRoot {
+        contract_id: ContractId,
+        expected: Bytes32,
+        found: Bytes32,
+    },
+    /// The root in `ContractsStateMerkleMetadata` doesn't match the state of the contract.
+    ContractStateRoot {
+        contract_id: ContractId,
+        expected: Bytes32,
+        found: Bytes32,
+    },
+    /// The block is not indexed by its height in `FuelBlockSecondaryKeyBlockHeights`.
+    MissingBlockHeight {
+        height: BlockHeight,
+        block_id: BlockId,
+    },
+    /// The `FuelBlockSecondaryKeyBlockHeights` entry refers to the block that doesn't exist
+    /// and wasn't pruned, or has another height.
+    DanglingBlockHeight {
+        height: BlockHeight,
+        block_id: BlockId,
+    },
+    /// The `FuelBlockMerkleMetadata` of the block doesn't match the chain of blocks.
+    BlockMerkleRoot {
+        height: BlockHeight,
+        expected: Bytes32,
+        found: Option<Bytes32>,
+    },
+    /// The key of the entry in the `column` can't be decoded.
+    MalformedKey { column: Column, key: Vec<u8> },
+}
+
+impl Inconsistency {
+    /// Returns `true` if [`Database::verify`] can fix the inconsistency.
+    pub fn is_repairable(&self) -> bool {
+        // The block can't be recovered from the index, and the data of the malformed
+        // key is unknown.
+        !matches!(
+            self,
+            Inconsistency::DanglingBlockHeight { .. }
+                | Inconsistency::MalformedKey { .. }
+        )
+    }
+}
+
+impl fmt::Display for Inconsistency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Inconsistency::MissingOwnedCoin { owner, utxo_id } => {
+                write!(f, "The coin {utxo_id:#x} is not indexed by the owner {owner:#x}")
+            }
+            Inconsistency::DanglingOwnedCoin { owner, utxo_id } => write!(
+                f,
+                "The owner {owner:#x} is indexed with the coin {utxo_id:#x} it doesn't own"
+            ),
+            Inconsistency::MissingOwnedMessage { owner, nonce } => write!(
+                f,
+                "The message {nonce:#x} is not indexed by the recipient {owner:#x}"
+            ),
+            Inconsistency::DanglingOwnedMessage { owner, nonce } => write!(
+                f,
+                "The recipient {owner:#x} is indexed with the message {nonce:#x} it doesn't own"
+            ),
+            Inconsistency::ContractAssetsRoot {
+                contract_id,
+                expected,
+                found,
+            } => write!(
+                f,
+                "The assets root of the contract {contract_id:#x} is {found:#x}, expected {expected:#x}"
+            ),
+            Inconsistency::ContractStateRoot {
+                contract_id,
+                expected,
+                found,
+            } => write!(
+                f,
+                "The state root of the contract {contract_id:#x} is {found:#x}, expected {expected:#x}"
+            ),
+            Inconsistency::MissingBlockHeight { height, block_id } => write!(
+                f,
+                "The block {block_id:#x} is not indexed by its height {height}"
+            ),
+            Inconsistency::DanglingBlockHeight { height, block_id } => write!(
+                f,
+                "The height {height} is indexed with the missing block {block_id:#x}"
+            ),
+            Inconsistency::BlockMerkleRoot {
+                height,
+                expected,
+                found: Some(found),
+            } => write!(
+                f,
+                "The block merkle root at height {height} is {found:#x}, expected {expected:#x}"
+            ),
+            Inconsistency::BlockMerkleRoot {
+                height,
+                expected,
+                found: None,
+            } => write!(
+                f,
+                "The block merkle root at height {height} is missing, expected {expected:#x}"
+            ),
+            Inconsistency::MalformedKey { column, key } => write!(
+                f,
+                "The key 0x{} in the column {column} is malformed",
+                hex::encode(key)
+            ),
+        }
+    }
+}
+
+/// The result of [`Database::verify`].
+#[derive(Debug, Default)]
+pub struct VerificationReport {
+    /// All inconsistencies found in the database.
+    pub inconsistencies: Vec<Inconsistency>,
+    /// The number of the repaired inconsistencies.
+    pub repaired: usize,
+}
+
+impl VerificationReport {
+    pub fn is_consistent(&self) -> bool {
+        self.inconsistencies.is_empty()
+    }
+}
+
+impl Database {
+    /// Walks the tables and checks that the secondary indexes and the merkle metadata
+    /// match the primary data. If `repair` is set, the indexes and the metadata
+    /// are rebuilt from the primary data.
+    ///
+    /// The database should not be used by a running node during the verification.
+    pub fn verify(&self, repair: bool) -> StorageResult<VerificationReport> {
+        let mut report = VerificationReport::default();
+
+        self.verify_owned_coins(repair, &mut report)?;
+        self.verify_owned_messages(repair, &mut report)?;
+        self.verify_contract_assets_roots(repair, &mut report)?;
+        self.verify_contract_state_roots(repair, &mut report)?;
+        // The block merkle data is verified against the heights index, so check the index first.
+        self.verify_block_heights(repair, &mut report)?;
+        self.verify_block_merkle_data(repair, &mut report)?;
+
+        Ok(report)
+    }
+
+    fn verify_owned_coins(
+        &self,
+        repair: bool,
+        report: &mut VerificationReport,
+    ) -> StorageResult<()> {
+        let mut found = vec![];
+        for entry in self.iter_all::<Vec<u8>, CompressedCoin>(Column::Coins, None) {
+            let (key, coin) = entry?;
+            let utxo_id = match utxo_id_from_bytes(&key) {
+                Some(utxo_id) => utxo_id,
+                None => {
+                    found.push(Inconsistency::MalformedKey {
+                        column: Column::Coins,
+                        key,
+                    });
+                    continue
+                }
+            };
+            let index_key = owner_coin_id_key(&coin.owner, &utxo_id);
+            if !self.contains_key(&index_key, Column::OwnedCoins)? {
+                found.push(Inconsistency::MissingOwnedCoin {
+                    owner: coin.owner,
+                    utxo_id,
+                });
+            }
+        }
+        for entry in self.iter_all::<Vec<u8>, bool>(Column::OwnedCoins, None) {
+            let (key, _) = entry?;
+            let decoded = key.get(..Address::LEN).zip(key.get(Address::LEN..));
+            let (owner, utxo_id) = match decoded.and_then(|(owner, utxo_id)| {
+                Some((Address::try_from(owner).ok()?, utxo_id_from_bytes(utxo_id)?))
+            }) {
+                Some(decoded) => decoded,
+                None => {
+                    found.push(Inconsistency::MalformedKey {
+                        column: Column::OwnedCoins,
+                        key,
+                    });
+                    continue
+                }
+            };
+            let coin = self.storage::<Coins>().get(&utxo_id)?;
+            if !matches!(coin, Some(coin) if coin.owner == owner) {
+                found.push(Inconsistency::DanglingOwnedCoin { owner, utxo_id });
+            }
+        }
+
+        self.repair_with(repair, found, report, |database, inconsistency| {
+            match inconsistency {
+                Inconsistency::MissingOwnedCoin { owner, utxo_id } => {
+                    let _: Option<bool> = database.insert(
+                        owner_coin_id_key(owner, utxo_id),
+                        Column::OwnedCoins,
+                        &true,
+                    )?;
+                }
+                Inconsistency::DanglingOwnedCoin { owner, utxo_id } => {
+                    let _: Option<bool> = database
+                        .remove(&owner_coin_id_key(owner, utxo_id), Column::OwnedCoins)?;
+                }
+                _ => unreachable!("Only owned coins inconsistencies are repaired"),
+            }
+            Ok(())
+        })
+    }
+
+    fn verify_owned_messages(
+        &self,
+        repair: bool,
+        report: &mut VerificationReport,
+    ) -> StorageResult<()> {
+        let mut found = vec![];
+        for entry in self.iter_all::<Vec<u8>, Message>(Column::Messages, None) {
+            let (_, message) = entry?;
+            let index_key = owner_msg_id_key(&message.recipient, &message.nonce);
+            if !self.contains_key(&index_key, Column::OwnedMessageIds)? {
+                found.push(Inconsistency::MissingOwnedMessage {
+                    owner: message.recipient,
+                    nonce: message.nonce,
+                });
+            }
+        }
+        for entry in self.iter_all::<Vec<u8>, bool>(Column::OwnedMessageIds, None) {
+            let (key, _) = entry?;
+            let decoded = key.get(..Address::LEN).zip(key.get(Address::LEN..));
+            let (owner, nonce) = match decoded.and_then(|(owner, nonce)| {
+                Some((Address::try_from(owner).ok()?, Nonce::try_from(nonce).ok()?))
+            }) {
+                Some(decoded) => decoded,
+                None => {
+                    found.push(Inconsistency::MalformedKey {
+                        column: Column::OwnedMessageIds,
+                        key,
+                    });
+                    continue
+                }
+            };
+            let message = self.storage::<Messages>().get(&nonce)?;
+            if !matches!(message, Some(message) if message.recipient == owner) {
+                found.push(Inconsistency::DanglingOwnedMessage { owner, nonce });
+            }
+        }
+
+        self.repair_with(repair, found, report, |database, inconsistency| {
+            match inconsistency {
+                Inconsistency::MissingOwnedMessage { owner, nonce } => {
+                    let _: Option<bool> = database.insert(
+                        owner_msg_id_key(owner, nonce),
+                        Column::OwnedMessageIds,
+                        &true,
+                    )?;
+                }
+                Inconsistency::DanglingOwnedMessage { owner, nonce } => {
+                    let _: Option<bool> = database.remove(
+                        &owner_msg_id_key(owner, nonce),
+                        Column::OwnedMessageIds,
+                    )?;
+                }
+                _ => unreachable!("Only owned messages inconsistencies are repaired"),
+            }
+            Ok(())
+        })
+    }
+
+    fn verify_contract_assets_roots(
+        &self,
+        repair: bool,
+        report: &mut VerificationReport,
+    ) -> StorageResult<()> {
+        let mut found = vec![];
+        let mismatches = self.sparse_roots_mismatches::<Word>(
+            Column::ContractsAssets,
+            Column::ContractsAssetsMerkleMetadata,
+            |balance| balance.to_be_bytes().to_vec(),
+            &mut found,
+        )?;
+        found.extend(
+            mismatches
+                .into_iter()
+                .map(|(contract_id, expected, found)| {
+                    Inconsistency::ContractAssetsRoot {
+                        contract_id,
+                        expected,
+                        found,
+                    }
+                }),
+        );
+
+        self.repair_with(repair, found, report, |database, inconsistency| {
+            let contract_id = match inconsistency {
+                Inconsistency::ContractAssetsRoot { contract_id, .. } => contract_id,
+                _ => unreachable!("Only contract assets inconsistencies are repaired"),
+            };
+            let balances: Vec<_> = database
+                .contract_balances(*contract_id, None, None)
+                .try_collect()?;
+            // Rebuild the tree from scratch by inserting all balances again.
+            database
+                .storage::<ContractsAssetsMerkleMetadata>()
+                .remove(contract_id)?;
+            for (asset_id, balance) in balances {
+                let key = ContractsAssetKey::new(contract_id, &asset_id);
+                StorageMutate::<ContractsAssets>::insert(database, &key, &balance)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn verify_contract_state_roots(
+        &self,
+        repair: bool,
+        report: &mut VerificationReport,
+    ) -> StorageResult<()> {
+        let mut found = vec![];
+        let mismatches = self.sparse_roots_mismatches::<Bytes32>(
+            Column::ContractsState,
+            Column::ContractsStateMerkleMetadata,
+            |value| value.to_vec(),
+            &mut found,
+        )?;
+        found.extend(
+            mismatches
+                .into_iter()
+                .map(
+                    |(contract_id, expected, found)| Inconsistency::ContractStateRoot {
+                        contract_id,
+                        expected,
+                        found,
+                    },
+                ),
+        );
+
+        self.repair_with(repair, found, report, |database, inconsistency| {
+            let contract_id = match inconsistency {
+                Inconsistency::ContractStateRoot { contract_id, .. } => contract_id,
+                _ => unreachable!("Only contract state inconsistencies are repaired"),
+            };
+            let slots: Vec<(Vec<u8>, Bytes32)> = database
+                .iter_all_by_prefix(Column::ContractsState, Some(contract_id), None)
+                .try_collect()?;
+            // Rebuild the tree from scratch by inserting all slots again.
+            database
+                .storage::<ContractsStateMerkleMetadata>()
+                .remove(contract_id)?;
+            for (key, value) in slots {
+                // The malformed keys are reported separately and don't belong to the tree.
+                let state_key = match key
+                    .get(ContractId::LEN..)
+                    .and_then(|key| Bytes32::try_from(key).ok())
+                {
+                    Some(state_key) => state_key,
+                    None => continue,
+                };
+                let key = ContractsStateKey::new(contract_id, &state_key);
+                StorageMutate::<ContractsState>::insert(database, &key, &value)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Recomputes the sparse merkle roots of all contracts in the `column` and
+    /// returns the contracts with the root in the `metadata_column` that doesn't match.
+    /// The entries with malformed keys are skipped and recorded into `malformed`.
+    fn sparse_roots_mismatches<V>(
+        &self,
+        column: Column,
+        metadata_column: Column,
+        leaf: impl Fn(&V) -> Vec<u8>,
+        malformed: &mut Vec<Inconsistency>,
+    ) -> StorageResult<Vec<(ContractId, Bytes32, Bytes32)>>
+    where
+        V: serde::de::DeserializeOwned,
+    {
+        let mut stored_roots = BTreeMap::new();
+        for entry in self.iter_all::<Vec<u8>, SparseMerkleMetadata>(metadata_column, None)
+        {
+            let (key, metadata) = entry?;
+            match ContractId::try_from(key.as_slice()) {
+                Ok(contract_id) => {
+                    stored_roots.insert(contract_id, Bytes32::from(metadata.root));
+                }
+                Err(_) => malformed.push(Inconsistency::MalformedKey {
+                    column: metadata_column,
+                    key,
+                }),
+            }
+        }
+        let empty_root = Bytes32::from(*sparse::empty_sum());
+
+        let mut mismatches = vec![];
+        let mut check = |contract_id: ContractId, expected: Bytes32| {
+            let found = stored_roots.remove(&contract_id).unwrap_or(empty_root);
+            if found != expected {
+                mismatches.push((contract_id, expected, found));
+            }
+        };
+
+        // The entries are sorted by the contract id, so the tree of one contract
+        // is computed at a time.
+        let mut current: Option<(ContractId, sparse::in_memory::MerkleTree)> = None;
+        for entry in self.iter_all::<Vec<u8>, V>(column, None) {
+            let (key, value) = entry?;
+            let decoded = key.get(..ContractId::LEN).zip(key.get(ContractId::LEN..));
+            let (contract_id, leaf_key) =
+                match decoded.and_then(|(contract_id, leaf_key)| {
+                    let leaf_key: [u8; 32] = leaf_key.try_into().ok()?;
+                    Some((ContractId::try_from(contract_id).ok()?, leaf_key))
+                }) {
+                    Some(decoded) => decoded,
+                    None => {
+                        malformed.push(Inconsistency::MalformedKey { column, key });
+                        continue
+                    }
+                };
+
+            if !matches!(&current, Some((id, _)) if *id == contract_id) {
+                if let Some((id, tree)) = current.take() {
+                    check(id, tree.root().into());
+                }
+                current = Some((contract_id, sparse::in_memory::MerkleTree::new()));
+            }
+            let (_, tree) = current.as_mut().expect("The tree is set above");
+            tree.update(&leaf_key, &leaf(&value));
+        }
+        if let Some((id, tree)) = current.take() {
+            check(id, tree.root().into());
+        }
+
+        // The contracts without entries should not have non-empty roots.
+        for (contract_id, found) in stored_roots {
+            if found != empty_root {
+                mismatches.push((contract_id, empty_root, found));
+            }
+        }
+        Ok(mismatches)
+    }
+
+    fn verify_block_heights(
+        &self,
+        repair: bool,
+        report: &mut VerificationReport,
+    ) -> StorageResult<()> {
+        let mut found = vec![];
+        for entry in self.iter_all::<Vec<u8>, CompressedBlock>(Column::FuelBlocks, None) {
+            let (key, block) = entry?;
+            let block_id = match BlockId::try_from(key.as_slice()) {
+                Ok(block_id) => block_id,
+                Err(_) => {
+                    found.push(Inconsistency::MalformedKey {
+                        column: Column::FuelBlocks,
+                        key,
+                    });
+                    continue
+                }
+            };
+            let height = *block.header().height();
+            if self.get_block_id(&height)? != Some(block_id) {
+                found.push(Inconsistency::MissingBlockHeight { height, block_id });
+            }
+        }
+        for entry in self.all_block_ids(None, Default::default()) {
+            let (height, block_id) = entry?;
+            let block = self.storage::<FuelBlocks>().get(&block_id)?;
+            let is_valid = match block {
+                Some(block) => *block.header().height() == height,
+                None => self.is_pruned(&height)?,
+            };
+            if !is_valid {
+                found.push(Inconsistency::DanglingBlockHeight { height, block_id });
+            }
+        }
+
+        self.repair_with(repair, found, report, |database, inconsistency| {
+            if let Inconsistency::MissingBlockHeight { height, block_id } = inconsistency
+            {
+                database
+                    .storage::<FuelBlockSecondaryKeyBlockHeights>()
+                    .insert(height, block_id)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn verify_block_merkle_data(
+        &self,
+        repair: bool,
+        report: &mut VerificationReport,
+    ) -> StorageResult<()> {
+        let mut found = vec![];
+        let mut tree = binary::in_memory::MerkleTree::new();
+        for entry in self.all_block_ids(None, Default::default()) {
+            let (height, block_id) = entry?;
+            tree.push(block_id.as_slice());
+            let expected = Bytes32::from(tree.root());
+            let metadata = self.storage::<FuelBlockMerkleMetadata>().get(&height)?;
+            let found_root = metadata.map(|metadata| Bytes32::from(metadata.root));
+            if found_root != Some(expected) {
+                found.push(Inconsistency::BlockMerkleRoot {
+                    height,
+                    expected,
+                    found: found_root,
+                });
+            }
+        }
+
+        if !repair || found.is_empty() {
+            report.inconsistencies.extend(found);
+            return Ok(())
+        }
+
+        // The tree is append-only, so rebuild it from scratch. The nodes are stored
+        // by their positions, so the old nodes are overwritten.
+        let mut transaction = self.transaction();
+        let database = transaction.as_mut();
+        let block_ids: Vec<(BlockHeight, BlockId)> =
+            self.all_block_ids(None, Default::default()).try_collect()?;
+        for (version, (height, block_id)) in block_ids.iter().enumerate() {
+            let mut tree: binary::MerkleTree<FuelBlockMerkleData, _> =
+                binary::MerkleTree::load(&mut *database, version as u64)
+                    .map_err(|err| StorageError::Other(err.into()))?;
+            tree.push(block_id.as_slice())?;
+            let metadata = DenseMerkleMetadata {
+                version: tree.leaves_count(),
+                root: tree.root(),
+            };
+            database
+                .storage::<FuelBlockMerkleMetadata>()
+                .insert(height, &metadata)?;
+        }
+        transaction.commit()?;
+
+        report.repaired += found.len();
+        report.inconsistencies.extend(found);
+        Ok(())
+    }
+
+    /// Records the `found` inconsistencies in the `report` and fixes them with `repair_fn`
+    /// if `repair` is set. All fixes are committed in one database transaction.
+    fn repair_with(
+        &self,
+        repair: bool,
+        found: Vec<Inconsistency>,
+        report: &mut VerificationReport,
+        repair_fn: impl Fn(&mut Database, &Inconsistency) -> StorageResult<()>,
+    ) -> StorageResult<()> {
+        if repair && !found.is_empty() {
+            let mut transaction = self.transaction();
+            for inconsistency in found.iter().filter(|i| i.is_repairable()) {
+                repair_fn(transaction.as_mut(), inconsistency)?;
+                report.repaired += 1;
+            }
+            transaction.commit()?;
+        }
+        report.inconsistencies.extend(found);
+        Ok(())
+    }
+}
+
+/// Decodes the `tx_id ++ output_index` key, returns `None` if the key is malformed.
+fn utxo_id_from_bytes(bytes: &[u8]) -> Option<UtxoId> {
+    if bytes.len() != Bytes32::LEN + 1 {
+        return None
+    }
+    let tx_id = Bytes32::try_from(&bytes[..Bytes32::LEN]).ok()?;
+    Some(UtxoId::new(tx_id, bytes[Bytes32::LEN]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        fuel_types::AssetId,
+        fuel_vm::crypto::ephemeral_merkle_root,
+    };
+
+    fn coin(owner: Address) -> CompressedCoin {
+        CompressedCoin {
+            owner,
+            amount: 100,
+            asset_id: Default::default(),
+            maturity: Default::default(),
+            tx_pointer: Default::default(),
+        }
+    }
+
+    fn insert_blocks(database: &mut Database, count: u32) -> Vec<BlockId> {
+        (0..count)
+            .map(|height| {
+                let header = PartialBlockHeader {
+                    application: Default::default(),
+                    consensus: ConsensusHeader::<Empty> {
+                        height: height.into(),
+                        ..Default::default()
+                    },
+                };
+                let block = PartialFuelBlock::new(header, vec![]).generate(&[]);
+                database
+                    .storage::<FuelBlocks>()
+                    .insert(&block.id(), &block.compress())
+                    .unwrap();
+                block.id()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn consistent_database_has_no_inconsistencies() {
+        let mut database = Database::default();
+        let owner = Address::from([1; 32]);
+        database
+            .storage::<Coins>()
+            .insert(&UtxoId::new([2; 32].into(), 0), &coin(owner))
+            .unwrap();
+        let contract_id = ContractId::from([3; 32]);
+        database
+            .storage::<ContractsAssets>()
+            .insert(
+                &ContractsAssetKey::new(&contract_id, &AssetId::from([4; 32])),
+                &100,
+            )
+            .unwrap();
+        insert_blocks(&mut database, 3);
+
+        let report = database.verify(false).unwrap();
+
+        assert!(report.is_consistent(), "{:?}", report.inconsistencies);
+    }
+
+    #[test]
+    fn owned_coins_are_verified_and_repaired() {
+        let mut database = Database::default();
+        let owner = Address::from([1; 32]);
+        let utxo_id = UtxoId::new([2; 32].into(), 0);
+        database
+            .storage::<Coins>()
+            .insert(&utxo_id, &coin(owner))
+            .unwrap();
+        let dangling = UtxoId::new([3; 32].into(), 1);
+        let _: Option<bool> = database
+            .insert(
+                owner_coin_id_key(&owner, &dangling),
+                Column::OwnedCoins,
+                &true,
+            )
+            .unwrap();
+        let _: Option<bool> = database
+            .remove(&owner_coin_id_key(&owner, &utxo_id), Column::OwnedCoins)
+            .unwrap();
+
+        let report = database.verify(false).unwrap();
+        assert_eq!(
+            report.inconsistencies,
+            vec![
+                Inconsistency::MissingOwnedCoin { owner, utxo_id },
+                Inconsistency::DanglingOwnedCoin {
+                    owner,
+                    utxo_id: dangling
+                },
+            ]
+        );
+        assert_eq!(report.repaired, 0);
+
+        let report = database.verify(true).unwrap();
+        assert_eq!(report.repaired, 2);
+        assert!(database.verify(false).unwrap().is_consistent());
+        let owned: Vec<_> = database
+            .owned_coins_ids(&owner, None, None)
+            .try_collect()
+            .unwrap();
+        assert_eq!(owned, vec![utxo_id]);
+    }
+
+    #[test]
+    fn malformed_keys_are_reported_without_repair() {
+        let database = Database::default();
+        let key = vec![1; Address::LEN + 3];
+        let _: Option<bool> = database
+            .insert(key.clone(), Column::OwnedCoins, &true)
+            .unwrap();
+
+        let report = database.verify(true).unwrap();
+
+        assert_eq!(
+            report.inconsistencies,
+            vec![Inconsistency::MalformedKey {
+                column: Column::OwnedCoins,
+                key,
+            }]
+        );
+        assert_eq!(report.repaired, 0);
+    }
+
+    #[test]
+    fn contract_roots_are_verified_and_repaired() {
+        let mut database = Database::default();
+        let contract_id = ContractId::from([3; 32]);
+        let asset_id = AssetId::from([4; 32]);
+        database
+            .storage::<ContractsAssets>()
+            .insert(&ContractsAssetKey::new(&contract_id, &asset_id), &100)
+            .unwrap();
+        // Change the balance bypassing the merkle tree.
+        let _: Option<Word> = database
+            .insert(
+                ContractsAssetKey::new(&contract_id, &asset_id),
+                Column::ContractsAssets,
+                &200u64,
+            )
+            .unwrap();
+
+        let report = database.verify(false).unwrap();
+        assert!(matches!(
+            report.inconsistencies.as_slice(),
+            [Inconsistency::ContractAssetsRoot { contract_id: id, .. }] if *id == contract_id
+        ));
+
+        database.verify(true).unwrap();
+        assert!(database.verify(false).unwrap().is_consistent());
+    }
+
+    #[test]
+    fn block_merkle_data_is_verified_and_repaired() {
+        let mut database = Database::default();
+        let block_ids = insert_blocks(&mut database, 4);
+        database
+            .storage::<FuelBlockMerkleMetadata>()
+            .remove(&2u32.into())
+            .unwrap();
+
+        let report = database.verify(false).unwrap();
+        assert!(matches!(
+            report.inconsistencies.as_slice(),
+            [Inconsistency::BlockMerkleRoot { found: None, .. }]
+        ));
+
+        database.verify(true).unwrap();
+        assert!(database.verify(false).unwrap().is_consistent());
+        let root = database
+            .storage::<FuelBlockMerkleMetadata>()
+            .get(&3u32.into())
+            .unwrap()
+            .unwrap()
+            .root;
+        assert_eq!(
+            Bytes32::from(root),
+            ephemeral_merkle_root(block_ids.iter().map(|id| id.as_slice()))
+        );
+    }
+
+    #[test]
+    fn dangling_block_height_is_not_repaired() {
+        let mut database = Database::default();
+        let block_ids = insert_blocks(&mut database, 2);
+        let _: Option<CompressedBlock> = database
+            .remove(block_ids[1].as_slice(), Column::FuelBlocks)
+            .unwrap();
+
+        let report = database.verify(true).unwrap();
+
+        assert_eq!(
+            report.inconsistencies,
+            vec![Inconsistency::DanglingBlockHeight {
+                height: 1u32.into(),
+                block_id: block_ids[1],
+            }]
+        );
+        assert_eq!(report.repaired, 0);
+    }
+}
@@ -11,7 +11,11 @@ use fuel_core_executor::refs::ContractRef;
 use fuel_core_storage::{
     tables::{
         Coins,
+        ContractsAssets,
+        ContractsInfo,
         ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
         FuelBlocks,
         Messages,
         Receipts,
@@ -68,6 +72,7 @@ use fuel_core_types::{
         Address,
         AssetId,
         Bytes32,
+        Contract as ContractCode,
         Input,
         Mint,
         Output,
@@ -78,7 +83,10 @@ use fuel_core_types::{
         UniqueIdentifier,
         UtxoId,
     },
-    fuel_types::MessageId,
+    fuel_types::{
+        MessageId,
+        Salt,
+    },
     fuel_vm::{
         checked_transaction::{
             Checked,
@@ -104,6 +112,7 @@ use fuel_core_types::{
             ExecutionType,
             ExecutionTypes,
             Result as ExecutorResult,
+            StateOverrides,
             TransactionExecutionResult,
             TransactionExecutionStatus,
             TransactionValidityError,
@@ -191,8 +200,16 @@ where
         &self,
         block: ExecutionBlock,
         utxo_validation: Option<bool>,
-    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
-        let database = self.database.clone();
+        overrides: StateOverrides,
+    ) -> ExecutorResult<Vec<ExecutorResult<Vec<Receipt>>>> {
+        // apply the overrides to the fork of the state, the fork is never committed.
+        let database = if overrides.is_empty() {
+            self.database.clone()
+        } else {
+            let mut fork = self.database.transaction();
+            apply_state_overrides(fork.as_mut(), overrides)?;
+            fork.as_ref().clone()
+        };
 
         // fallback to service config value if no utxo_validation override is provided
         let utxo_validation = utxo_validation.unwrap_or(self.config.utxo_validation);
@@ -207,36 +224,99 @@ where
             database,
         };
 
+        let ids: Vec<_> = match &block {
+            ExecutionTypes::Production(block) => block
+                .transactions
+                .iter()
+                .map(UniqueIdentifier::id)
+                .collect(),
+            ExecutionTypes::Validation(block) => block
+                .transactions()
+                .iter()
+                .map(UniqueIdentifier::id)
+                .collect(),
+        };
+
         let (
             ExecutionResult {
-                block,
                 skipped_transactions,
                 ..
             },
             temporary_db,
         ) = executor.execute_without_commit(block)?.into();
 
-        // If one of the transactions fails, return an error.
-        if let Some((_, err)) = skipped_transactions.into_iter().next() {
-            return Err(err)
-        }
-
-        block
-            .transactions()
-            .iter()
-            .map(|tx| {
-                let id = tx.id();
-                StorageInspect::<Receipts>::get(temporary_db.as_ref(), &id)
-                    .transpose()
-                    .unwrap_or_else(|| Ok(Default::default()))
-                    .map(|v| v.into_owned())
+        // The skipped transactions keep the order of the block, so each one is matched
+        // with the next transaction having the same id.
+        let mut skipped_transactions = skipped_transactions.into_iter().peekable();
+        ids.into_iter()
+            .map(|id| {
+                if let Some((_, err)) =
+                    skipped_transactions.next_if(|(tx, _)| tx.id() == id)
+                {
+                    return Ok(Err(err))
+                }
+                let receipts =
+                    StorageInspect::<Receipts>::get(temporary_db.as_ref(), &id)?
+                        .map(|receipts| receipts.into_owned())
+                        .unwrap_or_default();
+                Ok(Ok(receipts))
             })
-            .collect::<Result<Vec<Vec<Receipt>>, _>>()
-            .map_err(Into::into)
+            .collect()
         // drop `temporary_db` without committing to avoid altering state.
     }
 }
 
+/// Writes the `overrides` into the `database`. The replaced bytecode of the contract
+/// updates its code root, the missing contract is created with zero salt.
+fn apply_state_overrides(
+    database: &mut Database,
+    overrides: StateOverrides,
+) -> ExecutorResult<()> {
+    for coin in overrides.coins {
+        database
+            .storage::<Coins>()
+            .insert(&coin.utxo_id, &coin.compress())?;
+    }
+    for message in overrides.messages {
+        database
+            .storage::<Messages>()
+            .insert(message.id(), &message)?;
+    }
+    for (contract_id, code) in overrides.contract_code {
+        let code = ContractCode::from(code);
+        let info = database
+            .storage::<ContractsInfo>()
+            .get(&contract_id)?
+            .map(|info| info.into_owned());
+        let salt = match info {
+            Some((salt, _)) => salt,
+            None => {
+                database
+                    .storage::<ContractsLatestUtxo>()
+                    .insert(&contract_id, &Default::default())?;
+                Salt::zeroed()
+            }
+        };
+        database
+            .storage::<ContractsInfo>()
+            .insert(&contract_id, &(salt, code.root()))?;
+        database
+            .storage::<ContractsRawCode>()
+            .insert(&contract_id, code.as_ref())?;
+    }
+    for (contract_id, key, value) in overrides.contract_state {
+        database
+            .storage::<ContractsState>()
+            .insert(&(&contract_id, &key).into(), &value)?;
+    }
+    for (contract_id, asset_id, balance) in overrides.contract_balances {
+        database
+            .storage::<ContractsAssets>()
+            .insert(&(&contract_id, &asset_id).into(), &balance)?;
+    }
+    Ok(())
+}
+
 impl<R> Executor<R>
 where
     R: RelayerPort + Clone,
@@ -303,6 +383,11 @@ where
         // save the associated owner for each transaction in the block
         self.index_tx_owners_for_block(&result.block, &mut block_db_transaction)?;
 
+        // save the previous values of the state changed by the block
+        if self.config.state_history {
+            block_db_transaction.record_state_history(result.block.header().height())?;
+        }
+
         // ------------ GraphQL API Functionality   END ------------
 
         // insert block into database
@@ -692,6 +777,8 @@ where
 
         // persist receipts
         self.persist_receipts(
+            *header.height(),
+            idx,
             &tx_id,
             vm_result.receipts(),
             tx_db_transaction.deref_mut(),
@@ -1355,6 +1442,8 @@ where
 
     fn persist_receipts(
         &self,
+        block_height: BlockHeight,
+        tx_idx: u16,
         tx_id: &Bytes32,
         receipts: &[Receipt],
         db: &mut Database,
@@ -1362,10 +1451,17 @@ where
         if db.storage::<Receipts>().insert(tx_id, receipts)?.is_some() {
             return Err(ExecutorError::OutputAlreadyExists)
         }
+        db.record_contract_receipts(
+            block_height,
+            tx_idx as TransactionIndex,
+            tx_id,
+            receipts,
+        )?;
         Ok(())
     }
 
-    /// Associate all transactions within a block to their respective UTXO owners
+    /// Associate all transactions within a block to their respective UTXO owners,
+    /// contracts and assets
     fn index_tx_owners_for_block(
         &self,
         block: &Block,
@@ -1397,6 +1493,12 @@ where
                 tx_idx as u16,
                 block_db_transaction.deref_mut(),
             )?;
+            block_db_transaction.record_tx_id_contracts_and_assets(
+                tx,
+                block_height,
+                tx_idx as TransactionIndex,
+                &tx_id,
+            )?;
         }
         Ok(())
     }
@@ -1,3 +1,7 @@
+use crate::{
+    graphql_api::rate_limit::RateLimitConfig,
+    state::rocks_db_config::RocksDbConfig,
+};
 use fuel_core_storage::{
     Error as StorageError,
     IsNotFound,
@@ -5,11 +9,18 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     fuel_tx::ConsensusParameters,
+    fuel_vm::GasCosts,
     secrecy::Secret,
 };
-use std::net::SocketAddr;
+use std::{
+    net::SocketAddr,
+    path::PathBuf,
+};
 
+pub mod admin;
+pub mod batch_complexity;
 pub mod ports;
+pub mod rate_limit;
 pub mod service;
 
 #[derive(Clone, Debug)]
@@ -22,7 +33,68 @@ pub struct Config {
     pub max_tx: usize,
     pub max_depth: usize,
     pub transaction_parameters: ConsensusParameters,
+    pub gas_costs: GasCosts,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// The directory where the database checkpoints are created.
+    /// Checkpoints are disabled if it is not set.
+    pub checkpoints_dir: Option<PathBuf>,
+    /// The node serves the read-only database, the transactions can't be submitted.
+    pub read_only: bool,
+    /// The tuning of the database reported by `nodeInfo`, `None` for the in-memory database.
+    pub database_options: Option<RocksDbConfig>,
+    pub limits: ApiLimits,
+    /// The bearer token of the admin API. The admin API is served by `addr`
+    /// only if the token is set.
+    pub admin_token: Option<Secret<String>>,
+    /// The address of the listener serving only the admin API. Without the admin
+    /// token, it must be the loopback address.
+    pub admin_addr: Option<SocketAddr>,
+}
+
+/// The limits protecting the node from the expensive queries and the abusive clients.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApiLimits {
+    /// The maximum nesting of the fields in the query.
+    pub max_depth: usize,
+    /// The maximum number of the fields in the query.
+    pub max_complexity: usize,
+    /// The maximum number of the elements requested by `first` or `last`
+    /// from the connection queries.
+    pub max_page_size: usize,
+    /// The maximum size of the request body in bytes.
+    pub request_body_bytes_limit: usize,
+    /// The maximum number of the queries in one batched request.
+    pub max_batch_size: usize,
+    /// The number of the automatic persisted queries cached by the node.
+    pub persisted_queries_cache_size: usize,
+    /// The rate limit of the requests of each client, not limited if `None`.
+    pub rate_limit: Option<RateLimitConfig>,
+}
+
+impl ApiLimits {
+    pub const DEFAULT_MAX_DEPTH: usize = 16;
+    /// Allows the full page of the blocks with the ids of their transactions.
+    pub const DEFAULT_MAX_COMPLEXITY: usize = 80_000;
+    pub const DEFAULT_MAX_PAGE_SIZE: usize = 1024;
+    /// Fits the transaction with the biggest contract allowed by the default
+    /// consensus parameters encoded as hex.
+    pub const DEFAULT_REQUEST_BODY_BYTES_LIMIT: usize = 40 * 1024 * 1024;
+    pub const DEFAULT_MAX_BATCH_SIZE: usize = 32;
+    pub const DEFAULT_PERSISTED_QUERIES_CACHE_SIZE: usize = 1024;
+}
+
+impl Default for ApiLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+            max_complexity: Self::DEFAULT_MAX_COMPLEXITY,
+            max_page_size: Self::DEFAULT_MAX_PAGE_SIZE,
+            request_body_bytes_limit: Self::DEFAULT_REQUEST_BODY_BYTES_LIMIT,
+            max_batch_size: Self::DEFAULT_MAX_BATCH_SIZE,
+            persisted_queries_cache_size: Self::DEFAULT_PERSISTED_QUERIES_CACHE_SIZE,
+            rate_limit: None,
+        }
+    }
 }
 
 pub trait IntoApiResult<T> {
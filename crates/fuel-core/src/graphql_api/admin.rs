@@ -0,0 +1,112 @@
+use axum::{
+    body::Body,
+    http::{
+        header::{
+            AUTHORIZATION,
+            WWW_AUTHENTICATE,
+        },
+        HeaderMap,
+        Request,
+        StatusCode,
+    },
+    middleware::Next,
+    response::{
+        IntoResponse,
+        Response,
+    },
+    Json,
+};
+use fuel_core_types::secrecy::{
+    ExposeSecret,
+    Secret,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+/// The path of the admin GraphQL API with the privileged operations.
+pub const ADMIN_GRAPHQL_PATH: &str = "/admin/graphql";
+
+/// The access to the admin API.
+#[derive(Debug)]
+pub enum Access {
+    /// The request has the valid bearer token.
+    Granted,
+    /// The request has no bearer token or the token is invalid.
+    Unauthorized,
+}
+
+/// Checks that the request has the `Authorization: Bearer <token>` header.
+pub fn access(token: &Secret<String>, headers: &HeaderMap) -> Access {
+    let bearer = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    match bearer {
+        Some(bearer)
+            if constant_time_eq(bearer.as_bytes(), token.expose_secret().as_bytes()) =>
+        {
+            Access::Granted
+        }
+        _ => Access::Unauthorized,
+    }
+}
+
+/// Compares the secrets without leaking the length of the matching prefix.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+/// The middleware rejecting the unauthorized requests to the admin API.
+pub async fn authorize(
+    token: Arc<Secret<String>>,
+    req: Request<Body>,
+    next: Next<Body>,
+) -> Response {
+    match access(&token, req.headers()) {
+        Access::Granted => next.run(req).await,
+        Access::Unauthorized => {
+            let body = json!({
+                "data": null,
+                "errors": [{ "message": "The admin API requires the valid bearer token" }],
+            });
+            let mut response = (StatusCode::UNAUTHORIZED, Json(body)).into_response();
+            response
+                .headers_mut()
+                .insert(WWW_AUTHENTICATE, "Bearer".parse().expect("Valid header"));
+            response
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(authorization: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, authorization.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn valid_bearer_token_is_required() {
+        let token = Secret::new("secret".to_string());
+
+        assert!(matches!(
+            access(&token, &HeaderMap::new()),
+            Access::Unauthorized
+        ));
+        assert!(matches!(
+            access(&token, &headers("Bearer wrong")),
+            Access::Unauthorized
+        ));
+        assert!(matches!(
+            access(&token, &headers("secret")),
+            Access::Unauthorized
+        ));
+        assert!(matches!(
+            access(&token, &headers("Bearer secret")),
+            Access::Granted
+        ));
+    }
+}
@@ -0,0 +1,103 @@
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextValidation,
+    },
+    ServerError,
+    ValidationResult,
+};
+use std::sync::{
+    atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+    Arc,
+};
+
+/// The complexity left to the requests of one batch. Each request of the batch
+/// shares the same budget, so the batch can't multiply the allowed complexity.
+#[derive(Clone, Debug)]
+pub struct BatchComplexity {
+    max_complexity: usize,
+    remaining: Arc<AtomicUsize>,
+}
+
+impl BatchComplexity {
+    pub fn new(max_complexity: usize) -> Self {
+        Self {
+            max_complexity,
+            remaining: Arc::new(AtomicUsize::new(max_complexity)),
+        }
+    }
+
+    /// Takes the `complexity` from the budget, or fails if the budget is exceeded.
+    fn consume(&self, complexity: usize) -> Result<(), ServerError> {
+        self.remaining
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |remaining| {
+                remaining.checked_sub(complexity)
+            })
+            .map(|_| ())
+            .map_err(|_| {
+                ServerError::new(
+                    format!(
+                        "The batch exceeds the maximum complexity `{}`",
+                        self.max_complexity
+                    ),
+                    None,
+                )
+            })
+    }
+}
+
+/// The extension rejecting the requests that exceed the [`BatchComplexity`]
+/// of their batch. The requests without the budget aren't affected.
+pub struct BatchComplexityLimit;
+
+impl ExtensionFactory for BatchComplexityLimit {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(BatchComplexityLimitExtension)
+    }
+}
+
+struct BatchComplexityLimitExtension;
+
+#[async_trait::async_trait]
+impl Extension for BatchComplexityLimitExtension {
+    async fn validation(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextValidation<'_>,
+    ) -> Result<ValidationResult, Vec<ServerError>> {
+        let result = next.run(ctx).await?;
+        if let Some(budget) = ctx.data_opt::<BatchComplexity>() {
+            budget.consume(result.complexity).map_err(|err| vec![err])?;
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_is_shared_by_the_clones() {
+        let budget = BatchComplexity::new(10);
+        let clone = budget.clone();
+
+        budget.consume(6).unwrap();
+        clone.consume(4).unwrap();
+
+        assert!(budget.consume(1).is_err());
+    }
+
+    #[test]
+    fn exceeding_request_does_not_consume_the_budget() {
+        let budget = BatchComplexity::new(10);
+
+        assert!(budget.consume(11).is_err());
+        budget.consume(10).unwrap();
+    }
+}
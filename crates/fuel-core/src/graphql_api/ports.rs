@@ -1,3 +1,10 @@
+use crate::{
+    database::{
+        receipts::ContractReceiptIndexCursor,
+        Column,
+    },
+    state::ColumnStats,
+};
 use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
@@ -43,15 +50,23 @@ use fuel_core_types::{
         Nonce,
     },
     services::{
+        executor::{
+            Result as ExecutorResult,
+            StateOverrides,
+        },
         graphql_api::ContractBalance,
         txpool::{
             InsertionResult,
             TransactionStatus,
+            TxSource,
         },
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    path::Path,
+    sync::Arc,
+};
 use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 
 /// The database port expected by GraphQL API service.
@@ -64,6 +79,9 @@ pub trait DatabasePort:
     + DatabaseCoins
     + DatabaseContracts
     + DatabaseChain
+    + DatabaseStateHistory
+    + DatabaseCheckpoint
+    + DatabaseStats
 {
 }
 
@@ -81,6 +99,12 @@ pub trait DatabaseBlocks:
     ) -> BoxedIter<'_, StorageResult<(BlockHeight, BlockId)>>;
 
     fn ids_of_latest_block(&self) -> StorageResult<(BlockHeight, BlockId)>;
+
+    /// Returns the height of the last pruned block, if the pruning has happened.
+    fn pruned_height(&self) -> StorageResult<Option<BlockHeight>>;
+
+    /// Returns the height of the block with the `id`, if the block was pruned.
+    fn pruned_block_height(&self, id: &BlockId) -> StorageResult<Option<BlockHeight>>;
 }
 
 /// Trait that specifies all the getters required for transactions.
@@ -90,12 +114,41 @@ pub trait DatabaseTransactions:
 {
     fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus>;
 
+    /// Returns the height of the block that included the transaction with the `tx_id`,
+    /// if the block was pruned.
+    fn pruned_tx_height(&self, tx_id: &TxId) -> StorageResult<Option<BlockHeight>>;
+
     fn owned_transactions_ids(
         &self,
         owner: Address,
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
+    /// Returns the ids of the transactions that use the `contract` as an input or create it.
+    fn contract_transactions_ids(
+        &self,
+        contract: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
+    /// Returns the ids of the transactions that spend or create coins of the `asset`.
+    fn asset_transactions_ids(
+        &self,
+        asset: AssetId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
+    /// Returns the positions of the receipts emitted by the `contract`
+    /// with the ids of the transactions that contain them.
+    fn contract_receipts_ids(
+        &self,
+        contract: ContractId,
+        start: Option<ContractReceiptIndexCursor>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(ContractReceiptIndexCursor, TxId)>>;
 }
 
 /// Trait that specifies all the getters required for messages.
@@ -148,16 +201,65 @@ pub trait DatabaseChain {
     fn base_chain_height(&self) -> StorageResult<DaBlockHeight>;
 }
 
+/// Trait that specifies access to the state at past block heights.
+pub trait DatabaseStateHistory {
+    /// Returns the read-only view of the state at the end of the block at `height`.
+    fn state_at_height(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Box<dyn DatabasePort>>;
+}
+
+/// Trait that specifies the backup of the database.
+pub trait DatabaseCheckpoint {
+    /// Creates a consistent copy of the database in the `path` directory.
+    fn create_checkpoint(&self, path: &Path) -> StorageResult<()>;
+}
+
+/// Trait that specifies the statistics of the database.
+pub trait DatabaseStats {
+    /// Returns the statistics of each column of the database.
+    fn column_stats(&self) -> StorageResult<Vec<(Column, ColumnStats)>>;
+}
+
 pub trait TxPoolPort: Send + Sync {
     fn transaction(&self, id: TxId) -> Option<Transaction>;
 
     fn submission_time(&self, id: TxId) -> Option<Tai64>;
 
-    fn insert(&self, txs: Vec<Arc<Transaction>>) -> Vec<anyhow::Result<InsertionResult>>;
+    fn insert(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+        source: Option<TxSource>,
+    ) -> Vec<anyhow::Result<InsertionResult>>;
 
     fn tx_update_subscribe(
         &self,
     ) -> BoxStream<anyhow::Result<TxUpdate, BroadcastStreamRecvError>>;
+
+    /// The number of pending transactions in the pool.
+    fn pending_count(&self) -> usize;
+
+    /// The gas limit of all pending transactions combined.
+    fn consumable_gas(&self) -> u64;
+
+    /// All pending transactions sorted by the gas price, starting from the highest.
+    fn sorted_by_price(&self) -> Vec<Transaction>;
+
+    /// All pending transactions sorted by the time of submission, starting from the oldest.
+    fn sorted_by_time(&self) -> Vec<Transaction>;
+
+    /// The pending transactions spending the coins or messages of the `owner`.
+    fn transactions_by_owner(&self, owner: &Address) -> Vec<Transaction>;
+
+    /// The pending transactions which outputs are spent by the transaction,
+    /// directly or through other pending transactions. Returns `None`
+    /// if the transaction is not in the pool.
+    fn dependencies(&self, id: TxId) -> Option<Vec<Transaction>>;
+
+    /// Removes the transactions and the ones depending on them from the pool.
+    /// Returns the ids of all removed transactions.
+    fn remove(&self, ids: Vec<TxId>) -> Vec<TxId>;
 }
 
 #[async_trait]
@@ -167,11 +269,29 @@ pub trait DryRunExecution {
         transaction: Transaction,
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
+        overrides: StateOverrides,
     ) -> anyhow::Result<Vec<Receipt>>;
+
+    /// Executes the transactions in one block, returns the receipts of each transaction
+    /// or the reason why the transaction is invalid in the block.
+    async fn dry_run_txs(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
+        overrides: StateOverrides,
+    ) -> anyhow::Result<Vec<ExecutorResult<Vec<Receipt>>>>;
 }
 
 pub trait BlockProducerPort: Send + Sync + DryRunExecution {}
 
+pub trait BlockImporterPort: Send + Sync {
+    /// Returns the stream notifying about the new blocks committed to the database.
+    /// The notification contains the height of the latest committed block.
+    /// The notifications may be skipped when the subscriber is slow.
+    fn block_events(&self) -> BoxStream<BlockHeight>;
+}
+
 #[async_trait::async_trait]
 pub trait ConsensusModulePort: Send + Sync {
     async fn manually_produce_blocks(
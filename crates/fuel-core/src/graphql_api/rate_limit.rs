@@ -0,0 +1,262 @@
+use axum::{
+    body::Body,
+    extract::ConnectInfo,
+    http::{
+        header::RETRY_AFTER,
+        HeaderMap,
+        Request,
+        StatusCode,
+    },
+    middleware::Next,
+    response::{
+        IntoResponse,
+        Response,
+    },
+    Json,
+};
+use serde_json::json;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    net::{
+        IpAddr,
+        SocketAddr,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The header with the API key of the client.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// The buckets of the idle clients are dropped when the number of tracked clients exceeds it.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+/// The configuration of the token bucket rate limiter of the GraphQL requests.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateLimitConfig {
+    /// The number of requests per second allowed for one client in the long run.
+    pub requests_per_second: u32,
+    /// The number of requests that one client can make at once.
+    pub burst: u32,
+    /// The API keys identifying the clients instead of their IP addresses.
+    /// The unknown API keys are ignored, so the client can't bypass the limit
+    /// by changing the key.
+    pub api_keys: HashSet<String>,
+}
+
+/// The client identified by the rate limiter. It is available to the resolvers
+/// as the request data.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Client {
+    ApiKey(String),
+    Ip(IpAddr),
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// Limits the rate of the requests of each client with its own token bucket.
+#[derive(Debug)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    buckets: Mutex<HashMap<Client, TokenBucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            buckets: Default::default(),
+        }
+    }
+
+    /// Identifies the client of the request by the known API key or by the IP address.
+    pub fn client(&self, headers: &HeaderMap, addr: IpAddr) -> Client {
+        headers
+            .get(API_KEY_HEADER)
+            .and_then(|key| key.to_str().ok())
+            .filter(|key| self.config.api_keys.contains(*key))
+            .map(|key| Client::ApiKey(key.to_string()))
+            .unwrap_or(Client::Ip(addr))
+    }
+
+    /// Takes the token from the bucket of the `client`. Returns the time after which
+    /// the next token is available if the bucket is empty.
+    pub fn try_acquire(&self, client: Client, now: Instant) -> Result<(), Duration> {
+        self.try_acquire_many(client, 1, now)
+    }
+
+    /// Takes `count` tokens from the bucket of the `client` at once, or none of them.
+    /// Returns the time after which the bucket has enough tokens. The `count` above
+    /// the burst is never allowed.
+    pub fn try_acquire_many(
+        &self,
+        client: Client,
+        count: u32,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        let count = count as f64;
+        let rate = self.config.requests_per_second as f64;
+        let burst = self.config.burst as f64;
+        let mut buckets = self.buckets.lock().expect("The lock is not poisoned");
+
+        if buckets.len() >= MAX_TRACKED_CLIENTS && !buckets.contains_key(&client) {
+            // The bucket refilled to the burst is the same as a new one.
+            buckets.retain(|_, bucket| {
+                let elapsed = now.saturating_duration_since(bucket.updated_at);
+                bucket.tokens + elapsed.as_secs_f64() * rate < burst
+            });
+        }
+
+        let bucket = buckets.entry(client).or_insert(TokenBucket {
+            tokens: burst,
+            updated_at: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.updated_at);
+        bucket.tokens = (bucket.tokens + elapsed.as_secs_f64() * rate).min(burst);
+        bucket.updated_at = now;
+
+        if bucket.tokens >= count {
+            bucket.tokens -= count;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((count - bucket.tokens) / rate))
+        }
+    }
+
+    /// The response to the request of the client that exceeded the rate limit.
+    pub fn limit_exceeded_response(&self, retry_after: Duration) -> Response {
+        let retry_after = retry_after.as_secs().max(1);
+        let body = json!({
+            "data": null,
+            "errors": [{
+                "message": format!(
+                    "The rate limit of {} requests per second is exceeded, \
+                    retry after {} seconds",
+                    self.config.requests_per_second, retry_after
+                ),
+            }],
+        });
+        (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(RETRY_AFTER, retry_after.to_string())],
+            Json(body),
+        )
+            .into_response()
+    }
+}
+
+/// The middleware rejecting the requests of the clients that exceeded the rate limit.
+pub async fn rate_limit(
+    limiter: Arc<RateLimiter>,
+    req: Request<Body>,
+    next: Next<Body>,
+) -> Response {
+    let addr = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+    let addr = match addr {
+        Some(addr) => addr,
+        // The address is unknown only if the router is served without the connection info.
+        None => return next.run(req).await,
+    };
+
+    let client = limiter.client(req.headers(), addr);
+    match limiter.try_acquire(client, Instant::now()) {
+        Ok(()) => next.run(req).await,
+        Err(retry_after) => limiter.limit_exceeded_response(retry_after),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(requests_per_second: u32, burst: u32) -> RateLimiter {
+        RateLimiter::new(RateLimitConfig {
+            requests_per_second,
+            burst,
+            api_keys: ["known".to_string()].into_iter().collect(),
+        })
+    }
+
+    #[test]
+    fn burst_is_allowed_and_then_limited() {
+        let limiter = limiter(1, 3);
+        let client = Client::Ip([127, 0, 0, 1].into());
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            limiter.try_acquire(client.clone(), now).unwrap();
+        }
+        let retry_after = limiter.try_acquire(client.clone(), now).unwrap_err();
+
+        assert_eq!(retry_after, Duration::from_secs(1));
+        limiter
+            .try_acquire(client, now + Duration::from_secs(1))
+            .unwrap();
+    }
+
+    #[test]
+    fn many_tokens_are_taken_at_once_or_not_at_all() {
+        let limiter = limiter(1, 3);
+        let client = Client::Ip([127, 0, 0, 1].into());
+        let now = Instant::now();
+
+        limiter.try_acquire(client.clone(), now).unwrap();
+        let retry_after = limiter
+            .try_acquire_many(client.clone(), 3, now)
+            .unwrap_err();
+
+        assert_eq!(retry_after, Duration::from_secs(1));
+        limiter.try_acquire_many(client, 2, now).unwrap();
+    }
+
+    #[test]
+    fn clients_have_separate_buckets() {
+        let limiter = limiter(1, 1);
+        let now = Instant::now();
+
+        limiter
+            .try_acquire(Client::Ip([127, 0, 0, 1].into()), now)
+            .unwrap();
+        limiter
+            .try_acquire(Client::Ip([127, 0, 0, 2].into()), now)
+            .unwrap();
+        limiter
+            .try_acquire(Client::ApiKey("known".to_string()), now)
+            .unwrap();
+        assert!(limiter
+            .try_acquire(Client::Ip([127, 0, 0, 1].into()), now)
+            .is_err());
+    }
+
+    #[test]
+    fn only_known_api_keys_identify_the_client() {
+        let limiter = limiter(1, 1);
+        let addr = [127, 0, 0, 1].into();
+        let mut headers = HeaderMap::new();
+
+        assert_eq!(limiter.client(&headers, addr), Client::Ip(addr));
+        headers.insert(API_KEY_HEADER, "unknown".parse().unwrap());
+        assert_eq!(limiter.client(&headers, addr), Client::Ip(addr));
+        headers.insert(API_KEY_HEADER, "known".parse().unwrap());
+        assert_eq!(
+            limiter.client(&headers, addr),
+            Client::ApiKey("known".to_string())
+        );
+    }
+}
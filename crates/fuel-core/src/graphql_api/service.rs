@@ -1,28 +1,66 @@
 use crate::{
     fuel_core_graphql_api::ports::{
+        BlockImporterPort,
         BlockProducerPort,
         ConsensusModulePort,
         DatabasePort,
         TxPoolPort,
     },
-    graphql_api::Config,
+    graphql_api::{
+        admin::{
+            authorize,
+            ADMIN_GRAPHQL_PATH,
+        },
+        batch_complexity::{
+            BatchComplexity,
+            BatchComplexityLimit,
+        },
+        rate_limit::{
+            rate_limit,
+            Client,
+            RateLimiter,
+        },
+        ApiLimits,
+        Config,
+    },
     schema::{
+        AdminSchema,
+        AdminSchemaBuilder,
         CoreSchema,
         CoreSchemaBuilder,
     },
     service::metrics::metrics,
 };
 use async_graphql::{
-    extensions::Tracing,
+    extensions::{
+        apollo_persisted_queries::{
+            ApolloPersistedQueries,
+            LruCacheStorage,
+        },
+        Tracing,
+    },
     http::{
         playground_source,
         GraphQLPlaygroundConfig,
+        Protocols,
+        WebSocket as GraphQLWebSocket,
+        WsMessage,
+        ALL_WEBSOCKET_PROTOCOLS,
     },
+    BatchRequest,
+    BatchResponse,
     Request,
     Response,
 };
 use axum::{
     extract::{
+        rejection::JsonRejection,
+        ws::{
+            CloseFrame,
+            Message,
+            WebSocketUpgrade,
+        },
+        ConnectInfo,
         DefaultBodyLimit,
         Extension,
     },
@@ -31,9 +69,13 @@ use axum::{
             ACCESS_CONTROL_ALLOW_HEADERS,
             ACCESS_CONTROL_ALLOW_METHODS,
             ACCESS_CONTROL_ALLOW_ORIGIN,
+            SEC_WEBSOCKET_PROTOCOL,
         },
+        HeaderMap,
         HeaderValue,
+        StatusCode,
     },
+    middleware,
     response::{
         sse::Event,
         Html,
@@ -52,7 +94,10 @@ use fuel_core_services::{
     RunnableTask,
     StateWatcher,
 };
-use futures::Stream;
+use futures::{
+    SinkExt,
+    Stream,
+};
 use serde_json::json;
 use std::{
     future::Future,
@@ -61,6 +106,8 @@ use std::{
         TcpListener,
     },
     pin::Pin,
+    sync::Arc,
+    time::Instant,
 };
 use tokio_stream::StreamExt;
 use tower_http::{
@@ -73,6 +120,7 @@ pub type Service = fuel_core_services::ServiceRunner<NotInitializedTask>;
 pub type Database = Box<dyn DatabasePort>;
 
 pub type BlockProducer = Box<dyn BlockProducerPort>;
+pub type BlockImporter = Box<dyn BlockImporterPort>;
 // In the future GraphQL should not be aware of `TxPool`. It should
 //  use only `Database` to receive all information about transactions.
 pub type TxPool = Box<dyn TxPoolPort>;
@@ -81,12 +129,16 @@ pub type ConsensusModule = Box<dyn ConsensusModulePort>;
 #[derive(Clone)]
 pub struct SharedState {
     pub bound_address: SocketAddr,
+    /// The address of the admin API if it has its own listener.
+    pub admin_bound_address: Option<SocketAddr>,
 }
 
 pub struct NotInitializedTask {
     router: Router,
     listener: TcpListener,
     bound_address: SocketAddr,
+    /// The router and the listener of the admin API with its own address.
+    admin: Option<(Router, TcpListener)>,
 }
 
 pub struct Task {
@@ -104,27 +156,51 @@ impl RunnableService for NotInitializedTask {
     fn shared_data(&self) -> Self::SharedData {
         SharedState {
             bound_address: self.bound_address,
+            admin_bound_address: self
+                .admin
+                .as_ref()
+                .and_then(|(_, listener)| listener.local_addr().ok()),
         }
     }
 
     async fn into_task(self, state: &StateWatcher) -> anyhow::Result<Self::Task> {
-        let mut state = state.clone();
-        let server = axum::Server::from_tcp(self.listener)
-            .unwrap()
-            .serve(self.router.into_make_service())
-            .with_graceful_shutdown(async move {
-                state
-                    .while_started()
-                    .await
-                    .expect("The service is destroyed");
-            });
+        let server = serve(self.router, self.listener, state.clone());
+        let admin_server = self
+            .admin
+            .map(|(router, listener)| serve(router, listener, state.clone()));
 
+        let server = async move {
+            match admin_server {
+                Some(admin_server) => {
+                    futures::future::try_join(server, admin_server).await?;
+                    Ok(())
+                }
+                None => server.await,
+            }
+        };
         Ok(Task {
             server: Box::pin(server),
         })
     }
 }
 
+/// Serves the `router` until the service is stopped.
+fn serve(
+    router: Router,
+    listener: TcpListener,
+    mut state: StateWatcher,
+) -> impl Future<Output = hyper::Result<()>> + Send + 'static {
+    axum::Server::from_tcp(listener)
+        .unwrap()
+        .serve(router.into_make_service_with_connect_info::<SocketAddr>())
+        .with_graceful_shutdown(async move {
+            state
+                .while_started()
+                .await
+                .expect("The service is destroyed");
+        })
+}
+
 #[async_trait::async_trait]
 impl RunnableTask for Task {
     async fn run(&mut self, _: &mut StateWatcher) -> anyhow::Result<bool> {
@@ -143,35 +219,159 @@ impl RunnableTask for Task {
 }
 
 // Need a seperate Data Object for each Query endpoint, cannot be avoided
+#[allow(clippy::too_many_arguments)]
 pub fn new_service(
     config: Config,
     schema: CoreSchemaBuilder,
+    admin_schema: AdminSchemaBuilder,
     database: Database,
     txpool: TxPool,
     producer: BlockProducer,
+    importer: BlockImporter,
     consensus_module: ConsensusModule,
 ) -> anyhow::Result<Service> {
     let network_addr = config.addr;
+    let admin_addr = config.admin_addr;
+    let limits = config.limits.clone();
+    // The WebSocket messages are limited the same way as the request bodies.
+    let max_message_size = limits.request_body_bytes_limit;
+    let batch_limits = BatchLimits {
+        max_batch_size: limits.max_batch_size,
+        max_complexity: limits.max_complexity,
+        rate_limiter: limits
+            .rate_limit
+            .clone()
+            .map(|config| Arc::new(RateLimiter::new(config))),
+    };
+    let admin_token = config.admin_token.clone();
 
+    let admin_schema = admin_schema
+        .data(config.clone())
+        .data(consensus_module)
+        .extension(Tracing)
+        .finish();
     let schema = schema
+        .limit_depth(limits.max_depth)
+        .limit_complexity(limits.max_complexity)
         .data(config)
         .data(database)
         .data(txpool)
         .data(producer)
-        .data(consensus_module)
+        .data(importer)
+        .extension(ApolloPersistedQueries::new(LruCacheStorage::new(
+            limits.persisted_queries_cache_size,
+        )))
+        .extension(BatchComplexityLimit)
         .extension(Tracing)
         .finish();
 
-    let router = Router::new()
-        .route("/playground", get(graphql_playground))
-        .route("/graphql", post(graphql_handler).options(ok))
+    // The middleware and the handlers share the limiter to use the same buckets
+    // and to identify the clients by the same API keys.
+    let rate_limiter = batch_limits.rate_limiter.clone();
+    let sub_limiter = rate_limiter.clone();
+    let ws_limiter = rate_limiter.clone();
+    let mut graphql_router = Router::new()
+        .route(
+            "/graphql",
+            post(move |schema, addr, headers, req| {
+                graphql_handler(schema, addr, headers, req, batch_limits.clone())
+            })
+            .options(ok),
+        )
         .route(
             "/graphql-sub",
-            post(graphql_subscription_handler).options(ok),
+            post(move |schema, addr, headers, req| {
+                graphql_subscription_handler(
+                    schema,
+                    addr,
+                    headers,
+                    req,
+                    sub_limiter.clone(),
+                )
+            })
+            .options(ok),
         )
+        .route(
+            "/graphql-ws",
+            get(move |schema, addr, headers, upgrade| {
+                graphql_ws_handler(
+                    schema,
+                    addr,
+                    headers,
+                    upgrade,
+                    max_message_size,
+                    ws_limiter.clone(),
+                )
+            }),
+        );
+    if let Some(limiter) = rate_limiter {
+        graphql_router =
+            graphql_router.route_layer(middleware::from_fn(move |req, next| {
+                rate_limit(limiter.clone(), req, next)
+            }));
+    }
+
+    // The admin API isn't rate limited, it is available only to the node operator.
+    let mut admin_router = Router::new()
+        .route(ADMIN_GRAPHQL_PATH, post(admin_graphql_handler).options(ok))
+        .layer(Extension(admin_schema));
+    if let Some(token) = &admin_token {
+        let token = Arc::new(token.clone());
+        admin_router = admin_router.route_layer(middleware::from_fn(move |req, next| {
+            authorize(token.clone(), req, next)
+        }));
+    }
+
+    // The admin API is served by its own listener, or by the public one if it requires
+    // the token. Without the token, its own listener is trusted only on the loopback
+    // address. Otherwise, nobody is trusted to access it, so it isn't served.
+    let mut router = Router::new()
+        .route("/playground", get(graphql_playground))
+        .merge(graphql_router);
+    let mut admin = None;
+    match (admin_addr, &admin_token) {
+        (Some(admin_addr), None) if !admin_addr.ip().is_loopback() => {
+            return Err(anyhow::anyhow!(
+                "The admin API on {admin_addr} requires the admin token, \
+                only the loopback address can serve it without the token"
+            ))
+        }
+        (Some(admin_addr), _) => {
+            let listener = TcpListener::bind(admin_addr)?;
+            tracing::info!("Binding GraphQL admin API to {}", listener.local_addr()?);
+            admin = Some((with_http_layers(admin_router, &limits), listener));
+        }
+        (None, Some(_)) => router = router.merge(admin_router),
+        (None, None) => {
+            tracing::info!(
+                "The admin API is disabled because neither the admin token \
+                nor the admin address is configured"
+            )
+        }
+    }
+
+    let router = router
         .route("/metrics", get(metrics))
         .route("/health", get(health))
-        .layer(Extension(schema))
+        .layer(Extension(schema));
+    let router = with_http_layers(router, &limits);
+
+    let listener = TcpListener::bind(network_addr)?;
+    let bound_address = listener.local_addr()?;
+
+    tracing::info!("Binding GraphQL provider to {}", bound_address);
+
+    Ok(Service::new(NotInitializedTask {
+        router,
+        listener,
+        bound_address,
+        admin,
+    }))
+}
+
+/// Adds the tracing, the CORS headers and the body limit to the `router`.
+fn with_http_layers(router: Router, limits: &ApiLimits) -> Router {
+    router
         .layer(TraceLayer::new_for_http())
         .layer(SetResponseHeaderLayer::<_>::overriding(
             ACCESS_CONTROL_ALLOW_ORIGIN,
@@ -185,18 +385,7 @@ pub fn new_service(
             ACCESS_CONTROL_ALLOW_HEADERS,
             HeaderValue::from_static("*"),
         ))
-        .layer(DefaultBodyLimit::disable());
-
-    let listener = TcpListener::bind(network_addr)?;
-    let bound_address = listener.local_addr()?;
-
-    tracing::info!("Binding GraphQL provider to {}", bound_address);
-
-    Ok(Service::new(NotInitializedTask {
-        router,
-        listener,
-        bound_address,
-    }))
+        .layer(DefaultBodyLimit::max(limits.request_body_bytes_limit))
 }
 
 async fn graphql_playground() -> impl IntoResponse {
@@ -207,22 +396,182 @@ async fn health() -> Json<serde_json::Value> {
     Json(json!({ "up": true }))
 }
 
+/// The limits of the batch of requests served by the `graphql_handler`.
+#[derive(Clone)]
+struct BatchLimits {
+    max_batch_size: usize,
+    /// The maximum complexity of all requests of the batch together.
+    max_complexity: usize,
+    /// The limiter charging each request of the batch.
+    rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+/// Serves the single request or the batch of requests sent as the JSON array.
+/// The responses of the batch are returned in the order of the requests.
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
-    req: Json<Request>,
-) -> Json<Response> {
-    schema.execute(req.0).await.into()
+    addr: Option<ConnectInfo<SocketAddr>>,
+    headers: HeaderMap,
+    req: Result<Json<BatchRequest>, JsonRejection>,
+    limits: BatchLimits,
+) -> Result<Json<BatchResponse>, axum::response::Response> {
+    let req = req.map_err(|rejection| rejection_response(rejection).into_response())?;
+    let mut req = req.0;
+    if let BatchRequest::Batch(requests) = &req {
+        if requests.len() > limits.max_batch_size {
+            let error = async_graphql::ServerError::new(
+                format!(
+                    "The batch of `{}` requests exceeds the maximum batch size `{}`",
+                    requests.len(),
+                    limits.max_batch_size
+                ),
+                None,
+            );
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(Response::from_errors(vec![error])),
+            )
+                .into_response())
+        }
+
+        // The rate limit middleware already took the token for the first request.
+        let extra_requests = requests.len().saturating_sub(1) as u32;
+        if let (Some(limiter), Some(ConnectInfo(addr))) = (&limits.rate_limiter, addr) {
+            if extra_requests > 0 {
+                let client = limiter.client(&headers, addr.ip());
+                limiter
+                    .try_acquire_many(client, extra_requests, Instant::now())
+                    .map_err(|retry_after| {
+                        limiter.limit_exceeded_response(retry_after)
+                    })?;
+            }
+        }
+        req = req.data(BatchComplexity::new(limits.max_complexity));
+    }
+    let req = match identify(limits.rate_limiter.as_deref(), &headers, addr) {
+        Some(client) => req.data(client),
+        None => req,
+    };
+    Ok(schema.execute_batch(req).await.into())
+}
+
+async fn admin_graphql_handler(
+    schema: Extension<AdminSchema>,
+    req: Result<Json<Request>, JsonRejection>,
+) -> Result<Json<Response>, (StatusCode, Json<Response>)> {
+    let req = req.map_err(rejection_response)?;
+    Ok(schema.execute(req.0).await.into())
 }
 
 async fn graphql_subscription_handler(
     schema: Extension<CoreSchema>,
-    req: Json<Request>,
-) -> Sse<impl Stream<Item = anyhow::Result<Event, serde_json::Error>>> {
+    addr: Option<ConnectInfo<SocketAddr>>,
+    headers: HeaderMap,
+    req: Result<Json<Request>, JsonRejection>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+) -> Result<
+    Sse<impl Stream<Item = anyhow::Result<Event, serde_json::Error>>>,
+    (StatusCode, Json<Response>),
+> {
+    let req = req.map_err(rejection_response)?;
+    let req = match identify(rate_limiter.as_deref(), &headers, addr) {
+        Some(client) => req.0.data(client),
+        None => req.0,
+    };
     let stream = schema
-        .execute_stream(req.0)
+        .execute_stream(req)
         .map(|r| Ok(Event::default().json_data(r).unwrap()));
-    Sse::new(stream)
-        .keep_alive(axum::response::sse::KeepAlive::new().text("keep-alive-text"))
+    Ok(Sse::new(stream)
+        .keep_alive(axum::response::sse::KeepAlive::new().text("keep-alive-text")))
+}
+
+/// Serves the subscriptions multiplexed over the WebSocket connection with
+/// the `graphql-transport-ws` or the legacy `graphql-ws` protocol.
+async fn graphql_ws_handler(
+    schema: Extension<CoreSchema>,
+    addr: Option<ConnectInfo<SocketAddr>>,
+    headers: HeaderMap,
+    upgrade: WebSocketUpgrade,
+    max_message_size: usize,
+    rate_limiter: Option<Arc<RateLimiter>>,
+) -> axum::response::Response {
+    let protocol = headers
+        .get(SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|protocols| {
+            protocols
+                .split(',')
+                .find_map(|protocol| protocol.trim().parse::<Protocols>().ok())
+        });
+    let protocol = match protocol {
+        Some(protocol) => protocol,
+        None => {
+            let message = "The supported GraphQL WebSocket protocol is not requested";
+            return (StatusCode::BAD_REQUEST, message).into_response()
+        }
+    };
+
+    let schema = schema.0;
+    let client = identify(rate_limiter.as_deref(), &headers, addr);
+    upgrade
+        .max_message_size(max_message_size)
+        .protocols(ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |socket| async move {
+            let (mut sink, stream) = futures::StreamExt::split(socket);
+            let input =
+                stream
+                    .take_while(Result::is_ok)
+                    .filter_map(|message| match message {
+                        Ok(Message::Text(text)) => Some(text.into_bytes()),
+                        Ok(Message::Binary(bytes)) => Some(bytes),
+                        _ => None,
+                    });
+
+            let mut data = async_graphql::Data::default();
+            if let Some(client) = client {
+                data.insert(client);
+            }
+            let mut output =
+                GraphQLWebSocket::new(schema, input, protocol).connection_data(data);
+            while let Some(message) = output.next().await {
+                let message = match message {
+                    WsMessage::Text(text) => Message::Text(text),
+                    WsMessage::Close(code, reason) => Message::Close(Some(CloseFrame {
+                        code,
+                        reason: reason.into(),
+                    })),
+                };
+                if sink.send(message).await.is_err() {
+                    break
+                }
+            }
+        })
+}
+
+/// Identifies the client of the request by the API key known to the `rate_limiter`
+/// or by the IP address. The client is unknown without the connection info.
+fn identify(
+    rate_limiter: Option<&RateLimiter>,
+    headers: &HeaderMap,
+    addr: Option<ConnectInfo<SocketAddr>>,
+) -> Option<Client> {
+    let ConnectInfo(addr) = addr?;
+    Some(match rate_limiter {
+        Some(rate_limiter) => rate_limiter.client(headers, addr.ip()),
+        None => Client::Ip(addr.ip()),
+    })
+}
+
+/// Converts the rejected request, for example, the one exceeding the body limit,
+/// into the GraphQL error.
+fn rejection_response(rejection: JsonRejection) -> (StatusCode, Json<Response>) {
+    let mut message = rejection.to_string();
+    let status = rejection.into_response().status();
+    if status == StatusCode::PAYLOAD_TOO_LARGE {
+        message = "The request body exceeds the size limit of the node".to_string();
+    }
+    let error = async_graphql::ServerError::new(message, None);
+    (status, Json(Response::from_errors(vec![error])))
 }
 
 async fn ok() -> anyhow::Result<(), ()> {
@@ -1,4 +1,7 @@
-use crate::graphql_api::ports::DatabasePort;
+use crate::graphql_api::ports::{
+    DatabaseBlocks,
+    DatabasePort,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -21,6 +24,7 @@ use fuel_core_types::blockchain::{
         BlockId,
     },
 };
+use std::iter;
 
 pub trait SimpleBlockData: Send + Sync {
     fn block(&self, id: &BlockId) -> StorageResult<CompressedBlock>;
@@ -54,6 +58,12 @@ pub trait BlockQueryData: Send + Sync + SimpleBlockData {
     ) -> BoxedIter<StorageResult<CompressedBlock>>;
 
     fn consensus(&self, id: &BlockId) -> StorageResult<Consensus>;
+
+    /// Returns the height of the last pruned block, if the pruning has happened.
+    fn pruned_height(&self) -> StorageResult<Option<BlockHeight>>;
+
+    /// Returns the height of the block with the `id`, if the block was pruned.
+    fn pruned_block_height(&self, id: &BlockId) -> StorageResult<Option<BlockHeight>>;
 }
 
 impl<D: DatabasePort + ?Sized> BlockQueryData for D {
@@ -78,7 +88,18 @@ impl<D: DatabasePort + ?Sized> BlockQueryData for D {
         start: Option<BlockHeight>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<CompressedBlock>> {
+        let pruned_height = match self.pruned_height() {
+            Ok(pruned_height) => pruned_height,
+            Err(err) => return iter::once(Err(err)).into_boxed(),
+        };
         self.blocks_ids(start.map(Into::into), direction)
+            // Pruned blocks are skipped, the height index still contains them.
+            .filter(move |result| {
+                !matches!(
+                    (result, pruned_height),
+                    (Ok((height, _)), Some(pruned)) if *height <= pruned
+                )
+            })
             .map(|result| {
                 result.and_then(|(_, id)| {
                     let block = self.block(&id)?;
@@ -95,4 +116,12 @@ impl<D: DatabasePort + ?Sized> BlockQueryData for D {
             .map(|c| c.map(|c| c.into_owned()))?
             .ok_or(not_found!(SealedBlockConsensus))
     }
+
+    fn pruned_height(&self) -> StorageResult<Option<BlockHeight>> {
+        DatabaseBlocks::pruned_height(self)
+    }
+
+    fn pruned_block_height(&self, id: &BlockId) -> StorageResult<Option<BlockHeight>> {
+        DatabaseBlocks::pruned_block_height(self, id)
+    }
 }
@@ -1,10 +1,19 @@
-use crate::schema::tx::types::{
-    SqueezedOutStatus,
-    TransactionStatus,
+use crate::{
+    query::BlockQueryData,
+    schema::tx::types::{
+        SqueezedOutStatus,
+        TransactionStatus,
+    },
 };
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_txpool::service::TxUpdate;
-use fuel_core_types::fuel_types::Bytes32;
+use fuel_core_types::{
+    blockchain::{
+        block::CompressedBlock,
+        primitives::BlockHeight,
+    },
+    fuel_types::Bytes32,
+};
 use futures::{
     stream::BoxStream,
     Stream,
@@ -132,3 +141,54 @@ where
                 status
             })
 }
+
+/// Streams the blocks starting from the `next_height`, or from the block after the latest
+/// one if it is not set. The blocks are read from the database each time the `events`
+/// notify about the new blocks, so the blocks committed before the subscription
+/// or missed by the slow subscriber are streamed too. The stream ends after the error.
+pub(crate) fn new_blocks<'a, State>(
+    state: &'a State,
+    events: BoxStream<'a, BlockHeight>,
+    next_height: Option<BlockHeight>,
+) -> impl Stream<Item = StorageResult<CompressedBlock>> + 'a
+where
+    State: BlockQueryData + ?Sized,
+{
+    futures::stream::unfold(Some((events, next_height)), move |context| async move {
+        let (mut events, next_height) = context?;
+        let result = next_block(state, &mut events, next_height).await?;
+        match result {
+            Ok(block) => {
+                let next_height = (**block.header().height() + 1).into();
+                Some((Ok(block), Some((events, Some(next_height)))))
+            }
+            Err(err) => Some((Err(err), None)),
+        }
+    })
+}
+
+/// Waits for the block at the `next_height`. Returns `None` if the `events` are closed.
+async fn next_block<State>(
+    state: &State,
+    events: &mut BoxStream<'_, BlockHeight>,
+    next_height: Option<BlockHeight>,
+) -> Option<StorageResult<CompressedBlock>>
+where
+    State: BlockQueryData + ?Sized,
+{
+    let mut latest_height = match state.latest_block_height() {
+        Ok(height) => height,
+        Err(err) => return Some(Err(err)),
+    };
+    let next_height = next_height.unwrap_or_else(|| (*latest_height + 1).into());
+
+    while next_height > latest_height {
+        events.next().await?;
+        latest_height = match state.latest_block_height() {
+            Ok(height) => height,
+            Err(err) => return Some(Err(err)),
+        };
+    }
+
+    Some(state.block_id(&next_height).and_then(|id| state.block(&id)))
+}
@@ -32,7 +32,7 @@ fn txn_id(i: u8) -> Bytes32 {
 }
 
 fn txn_updated(tx_id: Bytes32) -> TxUpdate {
-    TxUpdate::updated(tx_id)
+    TxUpdate::completed(tx_id)
 }
 
 fn txn_squeezed(tx_id: Bytes32) -> TxUpdate {
@@ -1,4 +1,10 @@
-use crate::graphql_api::ports::DatabasePort;
+use crate::{
+    database::receipts::ContractReceiptIndexCursor,
+    graphql_api::ports::{
+        DatabasePort,
+        DatabaseTransactions,
+    },
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -15,12 +21,17 @@ use fuel_core_storage::{
 };
 use fuel_core_txpool::types::TxId;
 use fuel_core_types::{
+    blockchain::primitives::BlockHeight,
     fuel_tx::{
         Receipt,
         Transaction,
         TxPointer,
     },
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        AssetId,
+        ContractId,
+    },
     services::txpool::TransactionStatus,
 };
 
@@ -49,12 +60,39 @@ impl<D: DatabasePort + ?Sized> SimpleTransactionData for D {
 pub trait TransactionQueryData: Send + Sync + SimpleTransactionData {
     fn status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus>;
 
+    /// Returns the height of the block that included the transaction,
+    /// if the block was pruned.
+    fn pruned_tx_height(&self, tx_id: &TxId) -> StorageResult<Option<BlockHeight>>;
+
     fn owned_transactions(
         &self,
         owner: Address,
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>>;
+
+    fn contract_transactions(
+        &self,
+        contract: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>>;
+
+    fn asset_transactions(
+        &self,
+        asset: AssetId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>>;
+
+    /// Returns the receipts emitted by the `contract` with the ids of the transactions
+    /// that contain them.
+    fn contract_receipts(
+        &self,
+        contract: ContractId,
+        start: Option<ContractReceiptIndexCursor>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(ContractReceiptIndexCursor, TxId, Receipt)>>;
 }
 
 impl<D: DatabasePort + ?Sized> TransactionQueryData for D {
@@ -62,20 +100,78 @@ impl<D: DatabasePort + ?Sized> TransactionQueryData for D {
         self.tx_status(tx_id)
     }
 
+    fn pruned_tx_height(&self, tx_id: &TxId) -> StorageResult<Option<BlockHeight>> {
+        DatabaseTransactions::pruned_tx_height(self, tx_id)
+    }
+
     fn owned_transactions(
         &self,
         owner: Address,
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>> {
-        self.owned_transactions_ids(owner, start, direction)
-            .map(|result| {
-                result.and_then(|(tx_pointer, tx_id)| {
-                    let tx = self.transaction(&tx_id)?;
+        indexed_transactions(self, self.owned_transactions_ids(owner, start, direction))
+    }
+
+    fn contract_transactions(
+        &self,
+        contract: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>> {
+        indexed_transactions(
+            self,
+            self.contract_transactions_ids(contract, start, direction),
+        )
+    }
 
-                    Ok((tx_pointer, tx))
-                })
+    fn asset_transactions(
+        &self,
+        asset: AssetId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>> {
+        indexed_transactions(self, self.asset_transactions_ids(asset, start, direction))
+    }
+
+    fn contract_receipts(
+        &self,
+        contract: ContractId,
+        start: Option<ContractReceiptIndexCursor>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(ContractReceiptIndexCursor, TxId, Receipt)>> {
+        let mut tx_receipts: Option<(TxId, Vec<Receipt>)> = None;
+        self.contract_receipts_ids(contract, start, direction)
+            .map(move |result| -> StorageResult<_> {
+                let (cursor, tx_id) = result?;
+                // The receipts of the same transaction follow each other in the index,
+                // so the receipts are loaded once per transaction.
+                if !matches!(&tx_receipts, Some((cached, _)) if *cached == tx_id) {
+                    tx_receipts = Some((tx_id, self.receipts(&tx_id)?));
+                }
+                let receipt = tx_receipts
+                    .as_ref()
+                    .and_then(|(_, receipts)| receipts.get(cursor.receipt_idx as usize))
+                    .cloned()
+                    .ok_or(not_found!(Receipts))?;
+
+                Ok((cursor, tx_id, receipt))
             })
             .into_boxed()
     }
 }
+
+/// Loads the transactions found by the index.
+fn indexed_transactions<'a, D: DatabasePort + ?Sized>(
+    database: &'a D,
+    ids: BoxedIter<'a, StorageResult<(TxPointer, TxId)>>,
+) -> BoxedIter<'a, StorageResult<(TxPointer, Transaction)>> {
+    ids.map(|result| {
+        result.and_then(|(tx_pointer, tx_id)| {
+            let tx = database.transaction(&tx_id)?;
+
+            Ok((tx_pointer, tx))
+        })
+    })
+    .into_boxed()
+}
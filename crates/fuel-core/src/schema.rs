@@ -1,3 +1,8 @@
+use crate::fuel_core_graphql_api::{
+    service::Database,
+    ApiLimits,
+    Config as GraphQLConfig,
+};
 use anyhow::anyhow;
 use async_graphql::{
     connection::{
@@ -7,6 +12,8 @@ use async_graphql::{
         Edge,
         EmptyFields,
     },
+    Context,
+    EmptySubscription,
     MergedObject,
     MergedSubscription,
     OutputType,
@@ -18,10 +25,13 @@ use fuel_core_storage::{
     Result as StorageResult,
 };
 use itertools::Itertools;
+use scalars::U64;
+use std::ops::Deref;
 
 pub mod balance;
 pub mod block;
 pub mod chain;
+pub mod checkpoint;
 pub mod coins;
 pub mod contract;
 pub mod dap;
@@ -30,10 +40,10 @@ pub mod message;
 pub mod node_info;
 pub mod scalars;
 pub mod tx;
+pub mod txpool;
 
 #[derive(MergedObject, Default)]
 pub struct Query(
-    dap::DapQuery,
     balance::BalanceQuery,
     block::BlockQuery,
     chain::ChainQuery,
@@ -44,13 +54,18 @@ pub struct Query(
     contract::ContractBalanceQuery,
     node_info::NodeQuery,
     message::MessageQuery,
+    txpool::TxPoolQuery,
 );
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(tx::TxMutation);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(
+    tx::TxStatusSubscription,
+    tx::TxPoolSubscription,
+    block::BlockSubscription,
+);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -64,7 +79,84 @@ pub fn build_schema() -> CoreSchemaBuilder {
     )
 }
 
+// The privileged operations are served only by the admin API. The root types keep
+// the usual names, so the admin schema looks like the part of the public one.
+#[derive(MergedObject, Default)]
+#[graphql(name = "Query")]
+pub struct AdminQuery(dap::DapQuery, node_info::NodeAdminQuery);
+
+#[derive(MergedObject, Default)]
+#[graphql(name = "Mutation")]
+pub struct AdminMutation(
+    dap::DapMutation,
+    block::BlockMutation,
+    checkpoint::CheckpointMutation,
+    txpool::TxPoolMutation,
+);
+
+pub type AdminSchema = Schema<AdminQuery, AdminMutation, EmptySubscription>;
+pub type AdminSchemaBuilder = SchemaBuilder<AdminQuery, AdminMutation, EmptySubscription>;
+
+pub fn build_admin_schema() -> AdminSchemaBuilder {
+    Schema::build(
+        AdminQuery::default(),
+        AdminMutation::default(),
+        EmptySubscription,
+    )
+}
+
+/// The state used by the query: the latest one or the state at the past block height.
+enum StateView<'a> {
+    Latest(&'a Database),
+    Historical(Database),
+}
+
+impl<'a> Deref for StateView<'a> {
+    type Target = Database;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            StateView::Latest(database) => database,
+            StateView::Historical(database) => database,
+        }
+    }
+}
+
+/// Returns the state at the end of the block at `block_height`,
+/// or the latest state if the height is not specified.
+fn state_view<'a>(
+    ctx: &Context<'a>,
+    block_height: Option<U64>,
+) -> async_graphql::Result<StateView<'a>> {
+    let database: &Database = ctx.data_unchecked();
+    match block_height {
+        Some(block_height) => {
+            let block_height: u64 = block_height.into();
+            let block_height: u32 = block_height.try_into()?;
+            Ok(StateView::Historical(
+                database.state_at_height(&block_height.into())?,
+            ))
+        }
+        None => Ok(StateView::Latest(database)),
+    }
+}
+
+/// Returns the complexity of the connection field, the complexity of one element
+/// multiplied by the size of the requested page.
+pub(crate) fn page_complexity(
+    first: Option<i32>,
+    last: Option<i32>,
+    child_complexity: usize,
+) -> usize {
+    let page_size = first
+        .or(last)
+        .map(|page_size| page_size.unsigned_abs() as usize)
+        .unwrap_or(ApiLimits::DEFAULT_MAX_PAGE_SIZE);
+    page_size.saturating_mul(child_complexity)
+}
+
 async fn query_pagination<F, Entries, SchemaKey, SchemaValue>(
+    ctx: &Context<'_>,
     after: Option<String>,
     before: Option<String>,
     first: Option<i32>,
@@ -107,6 +199,16 @@ where
         (_, _, _, _) => { /* Other combinations are allowed */ }
     };
 
+    let max_page_size = ctx.data_unchecked::<GraphQLConfig>().limits.max_page_size;
+    if let Some(page_size) = first.or(last) {
+        if page_size.unsigned_abs() as usize > max_page_size {
+            return Err(anyhow!(
+                "The page size `{page_size}` exceeds the maximum page size `{max_page_size}`"
+            )
+            .into())
+        }
+    }
+
     query(
         after,
         before,
@@ -1,5 +1,4 @@
 use crate::{
-    fuel_core_graphql_api::service::Database,
     query::BalanceQueryData,
     schema::scalars::{
         Address,
@@ -52,8 +51,10 @@ impl BalanceQuery {
         ctx: &Context<'_>,
         #[graphql(desc = "address of the owner")] owner: Address,
         #[graphql(desc = "asset_id of the coin")] asset_id: AssetId,
+        #[graphql(desc = "the height of the block at which the state is queried")]
+        block_height: Option<U64>,
     ) -> async_graphql::Result<Balance> {
-        let data: &Database = ctx.data_unchecked();
+        let data = crate::schema::state_view(ctx, block_height)?;
         let balance = data.balance(owner.0, asset_id.0)?.into();
         Ok(balance)
     }
@@ -62,6 +63,10 @@ impl BalanceQuery {
     //  It should be replaced with `UtxoId`.
     //  This API should be migrated to the indexer for better support and
     //  discontinued within fuel-core.
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn balances(
         &self,
         ctx: &Context<'_>,
@@ -70,18 +75,27 @@ impl BalanceQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        #[graphql(desc = "the height of the block at which the state is queried")]
+        block_height: Option<U64>,
     ) -> async_graphql::Result<Connection<AssetId, Balance, EmptyFields, EmptyFields>>
     {
         if before.is_some() || after.is_some() {
             return Err(anyhow!("pagination is not yet supported").into())
         }
-        let query: &Database = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |_, direction| {
-            let owner = filter.owner.into();
-            Ok(query.balances(owner, direction).map(|result| {
-                result.map(|balance| (balance.asset_id.into(), balance.into()))
-            }))
-        })
+        let query = crate::schema::state_view(ctx, block_height)?;
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |_, direction| {
+                let owner = filter.owner.into();
+                Ok(query.balances(owner, direction).map(|result| {
+                    result.map(|balance| (balance.asset_id.into(), balance.into()))
+                }))
+            },
+        )
         .await
     }
 }
@@ -5,6 +5,7 @@ use super::scalars::{
 use crate::{
     fuel_core_graphql_api::{
         service::{
+            BlockImporter,
             ConsensusModule,
             Database,
         },
@@ -12,6 +13,7 @@ use crate::{
     },
     graphql_api::IntoApiResult,
     query::{
+        new_blocks,
         BlockQueryData,
         SimpleBlockData,
         SimpleTransactionData,
@@ -34,6 +36,7 @@ use async_graphql::{
     Context,
     Object,
     SimpleObject,
+    Subscription,
     Union,
 };
 use fuel_core_storage::{
@@ -42,15 +45,25 @@ use fuel_core_storage::{
         IntoBoxedIter,
         IterDirection,
     },
+    IsNotFound,
     Result as StorageResult,
 };
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
         header::BlockHeader,
+        primitives::BlockHeight,
     },
     fuel_types,
 };
+use futures::{
+    Stream,
+    TryStreamExt,
+};
+
+/// The expected number of the transactions in the block. The list of the transactions
+/// isn't paginated, so the complexity of its element is multiplied by this number.
+const BLOCK_TRANSACTIONS_COMPLEXITY: usize = 16;
 
 pub struct Block(pub(crate) CompressedBlock);
 
@@ -101,6 +114,7 @@ impl Block {
         Ok(consensus.into())
     }
 
+    #[graphql(complexity = "BLOCK_TRANSACTIONS_COMPLEXITY * child_complexity")]
     async fn transactions(
         &self,
         ctx: &Context<'_>,
@@ -191,26 +205,40 @@ impl BlockQuery {
         #[graphql(desc = "Height of the block")] height: Option<U64>,
     ) -> async_graphql::Result<Option<Block>> {
         let data: &Database = ctx.data_unchecked();
-        let id = match (id, height) {
+        let (id, mut height) = match (id, height) {
             (Some(_), Some(_)) => {
                 return Err(async_graphql::Error::new(
                     "Can't provide both an id and a height",
                 ))
             }
-            (Some(id), None) => Ok(id.0.into()),
+            (Some(id), None) => (Ok(id.0.into()), None),
             (None, Some(height)) => {
                 let height: u64 = height.into();
                 let height: u32 = height.try_into()?;
-                data.block_id(&height.into())
+                let height = height.into();
+                (data.block_id(&height), Some(height))
             }
             (None, None) => {
                 return Err(async_graphql::Error::new("Missing either id or height"))
             }
         };
 
-        id.and_then(|id| data.block(&id)).into_api_result()
+        let block = id.and_then(|id| {
+            let block = data.block(&id);
+            if block.is_not_found() && height.is_none() {
+                height = data.pruned_block_height(&id)?;
+            }
+            block
+        });
+        if let (true, Some(height)) = (block.is_not_found(), height) {
+            ensure_block_not_pruned(data, height)?;
+        }
+        block.into_api_result()
     }
 
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn blocks(
         &self,
         ctx: &Context<'_>,
@@ -220,9 +248,14 @@ impl BlockQuery {
         before: Option<String>,
     ) -> async_graphql::Result<Connection<usize, Block, EmptyFields, EmptyFields>> {
         let db: &Database = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            Ok(blocks_query(db, *start, direction))
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| Ok(blocks_query(db, *start, direction)),
+        )
         .await
     }
 }
@@ -244,6 +277,9 @@ impl HeaderQuery {
             .map(|b| b.0.header().clone().into()))
     }
 
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn headers(
         &self,
         ctx: &Context<'_>,
@@ -253,13 +289,73 @@ impl HeaderQuery {
         before: Option<String>,
     ) -> async_graphql::Result<Connection<usize, Header, EmptyFields, EmptyFields>> {
         let db: &Database = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            Ok(blocks_query(db, *start, direction))
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| Ok(blocks_query(db, *start, direction)),
+        )
         .await
     }
 }
 
+#[derive(Default)]
+pub struct BlockSubscription;
+
+#[Subscription]
+impl BlockSubscription {
+    /// Returns a stream of the new blocks committed by the node.
+    ///
+    /// If `fromHeight` is set, the stream starts from the block at this height,
+    /// so the subscriber can resume from the last block it received.
+    /// Otherwise, the stream starts from the next committed block.
+    ///
+    /// The blocks are streamed in order without gaps, even if the subscriber
+    /// is polled slower than the blocks are produced.
+    async fn new_blocks<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "Height of the first block in the stream")] from_height: Option<
+            U64,
+        >,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<Block>> + 'a>
+    {
+        let db: &Database = ctx.data_unchecked();
+        let importer: &BlockImporter = ctx.data_unchecked();
+        let from_height = match from_height {
+            Some(height) => {
+                let height: u64 = height.into();
+                let height: u32 = height.try_into()?;
+                let height: BlockHeight = height.into();
+                ensure_block_not_pruned(db, height)?;
+                Some(height)
+            }
+            None => None,
+        };
+
+        Ok(new_blocks(&**db, importer.block_events(), from_height)
+            .map_ok(Block::from)
+            .map_err(async_graphql::Error::from))
+    }
+}
+
+/// Returns an error instead of `None` if the block at `height` was removed by the pruning.
+/// The ids of the pruned blocks and transactions are resolved to their heights by
+/// the tombstones left by the pruning, the unknown ids still return `None`.
+pub(crate) fn ensure_block_not_pruned(
+    query: &Database,
+    height: BlockHeight,
+) -> async_graphql::Result<()> {
+    match query.pruned_height()? {
+        Some(pruned) if height <= pruned => {
+            Err(anyhow!("The block at height {height} has been pruned").into())
+        }
+        _ => Ok(()),
+    }
+}
+
 fn blocks_query<T>(
     query: &Database,
     start: Option<usize>,
@@ -0,0 +1,59 @@
+use crate::fuel_core_graphql_api::{
+    service::Database,
+    Config as GraphQLConfig,
+};
+use anyhow::anyhow;
+use async_graphql::{
+    Context,
+    Object,
+};
+use std::{
+    sync::atomic::{
+        AtomicU64,
+        Ordering,
+    },
+    time::{
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+/// Distinguishes the checkpoints created within the same millisecond.
+static CHECKPOINT_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Default)]
+pub struct CheckpointMutation;
+
+#[Object]
+impl CheckpointMutation {
+    /// Creates a consistent copy of the database in the checkpoints directory of the node
+    /// without stopping it. Returns the path to the created checkpoint.
+    /// The checkpoint can be used to start a new node with `fuel-core db restore`.
+    async fn create_checkpoint(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<String> {
+        let database: &Database = ctx.data_unchecked();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        let checkpoints_dir = config.checkpoints_dir.as_ref().ok_or_else(|| {
+            anyhow!("The checkpoints directory must be set to use this endpoint")
+        })?;
+        std::fs::create_dir_all(checkpoints_dir)?;
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+        let counter = CHECKPOINT_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = checkpoints_dir.join(format!("checkpoint-{timestamp}-{counter}"));
+        if path.exists() {
+            return Err(anyhow!(
+                "The checkpoint directory {} already exists",
+                path.display()
+            )
+            .into())
+        }
+
+        database.create_checkpoint(&path)?;
+        tracing::info!("Created the database checkpoint at {}", path.display());
+
+        Ok(path.display().to_string())
+    }
+}
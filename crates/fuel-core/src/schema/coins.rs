@@ -155,6 +155,10 @@ impl CoinQuery {
     }
 
     /// Gets all unspent coins of some `owner` maybe filtered with by `asset_id` per page.
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn coins(
         &self,
         ctx: &Context<'_>,
@@ -163,31 +167,41 @@ impl CoinQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        #[graphql(desc = "the height of the block at which the state is queried")]
+        block_height: Option<U64>,
     ) -> async_graphql::Result<Connection<UtxoId, Coin, EmptyFields, EmptyFields>> {
         // Rocksdb doesn't support reverse iteration over a prefix
         if matches!(last, Some(last) if last > 0) {
             return Err(anyhow!("reverse pagination isn't supported for this coins").into())
         }
 
-        let query: &Database = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            let owner: fuel_tx::Address = filter.owner.into();
-            let coins = query
-                .owned_coins(&owner, (*start).map(Into::into), direction)
-                .filter_map(|result| {
-                    if let (Ok(coin), Some(filter_asset_id)) = (&result, &filter.asset_id)
-                    {
-                        if coin.asset_id != filter_asset_id.0 {
-                            return None
+        let query = crate::schema::state_view(ctx, block_height)?;
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| {
+                let owner: fuel_tx::Address = filter.owner.into();
+                let coins = query
+                    .owned_coins(&owner, (*start).map(Into::into), direction)
+                    .filter_map(|result| {
+                        if let (Ok(coin), Some(filter_asset_id)) =
+                            (&result, &filter.asset_id)
+                        {
+                            if coin.asset_id != filter_asset_id.0 {
+                                return None
+                            }
                         }
-                    }
 
-                    Some(result)
-                })
-                .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
+                        Some(result)
+                    })
+                    .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
 
-            Ok(coins)
-        })
+                Ok(coins)
+            },
+        )
         .await
     }
 
@@ -106,10 +106,12 @@ impl ContractBalanceQuery {
         ctx: &Context<'_>,
         contract: ContractId,
         asset: AssetId,
+        #[graphql(desc = "the height of the block at which the state is queried")]
+        block_height: Option<U64>,
     ) -> async_graphql::Result<ContractBalance> {
         let contract_id = contract.into();
         let asset_id = asset.into();
-        let context: &Database = ctx.data_unchecked();
+        let context = crate::schema::state_view(ctx, block_height)?;
         context
             .contract_balance(contract_id, asset_id)
             .into_api_result()
@@ -125,6 +127,10 @@ impl ContractBalanceQuery {
             })
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn contract_balances(
         &self,
         ctx: &Context<'_>,
@@ -133,10 +139,12 @@ impl ContractBalanceQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        #[graphql(desc = "the height of the block at which the state is queried")]
+        block_height: Option<U64>,
     ) -> async_graphql::Result<
         Connection<AssetId, ContractBalance, EmptyFields, EmptyFields>,
     > {
-        let query: &Database = ctx.data_unchecked();
+        let query = crate::schema::state_view(ctx, block_height)?;
 
         // Rocksdb doesn't support reverse iteration over a prefix
         if matches!(last, Some(last) if last > 0) {
@@ -145,22 +153,29 @@ impl ContractBalanceQuery {
             )
         }
 
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            let balances = query
-                .contract_balances(
-                    filter.contract.into(),
-                    (*start).map(Into::into),
-                    direction,
-                )
-                .map(move |balance| {
-                    let balance = balance?;
-                    let asset_id = balance.asset_id;
-
-                    Ok((asset_id.into(), balance.into()))
-                });
-
-            Ok(balances)
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| {
+                let balances = query
+                    .contract_balances(
+                        filter.contract.into(),
+                        (*start).map(Into::into),
+                        direction,
+                    )
+                    .map(move |balance| {
+                        let balance = balance?;
+                        let asset_id = balance.asset_id;
+
+                        Ok((asset_id.into(), balance.into()))
+                    });
+
+                Ok(balances)
+            },
+        )
         .await
     }
 }
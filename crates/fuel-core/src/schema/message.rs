@@ -61,6 +61,9 @@ pub struct MessageQuery {}
 
 #[Object]
 impl MessageQuery {
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn messages(
         &self,
         ctx: &Context<'_>,
@@ -73,6 +76,7 @@ impl MessageQuery {
     {
         let query: &Database = ctx.data_unchecked();
         crate::schema::query_pagination(
+            ctx,
             after,
             before,
             first,
@@ -1,5 +1,18 @@
 use super::scalars::U64;
-use crate::fuel_core_graphql_api::Config as GraphQLConfig;
+use crate::{
+    database::Column,
+    fuel_core_graphql_api::{
+        service::Database,
+        Config as GraphQLConfig,
+    },
+    state::{
+        rocks_db_config::{
+            ColumnOptions,
+            RocksDbConfig,
+        },
+        ColumnStats,
+    },
+};
 use async_graphql::{
     Context,
     Object,
@@ -12,6 +25,89 @@ pub struct NodeInfo {
     max_tx: U64,
     max_depth: U64,
     node_version: String,
+    database_options: Option<RocksDbConfig>,
+}
+
+pub struct DatabaseOptions(RocksDbConfig);
+
+#[Object]
+impl DatabaseOptions {
+    /// The maximum number of the files opened by the database, `-1` means no limit.
+    /// `null` means the default of RocksDB.
+    async fn max_open_files(&self) -> Option<i32> {
+        self.0.max_open_files
+    }
+
+    /// The options used by each column of the database.
+    async fn columns(&self) -> Vec<DatabaseColumnOptions> {
+        enum_iterator::all::<Column>()
+            .map(|column| DatabaseColumnOptions {
+                column,
+                options: self.0.column_options(column),
+            })
+            .collect()
+    }
+}
+
+pub struct DatabaseColumnOptions {
+    column: Column,
+    options: ColumnOptions,
+}
+
+#[Object]
+impl DatabaseColumnOptions {
+    async fn column(&self) -> String {
+        self.column.to_string()
+    }
+
+    /// The size of the block cache in bytes, `null` means the default of RocksDB.
+    async fn cache_size(&self) -> Option<U64> {
+        self.options.cache_size.map(|size| U64(size as u64))
+    }
+
+    async fn compression(&self) -> String {
+        self.options
+            .compression
+            .unwrap_or(ColumnOptions::DEFAULT_COMPRESSION)
+            .to_string()
+    }
+
+    /// The bits per key of the bloom filter, `null` means the bloom filter is disabled.
+    async fn bloom_filter_bits_per_key(&self) -> Option<f64> {
+        self.options.bloom_filter_bits_per_key
+    }
+
+    /// The size of the memtable in bytes, `null` means the default of RocksDB.
+    async fn write_buffer_size(&self) -> Option<U64> {
+        self.options.write_buffer_size.map(|size| U64(size as u64))
+    }
+}
+
+pub struct DatabaseColumnStats {
+    column: Column,
+    stats: ColumnStats,
+}
+
+#[Object]
+impl DatabaseColumnStats {
+    async fn column(&self) -> String {
+        self.column.to_string()
+    }
+
+    /// The number of keys in the column, estimated by RocksDB.
+    async fn keys(&self) -> U64 {
+        self.stats.keys.into()
+    }
+
+    /// The approximate size of the keys and values of the column in bytes.
+    async fn bytes(&self) -> U64 {
+        self.stats.bytes.into()
+    }
+
+    /// The internal statistics of RocksDB, `null` for the in-memory database.
+    async fn internal_stats(&self) -> Option<String> {
+        self.stats.internal.clone()
+    }
 }
 
 #[Object]
@@ -39,6 +135,11 @@ impl NodeInfo {
     async fn node_version(&self) -> String {
         self.node_version.to_owned()
     }
+
+    /// The tuning of the database, `null` for the in-memory database.
+    async fn database_options(&self) -> Option<DatabaseOptions> {
+        self.database_options.clone().map(DatabaseOptions)
+    }
 }
 
 #[derive(Default)]
@@ -58,6 +159,28 @@ impl NodeQuery {
             max_tx: (config.max_tx as u64).into(),
             max_depth: (config.max_depth as u64).into(),
             node_version: VERSION.to_owned(),
+            database_options: config.database_options.clone(),
         })
     }
 }
+
+/// The information about the node available only to its operator.
+#[derive(Default)]
+pub struct NodeAdminQuery {}
+
+#[Object]
+impl NodeAdminQuery {
+    /// The number of keys and the size of each column of the database.
+    async fn database_stats(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<DatabaseColumnStats>> {
+        let database: &Database = ctx.data_unchecked();
+
+        Ok(database
+            .column_stats()?
+            .into_iter()
+            .map(|(column, stats)| DatabaseColumnStats { column, stats })
+            .collect())
+    }
+}
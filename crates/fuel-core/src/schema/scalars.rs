@@ -1,3 +1,4 @@
+use crate::database::receipts::ContractReceiptIndexCursor;
 use async_graphql::{
     connection::CursorType,
     InputValueError,
@@ -114,6 +115,41 @@ impl CursorType for SortedTxCursor {
     }
 }
 
+/// The position of the receipt in the chain.
+#[derive(Clone, Copy, Debug, derive_more::Into, derive_more::From, PartialEq, Eq)]
+pub struct ReceiptCursor(pub(crate) ContractReceiptIndexCursor);
+
+impl CursorType for ReceiptCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let mut parts = s.split('#');
+        let mut next = || parts.next().ok_or("Incorrect format provided");
+        let block_height = u32::from_str(next()?)
+            .map_err(|_| "Failed to decode block_height")?
+            .into();
+        let tx_idx = u16::from_str(next()?).map_err(|_| "Failed to decode tx_idx")?;
+        let receipt_idx =
+            u32::from_str(next()?).map_err(|_| "Failed to decode receipt_idx")?;
+        if parts.next().is_some() {
+            return Err("Incorrect format provided".to_string())
+        }
+
+        Ok(Self(ContractReceiptIndexCursor {
+            block_height,
+            tx_idx,
+            receipt_idx,
+        }))
+    }
+
+    fn encode_cursor(&self) -> String {
+        format!(
+            "{}#{}#{}",
+            self.0.block_height, self.0.tx_idx, self.0.receipt_idx
+        )
+    }
+}
+
 #[derive(Clone, Debug, derive_more::Into, derive_more::From, PartialEq, Eq)]
 pub struct HexString(pub(crate) Vec<u8>);
 
@@ -1,10 +1,13 @@
 use crate::{
+    database::receipts::ContractReceiptIndexCursor,
     fuel_core_graphql_api::{
+        rate_limit::Client,
         service::{
             BlockProducer,
             Database,
             TxPool,
         },
+        Config as GraphQLConfig,
         IntoApiResult,
     },
     query::{
@@ -14,12 +17,18 @@ use crate::{
         TransactionQueryData,
         TxnStatusChangeState,
     },
-    schema::scalars::{
-        Address,
-        HexString,
-        SortedTxCursor,
-        TransactionId,
-        TxPointer,
+    schema::{
+        block::ensure_block_not_pruned,
+        scalars::{
+            Address,
+            AssetId,
+            ContractId,
+            HexString,
+            ReceiptCursor,
+            SortedTxCursor,
+            TransactionId,
+            TxPointer,
+        },
     },
 };
 use anyhow::anyhow;
@@ -34,34 +43,88 @@ use async_graphql::{
 };
 use fuel_core_storage::{
     iter::IterDirection,
+    IsNotFound,
     Result as StorageResult,
 };
 use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+        Word,
+    },
     fuel_tx::{
+        field::{
+            GasLimit,
+            GasPrice,
+            Inputs,
+        },
         Cacheable,
+        Chargeable,
+        ConsensusParameters,
+        Input,
+        Receipt,
         Transaction as FuelTx,
+        TransactionFee,
+        UniqueIdentifier,
     },
     fuel_types,
     fuel_types::bytes::Deserializable,
+    fuel_vm::{
+        checked_transaction::IntoChecked,
+        interpreter::{
+            CheckedMetadata,
+            ExecutableTransaction,
+        },
+        GasCosts,
+        Interpreter,
+        PredicateStorage,
+    },
+    services::txpool::{
+        ClientId,
+        TxSource,
+        TxStatus,
+    },
 };
 use futures::{
     Stream,
+    StreamExt,
     TryStreamExt,
 };
 use itertools::Itertools;
 use std::{
     iter,
-    sync::Arc,
+    sync::{
+        Arc,
+        Mutex,
+    },
 };
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 use types::Transaction;
 
-use self::types::TransactionStatus;
+use self::{
+    receipt::{
+        IndexedReceipt,
+        ReceiptFilterInput,
+        ReceiptsScan,
+    },
+    types::{
+        DryRunTransactionResult,
+        GasEstimate,
+        PredicateGasUsed,
+        StateOverridesInput,
+        TransactionStatus,
+        TxPoolEvent,
+    },
+};
 
 pub mod input;
 pub mod output;
 pub mod receipt;
 pub mod types;
 
+/// The maximum number of the contract's receipts checked against the filter per request.
+const MAX_SCANNED_RECEIPTS: usize = 10_000;
+
 #[derive(Default)]
 pub struct TxQuery;
 
@@ -79,10 +142,19 @@ impl TxQuery {
         if let Some(transaction) = txpool.transaction(id) {
             Ok(Some(Transaction(transaction)))
         } else {
-            query.transaction(&id).into_api_result()
+            let transaction = query.transaction(&id);
+            if transaction.is_not_found() {
+                if let Some(height) = query.pruned_tx_height(&id)? {
+                    ensure_block_not_pruned(query, height)?;
+                }
+            }
+            transaction.into_api_result()
         }
     }
 
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn transactions(
         &self,
         ctx: &Context<'_>,
@@ -96,6 +168,7 @@ impl TxQuery {
         let db_query: &Database = ctx.data_unchecked();
         let tx_query: &Database = ctx.data_unchecked();
         crate::schema::query_pagination(
+            ctx,
             after,
             before,
             first,
@@ -145,6 +218,9 @@ impl TxQuery {
         .await
     }
 
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn transactions_by_owner(
         &self,
         ctx: &Context<'_>,
@@ -166,6 +242,7 @@ impl TxQuery {
         let owner = fuel_types::Address::from(owner);
 
         crate::schema::query_pagination(
+            ctx,
             after,
             before,
             first,
@@ -180,6 +257,378 @@ impl TxQuery {
         )
         .await
     }
+
+    /// Returns the transactions that use the contract as an input or create it.
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
+    async fn transactions_by_contract(
+        &self,
+        ctx: &Context<'_>,
+        contract: ContractId,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
+    {
+        // Rocksdb doesn't support reverse iteration over a prefix
+        if matches!(last, Some(last) if last > 0) {
+            return Err(
+                anyhow!("reverse pagination isn't supported for this resource").into(),
+            )
+        }
+
+        let query: &Database = ctx.data_unchecked();
+        let contract = fuel_types::ContractId::from(contract);
+
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TxPointer>, direction| {
+                let start = (*start).map(Into::into);
+                let txs = query
+                    .contract_transactions(contract, start, direction)
+                    .map(|result| result.map(|(cursor, tx)| (cursor.into(), tx.into())));
+                Ok(txs)
+            },
+        )
+        .await
+    }
+
+    /// Returns the transactions that spend or create coins of the asset.
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
+    async fn transactions_by_asset(
+        &self,
+        ctx: &Context<'_>,
+        asset: AssetId,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
+    {
+        // Rocksdb doesn't support reverse iteration over a prefix
+        if matches!(last, Some(last) if last > 0) {
+            return Err(
+                anyhow!("reverse pagination isn't supported for this resource").into(),
+            )
+        }
+
+        let query: &Database = ctx.data_unchecked();
+        let asset = fuel_types::AssetId::from(asset);
+
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TxPointer>, direction| {
+                let start = (*start).map(Into::into);
+                let txs = query
+                    .asset_transactions(asset, start, direction)
+                    .map(|result| result.map(|(cursor, tx)| (cursor.into(), tx.into())));
+                Ok(txs)
+            },
+        )
+        .await
+    }
+
+    /// Returns the receipts emitted by the contract, ordered by their position in the chain.
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
+    async fn receipts(
+        &self,
+        ctx: &Context<'_>,
+        filter: ReceiptFilterInput,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<ReceiptCursor, IndexedReceipt, ReceiptsScan, EmptyFields>,
+    > {
+        // Rocksdb doesn't support reverse iteration over a prefix
+        if matches!(last, Some(last) if last > 0) {
+            return Err(
+                anyhow!("reverse pagination isn't supported for this resource").into(),
+            )
+        }
+
+        let query: &Database = ctx.data_unchecked();
+        let contract = fuel_types::ContractId::from(filter.contract);
+        let to_height = filter.to_height.map(|height| height.0);
+        let from_height = filter
+            .from_height
+            .map(|height| u32::try_from(height.0))
+            .transpose()?;
+
+        // The number of the checked receipts, the cursor of the last one, and whether the
+        // scan stopped at the limit.
+        let scanned = Mutex::new((0usize, None, false));
+        let connection = crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<ReceiptCursor>, direction| {
+                let start = match (start, from_height) {
+                    (Some(start), _) => Some(start.0),
+                    (None, Some(from_height)) => Some(ContractReceiptIndexCursor {
+                        block_height: from_height.into(),
+                        tx_idx: 0,
+                        receipt_idx: 0,
+                    }),
+                    (None, None) => None,
+                };
+                let receipts = query
+                    .contract_receipts(contract, start, direction)
+                    .take_while(move |result| {
+                        !matches!(
+                            (result, to_height),
+                            (Ok((cursor, _, _)), Some(to_height))
+                                if u64::from(*cursor.block_height) > to_height
+                        )
+                    })
+                    .take_while(|result| {
+                        let mut scanned = scanned.lock().expect("poisoned lock");
+                        if scanned.0 == MAX_SCANNED_RECEIPTS {
+                            scanned.2 = true;
+                            return false
+                        }
+                        scanned.0 += 1;
+                        if let Ok((cursor, _, _)) = result {
+                            scanned.1 = Some(*cursor);
+                        }
+                        true
+                    })
+                    .filter(move |result| match result {
+                        Ok((_, _, receipt)) => filter.matches(receipt),
+                        Err(_) => true,
+                    })
+                    .map(|result| {
+                        result.map(|(cursor, tx_id, receipt)| {
+                            let receipt = IndexedReceipt {
+                                tx_id,
+                                block_height: cursor.block_height,
+                                receipt,
+                            };
+                            (cursor.into(), receipt)
+                        })
+                    });
+                Ok(receipts)
+            },
+        )
+        .await?;
+
+        // The scan stopped at the limit before filling the page, so the rest of the
+        // contract's receipts is left for the next page.
+        let (_, last_scanned, limit_reached) =
+            scanned.into_inner().expect("poisoned lock");
+        let scan_cursor = last_scanned
+            .filter(|_| limit_reached && !connection.has_next_page)
+            .map(|cursor| ReceiptCursor(cursor).encode_cursor());
+        let mut result = Connection::with_additional_fields(
+            connection.has_previous_page,
+            connection.has_next_page || scan_cursor.is_some(),
+            ReceiptsScan { scan_cursor },
+        );
+        result.edges = connection.edges;
+        Ok(result)
+    }
+
+    /// Estimates the gas and the fees of the transaction by its dry run with the maximum
+    /// gas limit. The inputs are not validated, so the transaction can be estimated
+    /// before it is signed. The fees are calculated at the gas price of the transaction.
+    async fn estimate_gas(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<GasEstimate> {
+        let query: &Database = ctx.data_unchecked();
+        let block_height = *query.latest_block_height()? as Word + 1;
+
+        match FuelTx::from_bytes(&tx.0)? {
+            FuelTx::Script(script) => estimate_gas(ctx, script, block_height).await,
+            FuelTx::Create(create) => estimate_gas(ctx, create, block_height).await,
+            FuelTx::Mint(_) => {
+                Err(anyhow!("The `Mint` transaction can't be estimated").into())
+            }
+        }
+    }
+}
+
+/// Runs the predicates and the dry run of the transaction with the maximum gas limit
+/// and the zero gas price, so the estimation doesn't depend on the balance of the inputs.
+async fn estimate_gas<Tx>(
+    ctx: &Context<'_>,
+    mut tx: Tx,
+    block_height: Word,
+) -> async_graphql::Result<GasEstimate>
+where
+    Tx: ExecutableTransaction + GasLimit + GasPrice + Cacheable + Send + 'static,
+    <Tx as IntoChecked>::Metadata: CheckedMetadata + Send + 'static,
+{
+    let block_producer = ctx.data_unchecked::<BlockProducer>();
+    let config = ctx.data_unchecked::<GraphQLConfig>();
+    let params = config.transaction_parameters;
+    let gas_costs = config.gas_costs.clone();
+
+    let gas_price = tx.price();
+    let metered_bytes = tx.metered_bytes_size() as Word;
+    *tx.gas_limit_mut() = params.max_gas_per_tx;
+    *tx.gas_price_mut() = 0;
+    tx.precompute();
+
+    let predicates_tx = tx.clone();
+    let (predicate_gas_used, predicates_gas_used) =
+        tokio::task::spawn_blocking(move || {
+            let gas_used = check_predicates(
+                predicates_tx.clone(),
+                block_height,
+                params,
+                &gas_costs,
+            )?;
+            let gas_used_by_input =
+                gas_used_by_predicates(predicates_tx, block_height, params, &gas_costs)?;
+            anyhow::Ok((gas_used, gas_used_by_input))
+        })
+        .await??;
+
+    let receipts = block_producer
+        .dry_run_tx(tx.into(), None, Some(false), Default::default())
+        .await?;
+    let gas_used = receipts
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+            _ => None,
+        })
+        .unwrap_or_default();
+
+    let gas_limit = gas_used.max(predicate_gas_used);
+    let fee =
+        TransactionFee::checked_from_values(&params, metered_bytes, gas_limit, gas_price)
+            .ok_or_else(|| anyhow!("The fee of the transaction overflows"))?;
+
+    Ok(GasEstimate {
+        gas_used,
+        predicate_gas_used,
+        predicates_gas_used,
+        fee,
+        receipts,
+    })
+}
+
+/// Runs the predicates of the transaction and returns the gas used by all of them.
+fn check_predicates<Tx>(
+    mut tx: Tx,
+    block_height: Word,
+    params: ConsensusParameters,
+    gas_costs: &GasCosts,
+) -> anyhow::Result<Word>
+where
+    Tx: ExecutableTransaction + Cacheable,
+    <Tx as IntoChecked>::Metadata: CheckedMetadata,
+{
+    tx.precompute();
+    let checked = tx.into_checked_basic(block_height, &params)?;
+    let checked = Interpreter::<PredicateStorage>::check_predicates(
+        checked,
+        params,
+        gas_costs.clone(),
+    )?;
+    Ok(checked.gas_used())
+}
+
+/// Replaces the predicate of the `input` and its owner, if the `input` has the predicate.
+fn replace_predicate(input: &mut Input, predicate: &[u8]) {
+    let owner = Input::predicate_owner(predicate);
+    match input {
+        Input::CoinPredicate(coin) => {
+            coin.owner = owner;
+            coin.predicate = predicate.to_vec();
+        }
+        Input::MessageCoinPredicate(message) => {
+            message.recipient = owner;
+            message.predicate = predicate.to_vec();
+        }
+        Input::MessageDataPredicate(message) => {
+            message.recipient = owner;
+            message.predicate = predicate.to_vec();
+        }
+        Input::CoinSigned(_)
+        | Input::Contract(_)
+        | Input::MessageCoinSigned(_)
+        | Input::MessageDataSigned(_) => {}
+    }
+}
+
+/// Measures the gas used by each predicate of the transaction. The VM reports only
+/// the gas of all predicates, so each predicate runs with the other ones replaced by
+/// the predicate returning `true`, and the gas of the replacements is subtracted.
+fn gas_used_by_predicates<Tx>(
+    tx: Tx,
+    block_height: Word,
+    params: ConsensusParameters,
+    gas_costs: &GasCosts,
+) -> anyhow::Result<Vec<PredicateGasUsed>>
+where
+    Tx: ExecutableTransaction + Cacheable,
+    <Tx as IntoChecked>::Metadata: CheckedMetadata,
+{
+    let predicates: Vec<usize> = tx
+        .inputs()
+        .iter()
+        .enumerate()
+        .filter(|(_, input)| input.predicate().is_some())
+        .map(|(index, _)| index)
+        .collect();
+    if predicates.is_empty() {
+        return Ok(Vec::new())
+    }
+
+    let trivial: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+    let with_trivial_predicates = |measured: Option<usize>| {
+        let mut tx = tx.clone();
+        for (index, input) in tx.inputs_mut().iter_mut().enumerate() {
+            if Some(index) != measured {
+                replace_predicate(input, &trivial);
+            }
+        }
+        tx
+    };
+    let trivial_gas_used = check_predicates(
+        with_trivial_predicates(None),
+        block_height,
+        params,
+        gas_costs,
+    )? / predicates.len() as Word;
+    let replaced_gas_used = trivial_gas_used * (predicates.len() as Word - 1);
+
+    predicates
+        .into_iter()
+        .map(|index| {
+            let gas_used = check_predicates(
+                with_trivial_predicates(Some(index)),
+                block_height,
+                params,
+                gas_costs,
+            )?;
+            Ok(PredicateGasUsed {
+                input_index: index as u8,
+                gas_used: gas_used.saturating_sub(replaced_gas_used),
+            })
+        })
+        .collect()
 }
 
 #[derive(Default)]
@@ -196,16 +645,60 @@ impl TxMutation {
         // This allows for non-existent inputs to be used without signature validation
         // for read-only calls.
         utxo_validation: Option<bool>,
+        // The changes of the state applied before the execution, like the fake coins or
+        // the replaced bytecode of the contracts.
+        state_overrides: Option<StateOverridesInput>,
     ) -> async_graphql::Result<Vec<receipt::Receipt>> {
         let block_producer = ctx.data_unchecked::<BlockProducer>();
 
         let mut tx = FuelTx::from_bytes(&tx.0)?;
         tx.precompute();
 
-        let receipts = block_producer.dry_run_tx(tx, None, utxo_validation).await?;
+        let overrides = state_overrides.map(Into::into).unwrap_or_default();
+        let receipts = block_producer
+            .dry_run_tx(tx, None, utxo_validation, overrides)
+            .await?;
         Ok(receipts.iter().map(Into::into).collect())
     }
 
+    /// Execute a dry-run of the transactions in one block using a fork of current state,
+    /// no changes are committed. The transactions are executed in order, so later
+    /// transactions can spend the outputs of the earlier ones.
+    async fn dry_run_many(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+        // If set to false, disable input utxo validation, overriding the configuration of the node.
+        utxo_validation: Option<bool>,
+        // The changes of the state applied before the execution of the first transaction.
+        state_overrides: Option<StateOverridesInput>,
+    ) -> async_graphql::Result<Vec<DryRunTransactionResult>> {
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+
+        let txs = txs
+            .iter()
+            .map(|tx| {
+                let mut tx = FuelTx::from_bytes(&tx.0)?;
+                tx.precompute();
+                Ok(tx)
+            })
+            .collect::<Result<Vec<_>, std::io::Error>>()?;
+        let ids: Vec<_> = txs.iter().map(UniqueIdentifier::id).collect();
+
+        let overrides = state_overrides.map(Into::into).unwrap_or_default();
+        let results = block_producer
+            .dry_run_txs(txs, None, utxo_validation, overrides)
+            .await?;
+        Ok(ids
+            .into_iter()
+            .zip(results)
+            .map(|(tx_id, result)| DryRunTransactionResult {
+                tx_id,
+                result: result.map_err(|err| err.to_string()),
+            })
+            .collect())
+    }
+
     /// Submits transaction to the txpool
     async fn submit(
         &self,
@@ -213,10 +706,23 @@ impl TxMutation {
         tx: HexString,
     ) -> async_graphql::Result<Transaction> {
         let txpool = ctx.data_unchecked::<TxPool>();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        if config.read_only {
+            return Err(anyhow!(
+                "The node serves the read-only database and doesn't accept transactions"
+            )
+            .into())
+        }
         let mut tx = FuelTx::from_bytes(&tx.0)?;
         tx.precompute();
+        let source = ctx.data_opt::<Client>().map(|client| {
+            TxSource::Client(match client {
+                Client::ApiKey(key) => ClientId::ApiKey(key.clone()),
+                Client::Ip(ip) => ClientId::Ip(*ip),
+            })
+        });
         let _: Vec<_> = txpool
-            .insert(vec![Arc::new(tx.clone())])
+            .insert(vec![Arc::new(tx.clone())], source)
             .into_iter()
             .try_collect()?;
 
@@ -263,6 +769,58 @@ impl TxStatusSubscription {
     }
 }
 
+#[derive(Default)]
+pub struct TxPoolSubscription;
+
+#[Subscription]
+impl TxPoolSubscription {
+    /// Returns a stream of the transactions submitted into the transaction pool.
+    ///
+    /// If the subscriber is polled slower than the transactions arrive,
+    /// the stream returns an error with the number of the skipped transactions
+    /// and continues from the latest ones.
+    async fn new_transactions<'a>(
+        &self,
+        ctx: &Context<'a>,
+    ) -> impl Stream<Item = async_graphql::Result<Transaction>> + 'a {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        txpool.tx_update_subscribe().filter_map(move |update| {
+            let result = match update {
+                Ok(update) if matches!(update.status(), TxStatus::Submitted) => txpool
+                    .transaction(*update.tx_id())
+                    .map(|transaction| Ok(Transaction(transaction))),
+                Ok(_) => None,
+                Err(err) => Some(Err(lagged(err))),
+            };
+            futures::future::ready(result)
+        })
+    }
+
+    /// Returns a stream of the events of the transaction pool: the submission,
+    /// the completion or the squeezing out of the transactions.
+    ///
+    /// If the subscriber is polled slower than the events arrive,
+    /// the stream returns an error with the number of the skipped events
+    /// and continues from the latest ones.
+    async fn txpool_events<'a>(
+        &self,
+        ctx: &Context<'a>,
+    ) -> impl Stream<Item = async_graphql::Result<TxPoolEvent>> + 'a {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        txpool
+            .tx_update_subscribe()
+            .map(|update| update.map(TxPoolEvent).map_err(lagged))
+    }
+}
+
+fn lagged(err: BroadcastStreamRecvError) -> async_graphql::Error {
+    match err {
+        BroadcastStreamRecvError::Lagged(skipped) => async_graphql::Error::new(format!(
+            "The subscriber is lagging behind, {skipped} events were skipped"
+        )),
+    }
+}
+
 #[async_trait::async_trait]
 impl<'a> TxnStatusChangeState for StreamState<'a> {
     async fn get_tx_status(
@@ -7,12 +7,15 @@ use crate::schema::{
         ContractId,
         HexString,
         Nonce,
+        TransactionId,
         U64,
     },
 };
 use async_graphql::{
     Enum,
+    InputObject,
     Object,
+    SimpleObject,
 };
 use derive_more::Display;
 use fuel_core_types::{
@@ -155,3 +158,66 @@ impl From<fuel_tx::Receipt> for Receipt {
         Receipt(receipt)
     }
 }
+
+/// The receipt with its position in the chain.
+pub struct IndexedReceipt {
+    pub(crate) tx_id: fuel_tx::TxId,
+    pub(crate) block_height: fuel_core_types::blockchain::primitives::BlockHeight,
+    pub(crate) receipt: fuel_tx::Receipt,
+}
+
+#[Object]
+impl IndexedReceipt {
+    /// The id of the transaction that emitted the receipt.
+    async fn transaction_id(&self) -> TransactionId {
+        self.tx_id.into()
+    }
+
+    /// The height of the block that includes the transaction.
+    async fn block_height(&self) -> U64 {
+        self.block_height.into()
+    }
+
+    async fn receipt(&self) -> Receipt {
+        self.receipt.clone().into()
+    }
+}
+
+/// The additional fields of the filtered receipts connection.
+#[derive(Default, SimpleObject)]
+pub struct ReceiptsScan {
+    /// The cursor of the last receipt checked against the filter. It is set when the scan
+    /// stopped at the limit of checked receipts before filling the page, and the next
+    /// page should start after it instead of the `endCursor`.
+    pub scan_cursor: Option<String>,
+}
+
+#[derive(InputObject)]
+pub struct ReceiptFilterInput {
+    /// Filter receipts emitted by the contract. The receipts of the scripts belong to the
+    /// zeroed contract id, and the `MessageOut` receipts belong to the sender.
+    pub contract: ContractId,
+    /// Filter receipts by the `receiptType` field
+    pub receipt_types: Option<Vec<ReceiptType>>,
+    /// Filter receipts by the `rb` field, used as a topic of the logs
+    pub rb: Option<U64>,
+    /// Filter receipts included in the blocks starting from this height
+    pub from_height: Option<U64>,
+    /// Filter receipts included in the blocks up to this height
+    pub to_height: Option<U64>,
+}
+
+impl ReceiptFilterInput {
+    /// Returns `true` if the receipt matches the type and `rb` filters.
+    pub fn matches(&self, receipt: &fuel_tx::Receipt) -> bool {
+        let type_matches = match &self.receipt_types {
+            Some(types) => types.contains(&receipt.into()),
+            None => true,
+        };
+        let rb_matches = match self.rb {
+            Some(rb) => receipt.rb() == Some(rb.0),
+            None => true,
+        };
+        type_matches && rb_matches
+    }
+}
@@ -20,13 +20,17 @@ use crate::{
         block::Block,
         contract::Contract,
         scalars::{
+            Address,
             AssetId,
             Bytes32,
+            ContractId,
             HexString,
+            Nonce,
             Salt,
             Tai64Timestamp,
             TransactionId,
             TxPointer,
+            UtxoId,
             U64,
         },
     },
@@ -34,12 +38,18 @@ use crate::{
 use async_graphql::{
     Context,
     Enum,
+    InputObject,
     Object,
     Union,
 };
 use fuel_core_storage::Error as StorageError;
+use fuel_core_txpool::service::TxUpdate;
 use fuel_core_types::{
     blockchain::primitives,
+    entities::{
+        coins::coin::Coin,
+        message::Message,
+    },
     fuel_tx::{
         self,
         field::{
@@ -63,6 +73,7 @@ use fuel_core_types::{
     fuel_types::bytes::SerializableVec,
     fuel_vm::ProgramState as VmProgramState,
     services::{
+        executor::StateOverrides,
         txpool,
         txpool::TransactionStatus as TxStatus,
     },
@@ -198,6 +209,293 @@ impl SqueezedOutStatus {
     }
 }
 
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum TxPoolEventKind {
+    /// The transaction was inserted into the transaction pool.
+    Submitted,
+    /// The transaction was included into the block and removed from the pool.
+    Completed,
+    /// The transaction was removed from the pool without being included into the block.
+    SqueezedOut,
+}
+
+pub struct TxPoolEvent(pub(crate) TxUpdate);
+
+#[Object]
+impl TxPoolEvent {
+    async fn transaction_id(&self) -> TransactionId {
+        TransactionId(*self.0.tx_id())
+    }
+
+    async fn kind(&self) -> TxPoolEventKind {
+        match self.0.status() {
+            txpool::TxStatus::Submitted => TxPoolEventKind::Submitted,
+            txpool::TxStatus::Completed => TxPoolEventKind::Completed,
+            txpool::TxStatus::SqueezedOut { .. } => TxPoolEventKind::SqueezedOut,
+        }
+    }
+
+    /// The reason of the removal of the squeezed out transaction.
+    async fn reason(&self) -> Option<String> {
+        match self.0.status() {
+            txpool::TxStatus::SqueezedOut { reason } => Some(reason.to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// The gas used by the predicate of the transaction input.
+pub struct PredicateGasUsed {
+    pub(crate) input_index: u8,
+    pub(crate) gas_used: u64,
+}
+
+#[Object]
+impl PredicateGasUsed {
+    /// The index of the input with the predicate.
+    async fn input_index(&self) -> u8 {
+        self.input_index
+    }
+
+    async fn gas_used(&self) -> U64 {
+        self.gas_used.into()
+    }
+}
+
+/// The gas and the fees of the transaction estimated by its dry run.
+pub struct GasEstimate {
+    pub(crate) gas_used: u64,
+    /// The gas used by all predicates of the transaction.
+    pub(crate) predicate_gas_used: u64,
+    pub(crate) predicates_gas_used: Vec<PredicateGasUsed>,
+    pub(crate) fee: fuel_tx::TransactionFee,
+    pub(crate) receipts: Vec<fuel_tx::Receipt>,
+}
+
+#[Object]
+impl GasEstimate {
+    /// The gas used by the script of the transaction.
+    async fn gas_used(&self) -> U64 {
+        self.gas_used.into()
+    }
+
+    /// The gas used by each predicate of the transaction, in the order of the inputs.
+    async fn predicate_gas_used(&self) -> &[PredicateGasUsed] {
+        &self.predicates_gas_used
+    }
+
+    /// The gas limit sufficient for the script and the predicates of the transaction.
+    async fn gas_limit(&self) -> U64 {
+        self.gas_used.max(self.predicate_gas_used).into()
+    }
+
+    /// The fee for the metered bytes of the transaction at its gas price.
+    async fn min_fee(&self) -> U64 {
+        self.fee.bytes().into()
+    }
+
+    /// The fee for the metered bytes and the `gasLimit` of the transaction
+    /// at its gas price.
+    async fn max_fee(&self) -> U64 {
+        self.fee.total().into()
+    }
+
+    /// The receipts of the dry run, allowing to check that the transaction succeeds.
+    async fn receipts(&self) -> Vec<Receipt> {
+        self.receipts.iter().map(Into::into).collect()
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum DryRunStatus {
+    Success,
+    /// The script of the transaction reverted or panicked.
+    Failure,
+    /// The transaction is invalid in the block and wasn't executed.
+    Invalid,
+}
+
+/// The result of the transaction executed by the dry run.
+pub struct DryRunTransactionResult {
+    pub(crate) tx_id: fuel_tx::TxId,
+    /// The receipts of the executed transaction, or the reason why it is invalid.
+    pub(crate) result: Result<Vec<fuel_tx::Receipt>, String>,
+}
+
+impl DryRunTransactionResult {
+    fn script_result(&self) -> Option<fuel_tx::ScriptExecutionResult> {
+        let receipts = self.result.as_ref().ok()?;
+        receipts.iter().find_map(|receipt| match receipt {
+            fuel_tx::Receipt::ScriptResult { result, .. } => Some(*result),
+            _ => None,
+        })
+    }
+}
+
+#[Object]
+impl DryRunTransactionResult {
+    async fn transaction_id(&self) -> TransactionId {
+        TransactionId(self.tx_id)
+    }
+
+    async fn status(&self) -> DryRunStatus {
+        if self.result.is_err() {
+            return DryRunStatus::Invalid
+        }
+        match self.script_result() {
+            Some(result) if result != fuel_tx::ScriptExecutionResult::Success => {
+                DryRunStatus::Failure
+            }
+            _ => DryRunStatus::Success,
+        }
+    }
+
+    /// The reason of the failure, like `Revert($rA)` or the reason of the panic,
+    /// or the reason why the transaction is invalid.
+    async fn reason(&self) -> Option<String> {
+        let receipts = match &self.result {
+            Ok(receipts) => receipts,
+            Err(reason) => return Some(reason.clone()),
+        };
+        let result = self.script_result()?;
+        if result == fuel_tx::ScriptExecutionResult::Success {
+            return None
+        }
+        let reason = receipts
+            .iter()
+            .find_map(|receipt| match receipt {
+                fuel_tx::Receipt::Revert { ra, .. } => Some(format!("Revert({ra})")),
+                fuel_tx::Receipt::Panic { reason, .. } => {
+                    Some(format!("{}", reason.reason()))
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| format!("{result:?}"));
+        Some(reason)
+    }
+
+    /// The receipts of the executed transaction, empty for the invalid transaction.
+    async fn receipts(&self) -> Vec<Receipt> {
+        self.result.iter().flatten().map(Into::into).collect()
+    }
+}
+
+#[derive(InputObject)]
+pub struct StateOverridesInput {
+    /// The coins added to the state, the existing coins with the same utxo ids are replaced.
+    coins: Option<Vec<CoinOverrideInput>>,
+    /// The messages added to the state, the existing messages with the same nonces are replaced.
+    messages: Option<Vec<MessageOverrideInput>>,
+    /// The values of the storage slots of the contracts.
+    contract_state: Option<Vec<ContractStateOverrideInput>>,
+    /// The balances of the contracts.
+    contract_balances: Option<Vec<ContractBalanceOverrideInput>>,
+    /// The bytecode of the contracts. The contract is created if it doesn't exist.
+    contract_code: Option<Vec<ContractCodeOverrideInput>>,
+}
+
+#[derive(InputObject)]
+pub struct CoinOverrideInput {
+    utxo_id: UtxoId,
+    owner: Address,
+    amount: U64,
+    asset_id: AssetId,
+    maturity: Option<U64>,
+}
+
+#[derive(InputObject)]
+pub struct MessageOverrideInput {
+    sender: Address,
+    recipient: Address,
+    nonce: Nonce,
+    amount: U64,
+    data: Option<HexString>,
+    da_height: Option<U64>,
+}
+
+#[derive(InputObject)]
+pub struct ContractStateOverrideInput {
+    contract_id: ContractId,
+    key: Bytes32,
+    value: Bytes32,
+}
+
+#[derive(InputObject)]
+pub struct ContractBalanceOverrideInput {
+    contract_id: ContractId,
+    asset_id: AssetId,
+    amount: U64,
+}
+
+#[derive(InputObject)]
+pub struct ContractCodeOverrideInput {
+    contract_id: ContractId,
+    bytecode: HexString,
+}
+
+impl From<StateOverridesInput> for StateOverrides {
+    fn from(input: StateOverridesInput) -> Self {
+        let coins = input
+            .coins
+            .unwrap_or_default()
+            .into_iter()
+            .map(|coin| Coin {
+                utxo_id: coin.utxo_id.0,
+                owner: coin.owner.0,
+                amount: coin.amount.0,
+                asset_id: coin.asset_id.0,
+                maturity: coin
+                    .maturity
+                    .map(|maturity| maturity.0)
+                    .unwrap_or_default()
+                    .into(),
+                tx_pointer: Default::default(),
+            })
+            .collect();
+        let messages = input
+            .messages
+            .unwrap_or_default()
+            .into_iter()
+            .map(|message| Message {
+                sender: message.sender.0,
+                recipient: message.recipient.0,
+                nonce: message.nonce.0,
+                amount: message.amount.0,
+                data: message.data.map(|data| data.0).unwrap_or_default(),
+                da_height: primitives::DaBlockHeight(
+                    message.da_height.map(|height| height.0).unwrap_or_default(),
+                ),
+            })
+            .collect();
+        let contract_state = input
+            .contract_state
+            .unwrap_or_default()
+            .into_iter()
+            .map(|slot| (slot.contract_id.0, slot.key.0, slot.value.0))
+            .collect();
+        let contract_balances = input
+            .contract_balances
+            .unwrap_or_default()
+            .into_iter()
+            .map(|balance| (balance.contract_id.0, balance.asset_id.0, balance.amount.0))
+            .collect();
+        let contract_code = input
+            .contract_code
+            .unwrap_or_default()
+            .into_iter()
+            .map(|code| (code.contract_id.0, code.bytecode.0))
+            .collect();
+
+        StateOverrides {
+            coins,
+            messages,
+            contract_state,
+            contract_balances,
+            contract_code,
+        }
+    }
+}
+
 impl From<TxStatus> for TransactionStatus {
     fn from(s: TxStatus) -> Self {
         match s {
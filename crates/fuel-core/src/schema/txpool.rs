@@ -0,0 +1,152 @@
+use crate::{
+    fuel_core_graphql_api::service::TxPool,
+    schema::{
+        scalars::{
+            Address,
+            TransactionId,
+            U64,
+        },
+        tx::types::Transaction,
+    },
+};
+use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
+    Context,
+    Enum,
+    Object,
+};
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_types::{
+    fuel_tx::UniqueIdentifier,
+    fuel_types,
+};
+
+/// The order of the pending transactions.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Enum)]
+pub enum TxPoolOrder {
+    /// By the gas price, starting from the highest.
+    #[default]
+    Price,
+    /// By the time of submission, starting from the oldest.
+    Time,
+}
+
+#[derive(Default)]
+pub struct TxPoolQuery;
+
+#[Object]
+impl TxPoolQuery {
+    /// The state of the transaction pool of the node.
+    async fn txpool(&self) -> TxPoolInfo {
+        TxPoolInfo
+    }
+}
+
+pub struct TxPoolInfo;
+
+#[Object]
+impl TxPoolInfo {
+    /// The number of pending transactions.
+    async fn pending_count(&self, ctx: &Context<'_>) -> U64 {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        (txpool.pending_count() as u64).into()
+    }
+
+    /// The gas limit of all pending transactions combined.
+    async fn consumable_gas(&self, ctx: &Context<'_>) -> U64 {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        txpool.consumable_gas().into()
+    }
+
+    /// The pending transactions in the `order_by` order. The cursor is the transaction id,
+    /// so the page after the transaction that left the pool is empty.
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
+    async fn transactions(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+        #[graphql(default)] order_by: TxPoolOrder,
+    ) -> async_graphql::Result<
+        Connection<TransactionId, Transaction, EmptyFields, EmptyFields>,
+    > {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let mut txs = match order_by {
+            TxPoolOrder::Price => txpool.sorted_by_price(),
+            TxPoolOrder::Time => txpool.sorted_by_time(),
+        };
+
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TransactionId>, direction| {
+                if direction == IterDirection::Reverse {
+                    txs.reverse();
+                }
+                let start = *start;
+                let txs = txs
+                    .into_iter()
+                    .map(|tx| (TransactionId(tx.id()), Transaction(tx)))
+                    .skip_while(
+                        move |(id, _)| matches!(start, Some(start) if id != &start),
+                    )
+                    .map(Ok);
+                Ok(txs)
+            },
+        )
+        .await
+    }
+
+    /// The pending transactions spending the coins or messages of the `owner`,
+    /// sorted by the time of submission.
+    async fn by_owner(&self, ctx: &Context<'_>, owner: Address) -> Vec<Transaction> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        txpool
+            .transactions_by_owner(&fuel_types::Address::from(owner))
+            .into_iter()
+            .map(Transaction)
+            .collect()
+    }
+
+    /// The pending transactions that must be included before the transaction,
+    /// sorted by the gas price. Returns `null` if the transaction is not in the pool.
+    async fn dependencies(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] tx_id: TransactionId,
+    ) -> Option<Vec<Transaction>> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        txpool
+            .dependencies(tx_id.0)
+            .map(|txs| txs.into_iter().map(Transaction).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct TxPoolMutation;
+
+#[Object]
+impl TxPoolMutation {
+    /// Removes the transactions and all transactions depending on them from the pool.
+    /// Returns the ids of the removed transactions.
+    async fn remove_transactions(
+        &self,
+        ctx: &Context<'_>,
+        ids: Vec<TransactionId>,
+    ) -> Vec<TransactionId> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let removed = txpool.remove(ids.into_iter().map(|id| id.0).collect());
+        tracing::info!("Removed {} transactions from the pool", removed.len());
+        removed.into_iter().map(TransactionId).collect()
+    }
+}
@@ -2,6 +2,7 @@ use crate::{
     database::Database,
     service::adapters::P2PAdapter,
 };
+use fuel_core_poa::Trigger;
 use fuel_core_services::{
     RunnableService,
     RunnableTask,
@@ -24,9 +25,13 @@ pub use fuel_core_consensus_module::RelayerVerifierConfig;
 use self::adapters::BlockImporterAdapter;
 
 pub mod adapters;
+pub mod catch_up;
 pub mod config;
+#[cfg(feature = "metrics")]
+pub mod database_metrics;
 pub mod genesis;
 pub mod metrics;
+pub mod pruner;
 pub mod sub_services;
 
 #[derive(Clone)]
@@ -59,20 +64,28 @@ pub struct FuelService {
     pub shared: SharedState,
     /// The address bound by the system for serving the API
     pub bound_address: SocketAddr,
+    /// The address bound by the system for serving the admin API, if it has its own listener
+    pub admin_bound_address: Option<SocketAddr>,
 }
 
 impl FuelService {
     /// Creates a `FuelService` instance from service config
     #[tracing::instrument(skip_all, fields(name = %config.name))]
     pub fn new(database: Database, mut config: Config) -> anyhow::Result<Self> {
-        database.init(&config.chain_conf)?;
+        if config.read_only_db {
+            database.init_read_only()?;
+        } else {
+            database.init(&config.chain_conf)?;
+        }
         Self::make_config_consistent(&mut config);
         let task = Task::new(database, config)?;
         let runner = ServiceRunner::new(task);
         let shared = runner.shared.clone();
         let bound_address = runner.shared.graph_ql.bound_address;
+        let admin_bound_address = runner.shared.graph_ql.admin_bound_address;
         Ok(FuelService {
             bound_address,
+            admin_bound_address,
             shared,
             runner,
         })
@@ -82,6 +95,14 @@ impl FuelService {
     pub async fn new_node(config: Config) -> anyhow::Result<Self> {
         // initialize database
         let database = match config.database_type {
+            #[cfg(feature = "rocksdb")]
+            DbType::RocksDb if config.read_only_db => {
+                anyhow::ensure!(
+                    !config.database_path.as_os_str().is_empty(),
+                    "The read-only database requires the path to the database of another node"
+                );
+                Database::open_secondary(&config.database_path, &config.rocksdb)?
+            }
             #[cfg(feature = "rocksdb")]
             DbType::RocksDb => {
                 // use a default tmp rocksdb if no path is provided
@@ -91,7 +112,7 @@ impl FuelService {
                     );
                     Database::default()
                 } else {
-                    Database::open(&config.database_path)?
+                    Database::open_with_config(&config.database_path, &config.rocksdb)?
                 }
             }
             DbType::InMemory => Database::in_memory(),
@@ -133,6 +154,9 @@ impl FuelService {
 
     // TODO: Rework our configs system to avoid nesting of the same configs.
     fn make_config_consistent(config: &mut Config) {
+        if config.read_only_db {
+            Self::disable_writers(config);
+        }
         if config.txpool.chain_config != config.chain_conf {
             warn!("The `ChainConfig` of `TxPool` was inconsistent");
             config.txpool.chain_config = config.chain_conf.clone();
@@ -146,6 +170,31 @@ impl FuelService {
             config.block_producer.utxo_validation = config.utxo_validation;
         }
     }
+
+    /// Disables the services that write to the database or produce blocks,
+    /// because the read-only database can't be modified by the node.
+    fn disable_writers(config: &mut Config) {
+        if config.block_production != Trigger::Never || config.manual_blocks_enabled {
+            warn!("Block production is disabled with the read-only database");
+            config.block_production = Trigger::Never;
+            config.manual_blocks_enabled = false;
+        }
+        if config.blocks_retention.take().is_some() {
+            warn!("Blocks pruning is disabled with the read-only database");
+        }
+        if config.checkpoints_dir.take().is_some() {
+            warn!("Checkpoints are disabled with the read-only database");
+        }
+        #[cfg(feature = "relayer")]
+        if config.relayer.eth_client.take().is_some() {
+            warn!("Relayer is disabled with the read-only database");
+        }
+        // The blocks received from the network can't be imported.
+        #[cfg(feature = "p2p")]
+        if config.p2p.take().is_some() {
+            warn!("P2P is disabled with the read-only database");
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -195,8 +244,10 @@ pub struct Task {
 impl Task {
     /// Private inner method for initializing the fuel service task
     pub fn new(database: Database, config: Config) -> anyhow::Result<Task> {
-        // initialize state
-        genesis::maybe_initialize_state(&config, &database)?;
+        // initialize state, the read-only database must be initialized by another node
+        if !config.read_only_db {
+            genesis::maybe_initialize_state(&config, &database)?;
+        }
 
         // initialize sub services
         let (services, shared) = sub_services::init_sub_services(&config, &database)?;
@@ -266,9 +317,14 @@ impl RunnableTask for Task {
 
 #[cfg(test)]
 mod tests {
-    use crate::service::{
-        Config,
-        Task,
+    use crate::{
+        database::Database,
+        service::{
+            genesis,
+            Config,
+            FuelService,
+            Task,
+        },
     };
     use fuel_core_services::{
         RunnableService,
@@ -316,11 +372,34 @@ mod tests {
             // p2p
             expected_services += 1;
         }
+        #[cfg(feature = "metrics")]
+        {
+            // database metrics
+            expected_services += 1;
+        }
 
         // # Dev-note: Update the `expected_services` when we add/remove a new/old service.
         assert_eq!(i, expected_services);
     }
 
+    #[tokio::test]
+    async fn read_only_node_starts_only_readers() {
+        let database = Database::default();
+        let config = Config::local_node();
+        database.init(&config.chain_conf).unwrap();
+        genesis::maybe_initialize_state(&config, &database).unwrap();
+
+        let mut config = Config::local_node();
+        config.read_only_db = true;
+        FuelService::make_config_consistent(&mut config);
+        let mut task = Task::new(database, config).unwrap();
+
+        // Only the GraphQL API, the catch up with the primary database and
+        // the database metrics.
+        let expected_services = if cfg!(feature = "metrics") { 3 } else { 2 };
+        assert_eq!(task.sub_services().len(), expected_services);
+    }
+
     #[tokio::test]
     async fn shutdown_stops_all_services() {
         let task = Task::new(Default::default(), Config::local_node()).unwrap();
@@ -16,6 +16,7 @@ use fuel_core_types::{
     services::executor::{
         ExecutionBlock,
         Result as ExecutorResult,
+        StateOverrides,
         UncommittedResult,
     },
 };
@@ -39,13 +40,14 @@ impl ExecutorAdapter {
         &self,
         block: ExecutionBlock,
         utxo_validation: Option<bool>,
-    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        overrides: StateOverrides,
+    ) -> ExecutorResult<Vec<ExecutorResult<Vec<Receipt>>>> {
         let executor = Executor {
             database: self.relayer.database.clone(),
             relayer: self.relayer.clone(),
             config: self.config.clone(),
         };
-        executor.dry_run(block, utxo_validation)
+        executor.dry_run(block, utxo_validation, overrides)
     }
 }
 
@@ -1,21 +1,33 @@
 use crate::{
     database::{
-        transactions::OwnedTransactionIndexCursor,
+        receipts::ContractReceiptIndexCursor,
+        Column,
         Database,
     },
     fuel_core_graphql_api::ports::{
+        BlockImporterPort,
         BlockProducerPort,
         DatabaseBlocks,
         DatabaseChain,
+        DatabaseCheckpoint,
         DatabaseCoins,
         DatabaseContracts,
         DatabaseMessages,
         DatabasePort,
+        DatabaseStateHistory,
+        DatabaseStats,
         DatabaseTransactions,
         DryRunExecution,
         TxPoolPort,
     },
-    service::adapters::TxPoolAdapter,
+    service::{
+        adapters::{
+            BlockImporterAdapter,
+            TxPoolAdapter,
+        },
+        catch_up,
+    },
+    state::ColumnStats,
 };
 use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
@@ -53,16 +65,22 @@ use fuel_core_types::{
     },
     fuel_types::Nonce,
     services::{
+        executor::{
+            Result as ExecutorResult,
+            StateOverrides,
+        },
         graphql_api::ContractBalance,
         txpool::{
             InsertionResult,
             TransactionStatus,
+            TxSource,
         },
     },
     tai64::Tai64,
 };
 use std::{
     ops::Deref,
+    path::Path,
     sync::Arc,
 };
 use tokio_stream::wrappers::{
@@ -92,6 +110,14 @@ impl DatabaseBlocks for Database {
             .transpose()
             .ok_or(not_found!("BlockId"))??)
     }
+
+    fn pruned_height(&self) -> StorageResult<Option<BlockHeight>> {
+        self.pruned_height()
+    }
+
+    fn pruned_block_height(&self, id: &BlockId) -> StorageResult<Option<BlockHeight>> {
+        self.pruned_id_height(id.as_slice())
+    }
 }
 
 impl DatabaseTransactions for Database {
@@ -102,20 +128,56 @@ impl DatabaseTransactions for Database {
             .ok_or(not_found!("TransactionId"))??)
     }
 
+    fn pruned_tx_height(&self, tx_id: &TxId) -> StorageResult<Option<BlockHeight>> {
+        self.pruned_id_height(tx_id.as_ref())
+    }
+
     fn owned_transactions_ids(
         &self,
         owner: Address,
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
-        let start = start.map(|tx_pointer| OwnedTransactionIndexCursor {
-            block_height: tx_pointer.block_height().into(),
-            tx_idx: tx_pointer.tx_index(),
-        });
+        let start = start.map(Into::into);
         self.owned_transactions(owner, start, Some(direction))
             .map(|result| result.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn contract_transactions_ids(
+        &self,
+        contract: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        let start = start.map(Into::into);
+        self.contract_transactions(contract, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
+    fn asset_transactions_ids(
+        &self,
+        asset: AssetId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        let start = start.map(Into::into);
+        self.asset_transactions(asset, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
+    fn contract_receipts_ids(
+        &self,
+        contract: ContractId,
+        start: Option<ContractReceiptIndexCursor>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(ContractReceiptIndexCursor, TxId)>> {
+        self.contract_receipts(contract, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
 }
 
 impl DatabaseMessages for Database {
@@ -197,6 +259,27 @@ impl DatabaseChain for Database {
     }
 }
 
+impl DatabaseStateHistory for Database {
+    fn state_at_height(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Box<dyn DatabasePort>> {
+        Ok(Box::new(Database::state_at_height(self, height)?))
+    }
+}
+
+impl DatabaseCheckpoint for Database {
+    fn create_checkpoint(&self, path: &Path) -> StorageResult<()> {
+        self.checkpoint(path).map_err(Into::into)
+    }
+}
+
+impl DatabaseStats for Database {
+    fn column_stats(&self) -> StorageResult<Vec<(Column, ColumnStats)>> {
+        self.stats().map_err(Into::into)
+    }
+}
+
 impl DatabasePort for Database {}
 
 impl TxPoolPort for TxPoolAdapter {
@@ -212,8 +295,12 @@ impl TxPoolPort for TxPoolAdapter {
             .map(|info| Tai64::from_unix(info.submitted_time().as_secs() as i64))
     }
 
-    fn insert(&self, txs: Vec<Arc<Transaction>>) -> Vec<anyhow::Result<InsertionResult>> {
-        self.service.insert(txs)
+    fn insert(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+        source: Option<TxSource>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.service.insert(txs, source)
     }
 
     fn tx_update_subscribe(
@@ -221,6 +308,56 @@ impl TxPoolPort for TxPoolAdapter {
     ) -> BoxStream<Result<TxUpdate, BroadcastStreamRecvError>> {
         Box::pin(BroadcastStream::new(self.service.tx_update_subscribe()))
     }
+
+    fn pending_count(&self) -> usize {
+        self.service.pending_number()
+    }
+
+    fn consumable_gas(&self) -> u64 {
+        self.service.total_consumable_gas()
+    }
+
+    fn sorted_by_price(&self) -> Vec<Transaction> {
+        self.service
+            .sorted_includable()
+            .into_iter()
+            .map(|tx| tx.deref().into())
+            .collect()
+    }
+
+    fn sorted_by_time(&self) -> Vec<Transaction> {
+        self.service
+            .sorted_by_time()
+            .into_iter()
+            .map(|tx| tx.deref().into())
+            .collect()
+    }
+
+    fn transactions_by_owner(&self, owner: &Address) -> Vec<Transaction> {
+        self.service
+            .find_by_owner(owner)
+            .into_iter()
+            .map(|tx| tx.deref().into())
+            .collect()
+    }
+
+    fn dependencies(&self, id: TxId) -> Option<Vec<Transaction>> {
+        let dependencies = self
+            .service
+            .find_dependencies(id)?
+            .into_iter()
+            .map(|tx| tx.deref().into())
+            .collect();
+        Some(dependencies)
+    }
+
+    fn remove(&self, ids: Vec<TxId>) -> Vec<TxId> {
+        self.service
+            .remove(ids)
+            .into_iter()
+            .map(|tx| tx.id())
+            .collect()
+    }
 }
 
 #[async_trait]
@@ -230,13 +367,48 @@ impl DryRunExecution for BlockProducerAdapter {
         transaction: Transaction,
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
+        overrides: StateOverrides,
     ) -> anyhow::Result<Vec<TxReceipt>> {
         self.block_producer
-            .dry_run(transaction, height, utxo_validation)
+            .dry_run(transaction, height, utxo_validation, overrides)
+            .await
+    }
+
+    async fn dry_run_txs(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
+        overrides: StateOverrides,
+    ) -> anyhow::Result<Vec<ExecutorResult<Vec<TxReceipt>>>> {
+        self.block_producer
+            .dry_run_many(transactions, height, utxo_validation, overrides)
             .await
     }
 }
 
 impl BlockProducerPort for BlockProducerAdapter {}
 
+impl BlockImporterPort for BlockImporterAdapter {
+    fn block_events(&self) -> BoxStream<BlockHeight> {
+        use tokio_stream::StreamExt;
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe()).filter_map(|result| {
+                result
+                    .ok()
+                    .map(|result| *result.sealed_block.entity.header().height())
+            }),
+        )
+    }
+}
+
+/// The read-only node doesn't import blocks, they appear in the database
+/// when it catches up with the node that writes to it.
+impl BlockImporterPort for catch_up::SharedState {
+    fn block_events(&self) -> BoxStream<BlockHeight> {
+        use tokio_stream::StreamExt;
+        Box::pin(BroadcastStream::new(self.subscribe()).filter_map(|result| result.ok()))
+    }
+}
+
 use super::BlockProducerAdapter;
@@ -29,6 +29,7 @@ use fuel_core_types::{
         executor::{
             ExecutionBlock,
             Result as ExecutorResult,
+            StateOverrides,
             UncommittedResult,
         },
         txpool::ArcPoolTx,
@@ -71,8 +72,9 @@ impl fuel_core_producer::ports::Executor<Database> for ExecutorAdapter {
         &self,
         block: ExecutionBlock,
         utxo_validation: Option<bool>,
-    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
-        self._dry_run(block, utxo_validation)
+        overrides: StateOverrides,
+    ) -> ExecutorResult<Vec<ExecutorResult<Vec<Receipt>>>> {
+        self._dry_run(block, utxo_validation, overrides)
     }
 }
 
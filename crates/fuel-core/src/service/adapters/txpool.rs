@@ -25,6 +25,7 @@ use fuel_core_types::{
     },
     fuel_tx::{
         Transaction,
+        TxId,
         UtxoId,
     },
     fuel_types::{
@@ -40,7 +41,10 @@ use fuel_core_types::{
         },
     },
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 impl BlockImporter for BlockImporterAdapter {
     fn block_events(&self) -> BoxStream<Arc<ImportResult>> {
@@ -143,4 +147,22 @@ impl fuel_core_txpool::ports::TxPoolDb for Database {
     fn current_block_height(&self) -> StorageResult<BlockHeight> {
         self.latest_height()
     }
+
+    fn journaled_transactions(&self) -> StorageResult<Vec<(Transaction, Duration)>> {
+        Database::journaled_transactions(self).map_err(Into::into)
+    }
+
+    fn journal_transaction(
+        &self,
+        tx_id: &TxId,
+        transaction: &Transaction,
+        submitted_time: Duration,
+    ) -> StorageResult<()> {
+        Database::journal_transaction(self, tx_id, transaction, submitted_time)
+            .map_err(Into::into)
+    }
+
+    fn remove_journaled_transaction(&self, tx_id: &TxId) -> StorageResult<()> {
+        Database::remove_journaled_transaction(self, tx_id).map_err(Into::into)
+    }
 }
@@ -0,0 +1,143 @@
+use crate::database::Database;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::blockchain::primitives::BlockHeight;
+use std::time::Duration;
+use tokio::{
+    sync::broadcast,
+    time::{
+        Interval,
+        MissedTickBehavior,
+    },
+};
+
+pub type Service = ServiceRunner<Task>;
+
+/// How often the read-only database catches up with the node that writes to it.
+pub const CATCH_UP_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Clone)]
+pub struct SharedState {
+    block_events: broadcast::Sender<BlockHeight>,
+}
+
+impl SharedState {
+    /// Subscribes to the heights of the latest blocks that appear
+    /// in the database after catching up with the primary instance.
+    pub fn subscribe(&self) -> broadcast::Receiver<BlockHeight> {
+        self.block_events.subscribe()
+    }
+}
+
+/// The task periodically applies the changes of the primary database instance
+/// to the read-only secondary instance.
+pub struct Task {
+    database: Database,
+    interval: Interval,
+    latest_height: Option<BlockHeight>,
+    shared: SharedState,
+}
+
+impl Task {
+    async fn catch_up(&mut self) -> anyhow::Result<()> {
+        let database = self.database.clone();
+        let latest_height = tokio::task::spawn_blocking(move || -> anyhow::Result<_> {
+            database.try_catch_up_with_primary()?;
+            Ok(database.ids_of_latest_block()?.map(|(height, _)| height))
+        })
+        .await??;
+
+        if let Some(height) = latest_height {
+            if self.latest_height != Some(height) {
+                self.latest_height = Some(height);
+                let _ = self.shared.block_events.send(height);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "CatchUp";
+
+    type SharedData = SharedState;
+    type Task = Task;
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared.clone()
+    }
+
+    async fn into_task(self, _: &StateWatcher) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            _ = self.interval.tick() => {
+                // The failed attempt is retried on the next tick.
+                if let Err(err) = self.catch_up().await {
+                    tracing::warn!("Failed to catch up with the primary database: {}", err);
+                }
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(database: Database, period: Duration) -> Service {
+    let mut interval = tokio::time::interval(period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let (block_events, _) = broadcast::channel(1024);
+    Service::new(Task {
+        database,
+        interval,
+        latest_height: None,
+        shared: SharedState { block_events },
+    })
+}
+
+#[cfg(all(test, feature = "rocksdb"))]
+mod tests {
+    use super::*;
+    use fuel_core_chain_config::ChainConfig;
+    use fuel_core_services::Service as ServiceTrait;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn secondary_database_follows_the_primary() {
+        let tmp = TempDir::new().unwrap();
+        let primary = Database::open(tmp.path()).unwrap();
+        let secondary =
+            Database::open_secondary(tmp.path(), &Default::default()).unwrap();
+        let service = new_service(secondary.clone(), Duration::from_millis(10));
+        service.start_and_await().await.unwrap();
+        assert_eq!(secondary.get_chain_name().unwrap(), None);
+
+        let config = ChainConfig::local_testnet();
+        primary.init(&config).unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        assert_eq!(secondary.get_chain_name().unwrap(), Some(config.chain_name));
+        service.stop_and_await().await.unwrap();
+    }
+}
@@ -27,14 +27,52 @@ use fuel_core_p2p::config::{
     NotInitialized,
 };
 
+pub use crate::{
+    graphql_api::{
+        rate_limit::RateLimitConfig,
+        ApiLimits,
+    },
+    state::rocks_db_config::{
+        ColumnOptions,
+        Compression,
+        RocksDbConfig,
+    },
+};
 pub use fuel_core_poa::Trigger;
 
 #[derive(Clone, Debug)]
 pub struct Config {
     pub addr: SocketAddr,
+    /// The limits of the queries and of the request rate of the GraphQL API.
+    pub api_limits: ApiLimits,
+    /// The bearer token of the admin GraphQL API with the privileged operations.
+    /// The admin API is served by `addr` only if the token is set.
+    pub admin_token: Option<Secret<String>>,
+    /// The address of the listener serving only the admin GraphQL API. If the admin
+    /// token is not set, the admin API doesn't require it on this address, so
+    /// the address must be the loopback one.
+    pub admin_addr: Option<SocketAddr>,
     pub database_path: PathBuf,
     pub database_type: DbType,
+    /// The tuning of the RocksDB database, ignored by the in-memory database.
+    pub rocksdb: RocksDbConfig,
+    /// The number of the latest blocks kept in the database. Older blocks with their
+    /// transactions and receipts are pruned. `None` means that all blocks are kept.
+    pub blocks_retention: Option<u32>,
+    /// Records the previous values of the versioned state for each block,
+    /// so it can be queried at past block heights.
+    pub state_history: bool,
+    /// The directory where the database checkpoints requested via GraphQL are created.
+    pub checkpoints_dir: Option<PathBuf>,
+    /// Opens the database of another node as a read-only secondary instance
+    /// that follows the node. Block production, block import and transaction
+    /// submission are disabled, only the queries are served.
+    pub read_only_db: bool,
     pub chain_conf: ChainConfig,
+    /// The directory of the snapshot created by `fuel-core snapshot --output-dir`.
+    /// If set, the genesis state is imported from the snapshot instead of
+    /// the initial state of the chain config.
+    pub genesis_snapshot: Option<PathBuf>,
     // default to false until downstream consumers stabilize
     pub utxo_validation: bool,
     pub manual_blocks_enabled: bool,
@@ -62,12 +100,21 @@ impl Config {
         let min_gas_price = 0;
         Self {
             addr: SocketAddr::new(Ipv4Addr::new(127, 0, 0, 1).into(), 0),
+            api_limits: Default::default(),
+            admin_token: None,
+            admin_addr: Some(SocketAddr::new(Ipv4Addr::new(127, 0, 0, 1).into(), 0)),
             database_path: Default::default(),
             #[cfg(feature = "rocksdb")]
             database_type: DbType::RocksDb,
             #[cfg(not(feature = "rocksdb"))]
             database_type: DbType::InMemory,
+            rocksdb: Default::default(),
+            blocks_retention: None,
+            state_history: false,
+            checkpoints_dir: None,
+            read_only_db: false,
             chain_conf: chain_conf.clone(),
+            genesis_snapshot: None,
             manual_blocks_enabled: false,
             block_production: Trigger::Instant,
             vm: Default::default(),
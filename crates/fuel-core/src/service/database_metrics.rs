@@ -0,0 +1,103 @@
+use crate::database::Database;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use std::time::Duration;
+use tokio::time::{
+    Interval,
+    MissedTickBehavior,
+};
+
+pub type Service = ServiceRunner<Task>;
+
+/// How often the metrics of the database columns are refreshed.
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(15);
+
+/// The task periodically updates the metrics of the database columns,
+/// so the exported values follow the growth of the database.
+pub struct Task {
+    database: Database,
+    interval: Interval,
+}
+
+impl Task {
+    async fn refresh(&self) -> anyhow::Result<()> {
+        let database = self.database.clone();
+        // The statistics are exported as the metrics by `Database::stats`.
+        tokio::task::spawn_blocking(move || database.stats()).await??;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "DatabaseMetrics";
+
+    type SharedData = ();
+    type Task = Task;
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(self, _: &StateWatcher) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            _ = self.interval.tick() => {
+                // The failed attempt is retried on the next tick.
+                if let Err(err) = self.refresh().await {
+                    tracing::warn!("Failed to refresh the database metrics: {}", err);
+                }
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(database: Database, period: Duration) -> Service {
+    let mut interval = tokio::time::interval(period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    Service::new(Task { database, interval })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_chain_config::ChainConfig;
+    use fuel_core_metrics::core_metrics::DATABASE_METRICS;
+    use fuel_core_services::Service as ServiceTrait;
+
+    #[tokio::test]
+    async fn metrics_follow_the_database() {
+        let database = Database::default();
+        let service = new_service(database.clone(), Duration::from_millis(10));
+        service.start_and_await().await.unwrap();
+
+        database.init(&ChainConfig::local_testnet()).unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        let labels = vec![("column".to_string(), "metadata".to_string())];
+        let keys = DATABASE_METRICS.column_keys.get_or_create(&labels).get();
+        assert!(keys >= 1);
+        service.stop_and_await().await.unwrap();
+    }
+}
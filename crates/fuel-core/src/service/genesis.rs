@@ -4,8 +4,15 @@ use crate::{
 };
 use anyhow::anyhow;
 use fuel_core_chain_config::{
+    CoinConfig,
+    ContractBalanceConfig,
     ContractConfig,
+    ContractStateConfig,
     GenesisCommitment,
+    MessageConfig,
+    SnapshotEntry,
+    SnapshotReader,
+    SnapshotTable,
     StateConfig,
 };
 use fuel_core_executor::refs::ContractRef;
@@ -24,6 +31,7 @@ use fuel_core_storage::{
     transactional::Transactional,
     MerkleRoot,
     StorageAsMut,
+    StorageAsRef,
 };
 use fuel_core_types::{
     blockchain::{
@@ -37,7 +45,10 @@ use fuel_core_types::{
             ConsensusHeader,
             PartialBlockHeader,
         },
-        primitives::Empty,
+        primitives::{
+            BlockHeight,
+            Empty,
+        },
         SealedBlock,
     },
     entities::{
@@ -62,6 +73,7 @@ use fuel_core_types::{
     },
 };
 use itertools::Itertools;
+use std::path::Path;
 
 /// Loads state from the chain config into database
 pub fn maybe_initialize_state(
@@ -80,6 +92,30 @@ fn import_genesis_block(
     config: &Config,
     original_database: &Database,
 ) -> anyhow::Result<()> {
+    let (height, roots) = match &config.genesis_snapshot {
+        Some(snapshot_dir) => {
+            if config.chain_conf.initial_state.is_some() {
+                tracing::warn!(
+                    "The initial state of the chain config is ignored in favor of the genesis snapshot"
+                );
+            }
+            // The snapshot is imported chunk by chunk before the genesis block,
+            // so the state never has to be kept in memory.
+            import_snapshot(original_database, snapshot_dir)?
+        }
+        None => (
+            // The initial height is defined by the `ChainConfig`.
+            // If it is `None` then it will be zero.
+            config
+                .chain_conf
+                .initial_state
+                .as_ref()
+                .map(|config| config.height.unwrap_or_else(|| 0u32.into()))
+                .unwrap_or_else(|| 0u32.into()),
+            None,
+        ),
+    };
+
     // start a db transaction for bulk-writing
     let mut database_transaction = Transactional::transaction(original_database);
 
@@ -87,17 +123,23 @@ fn import_genesis_block(
     // Initialize the chain id and height.
 
     let chain_config_hash = config.chain_conf.root()?.into();
-    let coins_root = init_coin_state(database, &config.chain_conf.initial_state)?.into();
-    let contracts_root =
-        init_contracts(database, &config.chain_conf.initial_state)?.into();
-    let messages_root = init_da_messages(database, &config.chain_conf.initial_state)?;
-    let messages_root = messages_root.into();
+    let roots = match roots {
+        Some(roots) => roots,
+        None => {
+            let state = &config.chain_conf.initial_state;
+            GenesisRoots {
+                coins_root: init_coin_state(database, state)?,
+                contracts_root: init_contracts(database, state)?,
+                messages_root: init_da_messages(database, state)?,
+            }
+        }
+    };
 
     let genesis = Genesis {
         chain_config_hash,
-        coins_root,
-        contracts_root,
-        messages_root,
+        coins_root: roots.coins_root.into(),
+        contracts_root: roots.contracts_root.into(),
+        messages_root: roots.messages_root.into(),
     };
 
     let block = Block::new(
@@ -110,14 +152,7 @@ fn import_genesis_block(
             consensus: ConsensusHeader::<Empty> {
                 // The genesis is a first block, so previous root is zero.
                 prev_root: Bytes32::zeroed(),
-                // The initial height is defined by the `ChainConfig`.
-                // If it is `None` then it will be zero.
-                height: config
-                    .chain_conf
-                    .initial_state
-                    .as_ref()
-                    .map(|config| config.height.unwrap_or_else(|| 0u32.into()))
-                    .unwrap_or_else(|| 0u32.into()),
+                height,
                 time: fuel_core_types::tai64::Tai64::UNIX_EPOCH,
                 generated: Empty,
             },
@@ -153,6 +188,171 @@ fn import_genesis_block(
     Ok(())
 }
 
+/// The roots of the genesis state committed by the genesis block.
+struct GenesisRoots {
+    coins_root: MerkleRoot,
+    contracts_root: MerkleRoot,
+    messages_root: MerkleRoot,
+}
+
+/// Imports the state from the snapshot directory.
+///
+/// Each chunk of the snapshot is committed in its own transaction along with
+/// the progress of the import. If the node stops before the genesis block is committed,
+/// the import continues from the next chunk on restart.
+fn import_snapshot(
+    database: &Database,
+    snapshot_dir: &Path,
+) -> anyhow::Result<(BlockHeight, Option<GenesisRoots>)> {
+    let snapshot = SnapshotReader::open(snapshot_dir)?;
+    let height = snapshot.manifest().height;
+    let progress = database.genesis_snapshot_progress()?;
+
+    let mut coins_tree = binary::in_memory::MerkleTree::new();
+    let mut generated_output_index: u64 = 0;
+    import_snapshot_table(
+        database,
+        &snapshot,
+        progress,
+        |mut db, coins: Vec<CoinConfig>| {
+            for coin in coins {
+                let (utxo_id, coin) =
+                    genesis_coin(&coin, &mut generated_output_index, height)?;
+                if let Some(db) = db.as_deref_mut() {
+                    insert_coin(db, &utxo_id, &coin)?;
+                }
+                coins_tree.push(coin.root()?.as_slice());
+            }
+            Ok(())
+        },
+    )?;
+
+    let mut message_tree = binary::in_memory::MerkleTree::new();
+    import_snapshot_table(
+        database,
+        &snapshot,
+        progress,
+        |mut db, messages: Vec<MessageConfig>| {
+            for message in messages {
+                let message = Message::from(message);
+                if let Some(db) = db.as_deref_mut() {
+                    insert_message(db, &message)?;
+                }
+                message_tree.push(message.root()?.as_slice());
+            }
+            Ok(())
+        },
+    )?;
+
+    let mut generated_output_index: usize = 0;
+    import_snapshot_table(
+        database,
+        &snapshot,
+        progress,
+        |mut db, contracts: Vec<ContractConfig>| {
+            for contract in contracts {
+                if let Some(db) = db.as_deref_mut() {
+                    init_contract(db, &contract, generated_output_index, height)?;
+                }
+                generated_output_index += 1;
+            }
+            Ok(())
+        },
+    )?;
+
+    import_snapshot_table(
+        database,
+        &snapshot,
+        progress,
+        |db, slots: Vec<ContractStateConfig>| {
+            if let Some(db) = db {
+                for slot in slots {
+                    ensure_contract_exists(db, &slot.contract_id)?;
+                    if db
+                        .storage::<ContractsState>()
+                        .insert(&(&slot.contract_id, &slot.key).into(), &slot.value)?
+                        .is_some()
+                    {
+                        return Err(anyhow!("Contract state should not exist"))
+                    }
+                }
+            }
+            Ok(())
+        },
+    )?;
+
+    import_snapshot_table(
+        database,
+        &snapshot,
+        progress,
+        |db, balances: Vec<ContractBalanceConfig>| {
+            if let Some(db) = db {
+                for balance in balances {
+                    ensure_contract_exists(db, &balance.contract_id)?;
+                    if db
+                        .storage::<ContractsAssets>()
+                        .insert(
+                            &(&balance.contract_id, &balance.asset_id).into(),
+                            &balance.amount,
+                        )?
+                        .is_some()
+                    {
+                        return Err(anyhow!("Contract balance should not exist"))
+                    }
+                }
+            }
+            Ok(())
+        },
+    )?;
+
+    // The roots of the contracts depend on their state and balances,
+    // so they are calculated when all tables are imported.
+    let mut contracts_tree = binary::in_memory::MerkleTree::new();
+    let mut db = database.clone();
+    for contract in snapshot.entries::<ContractConfig>() {
+        let contract_id = contract?.contract_id();
+        contracts_tree.push(ContractRef::new(&mut db, contract_id).root()?.as_slice());
+    }
+
+    let roots = GenesisRoots {
+        coins_root: coins_tree.root(),
+        contracts_root: contracts_tree.root(),
+        messages_root: message_tree.root(),
+    };
+    Ok((height, Some(roots)))
+}
+
+/// Calls `import` for each chunk of the table `T::TABLE`.
+/// The database is `None` for chunks imported before the restart of the node.
+fn import_snapshot_table<T, F>(
+    database: &Database,
+    snapshot: &SnapshotReader,
+    progress: Option<(SnapshotTable, usize)>,
+    mut import: F,
+) -> anyhow::Result<()>
+where
+    T: SnapshotEntry,
+    F: FnMut(Option<&mut Database>, Vec<T>) -> anyhow::Result<()>,
+{
+    for index in 0..snapshot.chunks_count::<T>() {
+        let entries = snapshot.read_chunk::<T>(index)?;
+        let imported =
+            matches!(progress, Some(progress) if (T::TABLE, index) <= progress);
+
+        if imported {
+            import(None, entries)?;
+        } else {
+            let mut transaction = Transactional::transaction(database);
+            import(Some(transaction.as_mut()), entries)?;
+            transaction
+                .as_mut()
+                .set_genesis_snapshot_progress(T::TABLE, index)?;
+            transaction.commit()?;
+        }
+    }
+    Ok(())
+}
+
 fn init_coin_state(
     db: &mut Database,
     state: &Option<StateConfig>,
@@ -163,53 +363,12 @@ fn init_coin_state(
     if let Some(state) = &state {
         if let Some(coins) = &state.coins {
             for coin in coins {
-                let utxo_id = UtxoId::new(
-                    // generated transaction id([0..[out_index/255]])
-                    coin.tx_id.unwrap_or_else(|| {
-                        Bytes32::try_from(
-                            (0..(Bytes32::LEN - WORD_SIZE))
-                                .map(|_| 0u8)
-                                .chain(
-                                    (generated_output_index / 255)
-                                        .to_be_bytes()
-                                        .into_iter(),
-                                )
-                                .collect_vec()
-                                .as_slice(),
-                        )
-                        .expect("Incorrect genesis transaction id byte length")
-                    }),
-                    coin.output_index.unwrap_or_else(|| {
-                        generated_output_index += 1;
-                        (generated_output_index % 255) as u8
-                    }),
-                );
-
-                let coin = CompressedCoin {
-                    owner: coin.owner,
-                    amount: coin.amount,
-                    asset_id: coin.asset_id,
-                    maturity: coin.maturity.unwrap_or_default(),
-                    tx_pointer: TxPointer::new(
-                        coin.tx_pointer_block_height
-                            .map(|b| b.into())
-                            .unwrap_or_default(),
-                        coin.tx_pointer_tx_idx.unwrap_or_default(),
-                    ),
-                };
-
-                // ensure coin can't point to blocks in the future
-                if coin.tx_pointer.block_height()
-                    > state.height.unwrap_or_default().into()
-                {
-                    return Err(anyhow!(
-                        "coin tx_pointer height cannot be greater than genesis block"
-                    ))
-                }
-
-                if db.storage::<Coins>().insert(&utxo_id, &coin)?.is_some() {
-                    return Err(anyhow!("Coin should not exist"))
-                }
+                let (utxo_id, coin) = genesis_coin(
+                    coin,
+                    &mut generated_output_index,
+                    state.height.unwrap_or_default(),
+                )?;
+                insert_coin(db, &utxo_id, &coin)?;
                 coins_tree.push(coin.root()?.as_slice())
             }
         }
@@ -217,6 +376,62 @@ fn init_coin_state(
     Ok(coins_tree.root())
 }
 
+fn genesis_coin(
+    coin: &CoinConfig,
+    generated_output_index: &mut u64,
+    height: BlockHeight,
+) -> anyhow::Result<(UtxoId, CompressedCoin)> {
+    let utxo_id = UtxoId::new(
+        // generated transaction id([0..[out_index/255]])
+        coin.tx_id.unwrap_or_else(|| {
+            Bytes32::try_from(
+                (0..(Bytes32::LEN - WORD_SIZE))
+                    .map(|_| 0u8)
+                    .chain((*generated_output_index / 255).to_be_bytes().into_iter())
+                    .collect_vec()
+                    .as_slice(),
+            )
+            .expect("Incorrect genesis transaction id byte length")
+        }),
+        coin.output_index.unwrap_or_else(|| {
+            *generated_output_index += 1;
+            (*generated_output_index % 255) as u8
+        }),
+    );
+
+    let coin = CompressedCoin {
+        owner: coin.owner,
+        amount: coin.amount,
+        asset_id: coin.asset_id,
+        maturity: coin.maturity.unwrap_or_default(),
+        tx_pointer: TxPointer::new(
+            coin.tx_pointer_block_height
+                .map(|b| b.into())
+                .unwrap_or_default(),
+            coin.tx_pointer_tx_idx.unwrap_or_default(),
+        ),
+    };
+
+    // ensure coin can't point to blocks in the future
+    if coin.tx_pointer.block_height() > height.into() {
+        return Err(anyhow!(
+            "coin tx_pointer height cannot be greater than genesis block"
+        ))
+    }
+    Ok((utxo_id, coin))
+}
+
+fn insert_coin(
+    db: &mut Database,
+    utxo_id: &UtxoId,
+    coin: &CompressedCoin,
+) -> anyhow::Result<()> {
+    if db.storage::<Coins>().insert(utxo_id, coin)?.is_some() {
+        return Err(anyhow!("Coin should not exist"))
+    }
+    Ok(())
+}
+
 fn init_contracts(
     db: &mut Database,
     state: &Option<StateConfig>,
@@ -227,78 +442,12 @@ fn init_contracts(
         if let Some(contracts) = &state.contracts {
             for (generated_output_index, contract_config) in contracts.iter().enumerate()
             {
-                let contract = Contract::from(contract_config.code.as_slice());
-                let salt = contract_config.salt;
-                let root = contract.root();
-                let contract_id =
-                    contract.id(&salt, &root, &Contract::default_state_root());
-                let utxo_id = if let (Some(tx_id), Some(output_idx)) =
-                    (contract_config.tx_id, contract_config.output_index)
-                {
-                    UtxoId::new(tx_id, output_idx)
-                } else {
-                    UtxoId::new(
-                        // generated transaction id([0..[out_index/255]])
-                        Bytes32::try_from(
-                            (0..(Bytes32::LEN - WORD_SIZE))
-                                .map(|_| 0u8)
-                                .chain(
-                                    (generated_output_index as u64 / 255)
-                                        .to_be_bytes()
-                                        .into_iter(),
-                                )
-                                .collect_vec()
-                                .as_slice(),
-                        )
-                        .expect("Incorrect genesis transaction id byte length"),
-                        generated_output_index as u8,
-                    )
-                };
-                let tx_pointer = if let (Some(block_height), Some(tx_idx)) = (
-                    contract_config.tx_pointer_block_height,
-                    contract_config.tx_pointer_tx_idx,
-                ) {
-                    TxPointer::new(block_height.into(), tx_idx)
-                } else {
-                    TxPointer::default()
-                };
-
-                if tx_pointer.block_height() > state.height.unwrap_or_default().into() {
-                    return Err(anyhow!(
-                        "contract tx_pointer cannot be greater than genesis block"
-                    ))
-                }
-
-                // insert contract code
-                if db
-                    .storage::<ContractsRawCode>()
-                    .insert(&contract_id, contract.as_ref())?
-                    .is_some()
-                {
-                    return Err(anyhow!("Contract code should not exist"))
-                }
-
-                // insert contract root
-                if db
-                    .storage::<ContractsInfo>()
-                    .insert(&contract_id, &(salt, root))?
-                    .is_some()
-                {
-                    return Err(anyhow!("Contract info should not exist"))
-                }
-                if db
-                    .storage::<ContractsLatestUtxo>()
-                    .insert(
-                        &contract_id,
-                        &ContractUtxoInfo {
-                            utxo_id,
-                            tx_pointer,
-                        },
-                    )?
-                    .is_some()
-                {
-                    return Err(anyhow!("Contract utxo should not exist"))
-                }
+                let contract_id = init_contract(
+                    db,
+                    contract_config,
+                    generated_output_index,
+                    state.height.unwrap_or_default(),
+                )?;
                 init_contract_state(db, &contract_id, contract_config)?;
                 init_contract_balance(db, &contract_id, contract_config)?;
                 contracts_tree
@@ -309,6 +458,94 @@ fn init_contracts(
     Ok(contracts_tree.root())
 }
 
+/// Inserts the code and the info of the contract.
+fn init_contract(
+    db: &mut Database,
+    contract_config: &ContractConfig,
+    generated_output_index: usize,
+    height: BlockHeight,
+) -> anyhow::Result<ContractId> {
+    let contract = Contract::from(contract_config.code.as_slice());
+    let salt = contract_config.salt;
+    let root = contract.root();
+    let contract_id = contract_config.contract_id();
+    let utxo_id = if let (Some(tx_id), Some(output_idx)) =
+        (contract_config.tx_id, contract_config.output_index)
+    {
+        UtxoId::new(tx_id, output_idx)
+    } else {
+        UtxoId::new(
+            // generated transaction id([0..[out_index/255]])
+            Bytes32::try_from(
+                (0..(Bytes32::LEN - WORD_SIZE))
+                    .map(|_| 0u8)
+                    .chain(
+                        (generated_output_index as u64 / 255)
+                            .to_be_bytes()
+                            .into_iter(),
+                    )
+                    .collect_vec()
+                    .as_slice(),
+            )
+            .expect("Incorrect genesis transaction id byte length"),
+            generated_output_index as u8,
+        )
+    };
+    let tx_pointer = if let (Some(block_height), Some(tx_idx)) = (
+        contract_config.tx_pointer_block_height,
+        contract_config.tx_pointer_tx_idx,
+    ) {
+        TxPointer::new(block_height.into(), tx_idx)
+    } else {
+        TxPointer::default()
+    };
+
+    if tx_pointer.block_height() > height.into() {
+        return Err(anyhow!(
+            "contract tx_pointer cannot be greater than genesis block"
+        ))
+    }
+
+    // insert contract code
+    if db
+        .storage::<ContractsRawCode>()
+        .insert(&contract_id, contract.as_ref())?
+        .is_some()
+    {
+        return Err(anyhow!("Contract code should not exist"))
+    }
+
+    // insert contract root
+    if db
+        .storage::<ContractsInfo>()
+        .insert(&contract_id, &(salt, root))?
+        .is_some()
+    {
+        return Err(anyhow!("Contract info should not exist"))
+    }
+    if db
+        .storage::<ContractsLatestUtxo>()
+        .insert(
+            &contract_id,
+            &ContractUtxoInfo {
+                utxo_id,
+                tx_pointer,
+            },
+        )?
+        .is_some()
+    {
+        return Err(anyhow!("Contract utxo should not exist"))
+    }
+    Ok(contract_id)
+}
+
+fn ensure_contract_exists(db: &Database, contract_id: &ContractId) -> anyhow::Result<()> {
+    if !db.storage::<ContractsRawCode>().contains_key(contract_id)? {
+        return Err(anyhow!("Contract {} doesn't exist", contract_id))
+    }
+    Ok(())
+}
+
 fn init_contract_state(
     db: &mut Database,
     contract_id: &ContractId,
@@ -337,22 +574,8 @@ fn init_da_messages(
     if let Some(state) = &state {
         if let Some(message_state) = &state.messages {
             for msg in message_state {
-                let message = Message {
-                    sender: msg.sender,
-                    recipient: msg.recipient,
-                    nonce: msg.nonce,
-                    amount: msg.amount,
-                    data: msg.data.clone(),
-                    da_height: msg.da_height,
-                };
-
-                if db
-                    .storage::<Messages>()
-                    .insert(message.id(), &message)?
-                    .is_some()
-                {
-                    return Err(anyhow!("Message should not exist"))
-                }
+                let message = Message::from(msg.clone());
+                insert_message(db, &message)?;
                 message_tree.push(message.root()?.as_slice());
             }
         }
@@ -361,6 +584,17 @@ fn init_da_messages(
     Ok(message_tree.root())
 }
 
+fn insert_message(db: &mut Database, message: &Message) -> anyhow::Result<()> {
+    if db
+        .storage::<Messages>()
+        .insert(message.id(), message)?
+        .is_some()
+    {
+        return Err(anyhow!("Message should not exist"))
+    }
+    Ok(())
+}
+
 fn init_contract_balance(
     db: &mut Database,
     contract_id: &ContractId,
@@ -772,4 +1006,156 @@ mod tests {
             })
             .collect()
     }
+
+    fn snapshot_source_state(rng: &mut StdRng) -> StateConfig {
+        let coins = (0..5)
+            .map(|_| CoinConfig {
+                tx_id: None,
+                output_index: None,
+                tx_pointer_block_height: None,
+                tx_pointer_tx_idx: None,
+                maturity: None,
+                owner: rng.gen(),
+                amount: rng.gen(),
+                asset_id: rng.gen(),
+            })
+            .collect();
+        let contracts = (0..3)
+            .map(|i| ContractConfig {
+                code: Contract::from(op::ret(i).to_bytes().to_vec()).into(),
+                salt: rng.gen(),
+                state: Some((0..3).map(|_| (rng.gen(), rng.gen())).collect()),
+                balances: Some((0..3).map(|_| (rng.gen(), rng.gen())).collect()),
+                tx_id: None,
+                output_index: None,
+                tx_pointer_block_height: None,
+                tx_pointer_tx_idx: None,
+            })
+            .collect();
+        let messages = (0..3)
+            .map(|_| MessageConfig {
+                sender: rng.gen(),
+                recipient: rng.gen(),
+                nonce: rng.gen(),
+                amount: rng.gen(),
+                data: vec![rng.gen()],
+                da_height: DaBlockHeight(rng.gen()),
+            })
+            .collect();
+
+        StateConfig {
+            coins: Some(coins),
+            contracts: Some(contracts),
+            messages: Some(messages),
+            height: Some(10u32.into()),
+        }
+    }
+
+    /// Returns the database initialized from the state config
+    /// and the snapshot exported from the same database.
+    fn exported_snapshot(
+        initial_state: Option<StateConfig>,
+        snapshot_dir: &std::path::Path,
+    ) -> Database {
+        let config = Config {
+            chain_conf: ChainConfig {
+                initial_state,
+                ..ChainConfig::local_testnet()
+            },
+            ..Config::local_node()
+        };
+        let db = Database::default();
+        maybe_initialize_state(&config, &db).unwrap();
+        db.export_snapshot(snapshot_dir, 2).unwrap();
+        db
+    }
+
+    fn assert_same_state(expected: &Database, actual: &Database) {
+        assert_eq!(
+            expected.get_genesis().unwrap(),
+            actual.get_genesis().unwrap()
+        );
+        assert_eq!(
+            expected.latest_height().unwrap(),
+            actual.latest_height().unwrap()
+        );
+        assert_eq!(
+            expected.get_coin_config().unwrap(),
+            actual.get_coin_config().unwrap()
+        );
+        assert_eq!(
+            expected.get_contract_config().unwrap(),
+            actual.get_contract_config().unwrap()
+        );
+        assert_eq!(
+            expected.get_message_config().unwrap(),
+            actual.get_message_config().unwrap()
+        );
+    }
+
+    #[test]
+    fn snapshot_initializes_the_same_state_as_state_config() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let snapshot_dir = tempfile::tempdir().unwrap();
+        let source =
+            exported_snapshot(Some(snapshot_source_state(&mut rng)), snapshot_dir.path());
+
+        let from_state_config = Database::default();
+        let config = Config {
+            chain_conf: ChainConfig {
+                initial_state: Some(StateConfig::generate_state_config(source).unwrap()),
+                ..ChainConfig::local_testnet()
+            },
+            ..Config::local_node()
+        };
+        maybe_initialize_state(&config, &from_state_config).unwrap();
+
+        // The initial state of the local testnet is ignored in favor of the snapshot.
+        let from_snapshot = Database::default();
+        let config = Config {
+            genesis_snapshot: Some(snapshot_dir.path().to_path_buf()),
+            ..Config::local_node()
+        };
+        maybe_initialize_state(&config, &from_snapshot).unwrap();
+
+        assert_eq!(from_snapshot.latest_height().unwrap(), 10u32.into());
+        assert_same_state(&from_state_config, &from_snapshot);
+    }
+
+    #[test]
+    fn snapshot_import_resumes_from_the_last_imported_chunk() {
+        let mut rng = StdRng::seed_from_u64(43);
+        let snapshot_dir = tempfile::tempdir().unwrap();
+        exported_snapshot(Some(snapshot_source_state(&mut rng)), snapshot_dir.path());
+        let config = Config {
+            genesis_snapshot: Some(snapshot_dir.path().to_path_buf()),
+            ..Config::local_node()
+        };
+
+        let expected = Database::default();
+        maybe_initialize_state(&config, &expected).unwrap();
+
+        // The node stopped after importing the coins but before the genesis block.
+        let db = Database::default();
+        let coins = SnapshotReader::open(snapshot_dir.path()).unwrap();
+        import_snapshot_table(&db, &coins, None, |db, coins: Vec<CoinConfig>| {
+            let db = db.expect("The chunk is not imported yet");
+            let mut generated_output_index = 0;
+            for coin in coins {
+                let (utxo_id, coin) =
+                    genesis_coin(&coin, &mut generated_output_index, 10u32.into())?;
+                insert_coin(db, &utxo_id, &coin)?;
+            }
+            Ok(())
+        })
+        .unwrap();
+        assert!(db.ids_of_latest_block().unwrap().is_none());
+        assert_eq!(
+            db.genesis_snapshot_progress().unwrap(),
+            Some((SnapshotTable::Coins, 2))
+        );
+
+        maybe_initialize_state(&config, &db).unwrap();
+        assert_same_state(&expected, &db);
+    }
 }
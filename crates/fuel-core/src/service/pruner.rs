@@ -0,0 +1,156 @@
+use crate::database::Database;
+use fuel_core_services::{
+    stream::BoxStream,
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockHeight,
+    services::block_importer::ImportResult,
+};
+use std::sync::Arc;
+use tokio_stream::StreamExt;
+
+pub type Service = ServiceRunner<Task>;
+
+/// The task removes blocks, transactions and receipts that are out of the retention window
+/// after each imported block.
+pub struct Task {
+    database: Database,
+    /// The number of the latest blocks that should be kept in the database.
+    blocks_retention: u32,
+    block_events: BoxStream<Arc<ImportResult>>,
+}
+
+impl Task {
+    /// Prunes all blocks that are older than `blocks_retention` relative to the `latest_height`.
+    async fn prune(&self, latest_height: BlockHeight) -> anyhow::Result<()> {
+        let prune_below = match (*latest_height + 1).checked_sub(self.blocks_retention) {
+            Some(height) => height,
+            None => return Ok(()),
+        };
+        let database = self.database.clone();
+        let pruned = tokio::task::spawn_blocking(move || {
+            database.prune_blocks_below(prune_below.into())
+        })
+        .await??;
+
+        if pruned > 0 {
+            tracing::info!("Pruned {} blocks below height {}", pruned, prune_below);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "Pruner";
+
+    type SharedData = EmptyShared;
+    type Task = Task;
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(self, _: &StateWatcher) -> anyhow::Result<Self::Task> {
+        // Catch up with blocks imported while the node was offline
+        // or with the decreased retention window.
+        let latest_height = self.database.latest_height()?;
+        self.prune(latest_height).await?;
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            result = self.block_events.next() => {
+                if let Some(result) = result {
+                    let height = *result.sealed_block.entity.header().height();
+                    self.prune(height).await?;
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Each block is pruned in its own database transaction,
+        // so there is nothing to finish here.
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    database: Database,
+    blocks_retention: u32,
+    block_events: BoxStream<Arc<ImportResult>>,
+) -> Service {
+    Service::new(Task {
+        database,
+        blocks_retention,
+        block_events,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_services::{
+        stream::IntoBoxStream,
+        Service as ServiceTrait,
+    };
+    use fuel_core_storage::{
+        tables::FuelBlocks,
+        StorageAsMut,
+    };
+    use fuel_core_types::blockchain::{
+        block::PartialFuelBlock,
+        header::{
+            ConsensusHeader,
+            PartialBlockHeader,
+        },
+        primitives::Empty,
+    };
+
+    #[tokio::test]
+    async fn pruner_keeps_only_retention_window_on_start() {
+        let mut database = Database::default();
+        for height in 0u32..10 {
+            let header = PartialBlockHeader {
+                application: Default::default(),
+                consensus: ConsensusHeader::<Empty> {
+                    height: height.into(),
+                    ..Default::default()
+                },
+            };
+            let block = PartialFuelBlock::new(header, vec![]).generate(&[]);
+            database
+                .storage::<FuelBlocks>()
+                .insert(&block.id(), &block.compress())
+                .unwrap();
+        }
+
+        let service =
+            new_service(database.clone(), 3, futures::stream::pending().into_boxed());
+        service.start_and_await().await.unwrap();
+
+        assert_eq!(database.pruned_height().unwrap(), Some(6u32.into()));
+        service.stop_and_await().await.unwrap();
+    }
+}
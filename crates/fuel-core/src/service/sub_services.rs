@@ -3,8 +3,16 @@ use super::adapters::P2PAdapter;
 
 use crate::{
     database::Database,
-    fuel_core_graphql_api::Config as GraphQLConfig,
+    fuel_core_graphql_api::{
+        ports::BlockImporterPort,
+        service::{
+            Database as GraphQLDatabase,
+            TxPool as GraphQLTxPool,
+        },
+        Config as GraphQLConfig,
+    },
     schema::{
+        build_admin_schema,
         build_schema,
         dap,
     },
@@ -19,6 +27,7 @@ use crate::{
             VerifierAdapter,
         },
         Config,
+        DbType,
         SharedState,
         SubServices,
     },
@@ -104,6 +113,28 @@ pub fn init_sub_services(
 
     let p2p_adapter = p2p_adapter;
 
+    let pruner = config.blocks_retention.map(|blocks_retention| {
+        use fuel_core_txpool::ports::BlockImporter;
+        crate::service::pruner::new_service(
+            database.clone(),
+            blocks_retention,
+            importer_adapter.block_events(),
+        )
+    });
+
+    #[cfg(feature = "metrics")]
+    let database_metrics = crate::service::database_metrics::new_service(
+        database.clone(),
+        crate::service::database_metrics::REFRESH_INTERVAL,
+    );
+
+    let catch_up = config.read_only_db.then(|| {
+        crate::service::catch_up::new_service(
+            database.clone(),
+            crate::service::catch_up::CATCH_UP_INTERVAL,
+        )
+    });
+
     let txpool = fuel_core_txpool::new_service(
         config.txpool.clone(),
         database.clone(),
@@ -141,7 +172,7 @@ pub fn init_sub_services(
     let poa_adapter = PoAAdapter::new(poa.as_ref().map(|service| service.shared.clone()));
 
     #[cfg(feature = "p2p")]
-    let sync = (!production_enabled)
+    let sync = (!production_enabled && !config.read_only_db)
         .then(|| {
             fuel_core_sync::service::new_service(
                 *last_block.header().height(),
@@ -154,12 +185,19 @@ pub fn init_sub_services(
         .transpose()?;
 
     // TODO: Figure out on how to move it into `fuel-core-graphql-api`.
-    let schema = dap::init(
-        build_schema(),
+    let admin_schema = dap::init(
+        build_admin_schema(),
         config.chain_conf.transaction_parameters,
         config.chain_conf.gas_costs.clone(),
     )
-    .data(database.clone());
+    .data(database.clone())
+    .data::<GraphQLDatabase>(Box::new(database.clone()))
+    .data::<GraphQLTxPool>(Box::new(tx_pool_adapter.clone()));
+
+    let block_events: Box<dyn BlockImporterPort> = match &catch_up {
+        Some(catch_up) => Box::new(catch_up.shared.clone()),
+        None => Box::new(importer_adapter.clone()),
+    };
 
     let graph_ql = crate::fuel_core_graphql_api::service::new_service(
         GraphQLConfig {
@@ -171,12 +209,22 @@ pub fn init_sub_services(
             max_tx: config.txpool.max_tx,
             max_depth: config.txpool.max_depth,
             transaction_parameters: config.chain_conf.transaction_parameters,
+            gas_costs: config.chain_conf.gas_costs.clone(),
             consensus_key: config.consensus_key.clone(),
+            checkpoints_dir: config.checkpoints_dir.clone(),
+            read_only: config.read_only_db,
+            database_options: matches!(config.database_type, DbType::RocksDb)
+                .then(|| config.rocksdb.clone()),
+            limits: config.api_limits.clone(),
+            admin_token: config.admin_token.clone(),
+            admin_addr: config.admin_addr,
         },
-        schema,
+        build_schema(),
+        admin_schema,
         Box::new(database.clone()),
         Box::new(tx_pool_adapter),
         Box::new(producer_adapter),
+        block_events,
         Box::new(poa_adapter),
     )?;
 
@@ -197,13 +245,29 @@ pub fn init_sub_services(
     let mut services: SubServices = vec![
         // GraphQL should be shutdown first, so let's start it first.
         Box::new(graph_ql),
-        Box::new(txpool),
     ];
 
+    // The read-only node doesn't accept transactions, so the pool stays empty and
+    // doesn't gossip transactions or restore the journal into the database.
+    if !config.read_only_db {
+        services.push(Box::new(txpool));
+    }
+
     if let Some(poa) = poa {
         services.push(Box::new(poa));
     }
 
+    if let Some(pruner) = pruner {
+        services.push(Box::new(pruner));
+    }
+
+    if let Some(catch_up) = catch_up {
+        services.push(Box::new(catch_up));
+    }
+
+    #[cfg(feature = "metrics")]
+    services.push(Box::new(database_metrics));
+
     #[cfg(feature = "relayer")]
     if let Some(relayer) = relayer_service {
         services.push(Box::new(relayer));
@@ -1,6 +1,7 @@
 use crate::{
     database::{
         Column,
+        Error as DatabaseError,
         Result as DatabaseResult,
     },
     state::in_memory::transaction::MemoryTransactionView,
@@ -11,6 +12,7 @@ use fuel_core_storage::iter::{
 };
 use std::{
     fmt::Debug,
+    path::Path,
     sync::Arc,
 };
 
@@ -70,12 +72,11 @@ pub trait BatchOperations: KeyValueStore {
     ) -> DatabaseResult<()> {
         for entry in entries {
             match entry {
-                // TODO: error handling
                 WriteOperation::Insert(key, column, value) => {
-                    let _ = self.put(&key, column, value);
+                    self.put(&key, column, value)?;
                 }
                 WriteOperation::Remove(key, column) => {
-                    let _ = self.delete(&key, column);
+                    self.delete(&key, column)?;
                 }
             }
         }
@@ -89,6 +90,17 @@ pub enum WriteOperation {
     Remove(Vec<u8>, Column),
 }
 
+/// The statistics of the storage column.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnStats {
+    /// The number of keys in the column. It is an estimation for RocksDB.
+    pub keys: u64,
+    /// The approximate size of the keys and values in bytes.
+    pub bytes: u64,
+    /// The internal statistics of the storage backend, if it provides them.
+    pub internal: Option<String>,
+}
+
 pub trait Transaction {
     fn transaction<F, R>(&mut self, f: F) -> TransactionResult<R>
     where
@@ -97,13 +109,43 @@ pub trait Transaction {
 
 pub type TransactionResult<T> = core::result::Result<T, TransactionError>;
 
-pub trait TransactableStorage: BatchOperations + Debug + Send + Sync {}
+pub trait TransactableStorage: BatchOperations + Debug + Send + Sync {
+    /// Creates a consistent copy of the storage in the `path` directory
+    /// without blocking the concurrent reads and writes.
+    fn checkpoint(&self, _path: &Path) -> DatabaseResult<()> {
+        Err(DatabaseError::Other(anyhow::anyhow!(
+            "The storage doesn't support checkpoints"
+        )))
+    }
+
+    /// Applies the changes made by the primary instance since the last call.
+    /// Only the secondary instances of the storage support it.
+    fn try_catch_up_with_primary(&self) -> DatabaseResult<()> {
+        Err(DatabaseError::Other(anyhow::anyhow!(
+            "The storage is not a secondary instance"
+        )))
+    }
+
+    /// Returns the statistics of the `column`. By default, the statistics are calculated
+    /// by iterating over all entries of the column.
+    fn column_stats(&self, column: Column) -> DatabaseResult<ColumnStats> {
+        let mut stats = ColumnStats::default();
+        for entry in self.iter_all(column, None, None, IterDirection::Forward) {
+            let (key, value) = entry?;
+            stats.keys += 1;
+            stats.bytes += (key.len() + value.len()) as u64;
+        }
+        Ok(stats)
+    }
+}
 
 #[derive(Clone, Debug)]
 pub enum TransactionError {
     Aborted,
 }
 
+pub mod historical;
 pub mod in_memory;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db;
+pub mod rocks_db_config;
@@ -0,0 +1,355 @@
+use crate::{
+    database::{
+        Column,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+    state::{
+        in_memory::column_key,
+        BatchOperations,
+        ColumnId,
+        DataSource,
+        IterDirection,
+        KVItem,
+        KeyValueStore,
+        TransactableStorage,
+        WriteOperation,
+    },
+};
+use fuel_core_storage::iter::{
+    BoxedIter,
+    IntoBoxedIter,
+};
+use fuel_core_types::blockchain::primitives::BlockHeight;
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    iter::Peekable,
+    mem::size_of,
+};
+
+/// The columns that track the history of changes in the [`Column::StateHistory`].
+pub(crate) const VERSIONED_COLUMNS: [Column; 7] = [
+    Column::ContractsState,
+    Column::ContractsAssets,
+    Column::Coins,
+    Column::OwnedCoins,
+    Column::Messages,
+    Column::OwnedMessageIds,
+    Column::SpentMessages,
+];
+
+/// Returns the key of the [`Column::StateHistory`] that stores the value of the `key`
+/// before the block at `height` was applied. The key is `column ++ key ++ height`,
+/// so all changes of the `key` are sorted by the height.
+pub(crate) fn history_key(column: Column, key: &[u8], height: u32) -> Vec<u8> {
+    let mut history_key = column_key(key, column);
+    history_key.extend_from_slice(&height.to_be_bytes());
+    history_key
+}
+
+/// The upper bound of the size of the keys of the [`VERSIONED_COLUMNS`].
+const MAX_VERSIONED_KEY_SIZE: usize = 128;
+
+/// Splits the key of the [`Column::StateHistory`] into the original key and the height.
+fn split_history_key(history_key: &[u8]) -> DatabaseResult<(&[u8], u32)> {
+    let column_len = size_of::<ColumnId>();
+    let height_len = size_of::<u32>();
+    if history_key.len() < column_len + height_len {
+        return Err(DatabaseError::Codec)
+    }
+    let (key, height) = history_key.split_at(history_key.len() - height_len);
+    Ok((&key[column_len..], u32::from_be_bytes(height.try_into()?)))
+}
+
+/// The read-only view of the state at the end of the block at some height.
+///
+/// The values of the [`VERSIONED_COLUMNS`] are restored from the [`Column::StateHistory`]:
+/// the value at the `height` is the value before the first change after the `height`.
+/// If the key was not changed after the `height`, the value is taken from the latest state.
+/// All other columns are read from the latest state as is.
+#[derive(Debug)]
+pub struct HistoricalView {
+    data_source: DataSource,
+    height: BlockHeight,
+}
+
+impl HistoricalView {
+    pub fn new(data_source: DataSource, height: BlockHeight) -> Self {
+        Self {
+            data_source,
+            height,
+        }
+    }
+
+    /// Returns the value of the `key` before the first change after the `height`,
+    /// or `None` if the `key` was not changed since then.
+    fn historical_value(
+        &self,
+        key: &[u8],
+        column: Column,
+    ) -> DatabaseResult<Option<Option<Vec<u8>>>> {
+        let next_height = match self.height.checked_add(1) {
+            Some(next_height) => next_height,
+            None => return Ok(None),
+        };
+        let prefix = column_key(key, column);
+        let start = history_key(column, key, next_height);
+        self.data_source
+            .iter_all(
+                Column::StateHistory,
+                Some(&prefix),
+                Some(&start),
+                IterDirection::Forward,
+            )
+            .next()
+            .transpose()?
+            .map(|(_, value)| {
+                postcard::from_bytes(&value).map_err(|_| DatabaseError::Codec)
+            })
+            .transpose()
+    }
+
+    /// Iterates over the entries of the versioned `column` at the `height`. The latest
+    /// entries are merged with the entries restored from the history, so only the entries
+    /// of the iterated range are read.
+    fn versioned_entries(
+        &self,
+        column: Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        let latest = self.data_source.iter_all(column, prefix, start, direction);
+
+        // The keys of the versioned columns have a fixed size per column, so the history
+        // entries are sorted by the key first and then by the height.
+        let history_prefix = column_key(prefix.unwrap_or_default(), column);
+        let history_start = match (start, direction) {
+            (Some(start), IterDirection::Forward) => history_key(column, start, 0),
+            (Some(start), IterDirection::Reverse) => history_key(column, start, u32::MAX),
+            // The `start` equal to the `prefix` limits the iteration by the prefix
+            // even for columns without the prefix extractor.
+            (None, IterDirection::Forward) => history_prefix.clone(),
+            (None, IterDirection::Reverse) => {
+                let mut history_start = history_prefix.clone();
+                history_start.extend_from_slice(&[u8::MAX; MAX_VERSIONED_KEY_SIZE]);
+                history_start
+            }
+        };
+        let history = self.data_source.iter_all(
+            Column::StateHistory,
+            Some(&history_prefix),
+            Some(&history_start),
+            direction,
+        );
+        let restored = RestoredEntries {
+            history: history.peekable(),
+            height: *self.height,
+            direction,
+        };
+
+        MergedEntries {
+            latest: latest.peekable(),
+            restored: restored.peekable(),
+            direction,
+        }
+        .into_boxed()
+    }
+
+    fn read_only_error() -> DatabaseError {
+        DatabaseError::Other(anyhow::anyhow!("The historical state is read-only"))
+    }
+}
+
+impl KeyValueStore for HistoricalView {
+    fn put(&self, _: &[u8], _: Column, _: Vec<u8>) -> DatabaseResult<Option<Vec<u8>>> {
+        Err(Self::read_only_error())
+    }
+
+    fn write(&self, _: &[u8], _: Column, _: Vec<u8>) -> DatabaseResult<usize> {
+        Err(Self::read_only_error())
+    }
+
+    fn replace(
+        &self,
+        _: &[u8],
+        _: Column,
+        _: Vec<u8>,
+    ) -> DatabaseResult<(usize, Option<Vec<u8>>)> {
+        Err(Self::read_only_error())
+    }
+
+    fn take(&self, _: &[u8], _: Column) -> DatabaseResult<Option<Vec<u8>>> {
+        Err(Self::read_only_error())
+    }
+
+    fn delete(&self, _: &[u8], _: Column) -> DatabaseResult<Option<Vec<u8>>> {
+        Err(Self::read_only_error())
+    }
+
+    fn exists(&self, key: &[u8], column: Column) -> DatabaseResult<bool> {
+        Ok(self.get(key, column)?.is_some())
+    }
+
+    fn size_of_value(&self, key: &[u8], column: Column) -> DatabaseResult<Option<usize>> {
+        Ok(self.get(key, column)?.map(|value| value.len()))
+    }
+
+    fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Vec<u8>>> {
+        if VERSIONED_COLUMNS.contains(&column) {
+            if let Some(value) = self.historical_value(key, column)? {
+                return Ok(value)
+            }
+        }
+        self.data_source.get(key, column)
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Column,
+        mut buf: &mut [u8],
+    ) -> DatabaseResult<Option<usize>> {
+        self.get(key, column)?
+            .map(|value| {
+                let read = value.len();
+                std::io::Write::write_all(&mut buf, value.as_ref())
+                    .map_err(|e| DatabaseError::Other(anyhow::anyhow!(e)))?;
+                DatabaseResult::Ok(read)
+            })
+            .transpose()
+    }
+
+    fn read_alloc(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Vec<u8>>> {
+        self.get(key, column)
+    }
+
+    fn iter_all(
+        &self,
+        column: Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        if !VERSIONED_COLUMNS.contains(&column) {
+            return self.data_source.iter_all(column, prefix, start, direction)
+        }
+
+        self.versioned_entries(column, prefix, start, direction)
+    }
+}
+
+impl BatchOperations for HistoricalView {
+    fn batch_write(
+        &self,
+        _: &mut dyn Iterator<Item = WriteOperation>,
+    ) -> DatabaseResult<()> {
+        Err(Self::read_only_error())
+    }
+}
+
+impl TransactableStorage for HistoricalView {}
+
+/// Restores the values of the keys changed after the `height` from the history entries
+/// sorted in the `direction`. The value at the `height` is the value before the first
+/// change after the `height`, `None` means that the key didn't exist at the `height`.
+/// The keys not changed after the `height` are skipped.
+struct RestoredEntries<'a> {
+    history: Peekable<BoxedIter<'a, KVItem>>,
+    height: u32,
+    direction: IterDirection,
+}
+
+impl<'a> Iterator for RestoredEntries<'a> {
+    type Item = DatabaseResult<(Vec<u8>, Option<Vec<u8>>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (history_key, value) = match self.history.next()? {
+                Ok(entry) => entry,
+                Err(err) => return Some(Err(err)),
+            };
+            let (key, height) = match split_history_key(&history_key) {
+                Ok(split) => split,
+                Err(err) => return Some(Err(err)),
+            };
+            let mut restored = (height > self.height).then_some(value);
+
+            // Consume other changes of the same key. The forward iteration meets the first
+            // change after the `height` first, the reverse iteration meets it last.
+            loop {
+                let next_height = match self.history.peek() {
+                    Some(Ok((next_history_key, _))) => {
+                        match split_history_key(next_history_key) {
+                            Ok((next_key, next_height)) if next_key == key => next_height,
+                            _ => break,
+                        }
+                    }
+                    _ => break,
+                };
+                let value = match self.history.next() {
+                    Some(Ok((_, value))) => value,
+                    _ => break,
+                };
+                let is_first_change = match self.direction {
+                    IterDirection::Forward => restored.is_none(),
+                    IterDirection::Reverse => true,
+                };
+                if next_height > self.height && is_first_change {
+                    restored = Some(value);
+                }
+            }
+
+            if let Some(value) = restored {
+                let key = key.to_vec();
+                return Some(
+                    postcard::from_bytes(&value)
+                        .map(|value| (key, value))
+                        .map_err(|_| DatabaseError::Codec),
+                )
+            }
+        }
+    }
+}
+
+/// Merges the latest entries with the restored ones, the restored entries take precedence.
+struct MergedEntries<'a> {
+    latest: Peekable<BoxedIter<'a, KVItem>>,
+    restored: Peekable<RestoredEntries<'a>>,
+    direction: IterDirection,
+}
+
+impl<'a> Iterator for MergedEntries<'a> {
+    type Item = KVItem;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let ordering = match (self.latest.peek(), self.restored.peek()) {
+                (None, None) => return None,
+                (Some(Err(_)), _) | (Some(_), None) => Ordering::Less,
+                (_, Some(Err(_))) | (None, Some(_)) => Ordering::Greater,
+                (Some(Ok((latest, _))), Some(Ok((restored, _)))) => {
+                    match self.direction {
+                        IterDirection::Forward => latest.cmp(restored),
+                        IterDirection::Reverse => restored.cmp(latest),
+                    }
+                }
+            };
+
+            match ordering {
+                Ordering::Less => return self.latest.next(),
+                Ordering::Equal => {
+                    self.latest.next();
+                }
+                Ordering::Greater => {}
+            }
+            match self.restored.next() {
+                Some(Ok((key, Some(value)))) => return Some(Ok((key, value))),
+                Some(Err(err)) => return Some(Err(err)),
+                // The key didn't exist at the `height`.
+                Some(Ok((_, None))) | None => continue,
+            }
+        }
+    }
+}
@@ -15,6 +15,7 @@ use crate::{
         KVItem,
         KeyValueStore,
         TransactableStorage,
+        WriteOperation,
     },
 };
 use fuel_core_storage::iter::{
@@ -203,13 +204,45 @@ impl KeyValueStore for MemoryStore {
     }
 }
 
-impl BatchOperations for MemoryStore {}
+impl BatchOperations for MemoryStore {
+    fn batch_write(
+        &self,
+        entries: &mut dyn Iterator<Item = WriteOperation>,
+    ) -> DatabaseResult<()> {
+        // Prepare the whole batch before taking the lock,
+        // so readers observe either none or all of the changes.
+        let batch: Vec<_> = entries
+            .map(|entry| match entry {
+                WriteOperation::Insert(key, column, value) => {
+                    (column_key(&key, column), Some(value))
+                }
+                WriteOperation::Remove(key, column) => (column_key(&key, column), None),
+            })
+            .collect();
+
+        let mut lock = self.inner.lock().map_err(|_| {
+            DatabaseError::Other(anyhow::anyhow!("The memory store lock is poisoned"))
+        })?;
+        for (key, value) in batch {
+            match value {
+                Some(value) => {
+                    lock.insert(key, value);
+                }
+                None => {
+                    lock.remove(&key);
+                }
+            }
+        }
+        Ok(())
+    }
+}
 
 impl TransactableStorage for MemoryStore {}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Arc;
 
     #[test]
     fn can_use_unit_value() {
@@ -297,4 +330,51 @@ mod tests {
 
         assert!(!db.exists(&key, Column::Metadata).unwrap());
     }
+
+    #[test]
+    fn batch_write_applies_all_operations() {
+        let db = MemoryStore::default();
+        db.put(&[0x01], Column::Metadata, vec![1]).unwrap();
+
+        let ops = vec![
+            WriteOperation::Insert(vec![0x02], Column::Metadata, vec![2]),
+            WriteOperation::Remove(vec![0x01], Column::Metadata),
+            WriteOperation::Insert(vec![0x03], Column::ContractsRawCode, vec![3]),
+        ];
+        db.batch_write(&mut ops.into_iter()).unwrap();
+
+        assert_eq!(db.get(&[0x01], Column::Metadata).unwrap(), None);
+        assert_eq!(db.get(&[0x02], Column::Metadata).unwrap(), Some(vec![2]));
+        assert_eq!(
+            db.get(&[0x03], Column::ContractsRawCode).unwrap(),
+            Some(vec![3])
+        );
+    }
+
+    #[test]
+    fn failed_batch_write_leaves_store_untouched() {
+        let db = Arc::new(MemoryStore::default());
+        db.put(&[0x01], Column::Metadata, vec![1]).unwrap();
+
+        // poison the lock by panicking while holding it
+        let poisoner = db.clone();
+        let _ = std::thread::spawn(move || {
+            let _lock = poisoner.inner.lock().unwrap();
+            panic!("poison the lock");
+        })
+        .join();
+
+        let ops = vec![
+            WriteOperation::Insert(vec![0x02], Column::Metadata, vec![2]),
+            WriteOperation::Remove(vec![0x01], Column::Metadata),
+        ];
+        assert!(db.batch_write(&mut ops.into_iter()).is_err());
+
+        let inner = db.inner.lock().unwrap_err().into_inner();
+        assert_eq!(inner.len(), 1);
+        assert_eq!(
+            inner.get(&column_key(&[0x01], Column::Metadata)),
+            Some(&vec![1])
+        );
+    }
 }
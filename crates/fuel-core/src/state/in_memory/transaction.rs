@@ -37,6 +37,9 @@ use std::{
     },
 };
 
+/// The key and its value before the transaction, `None` if the key didn't exist.
+pub type OriginalValue = (Vec<u8>, Option<Vec<u8>>);
+
 #[derive(Debug)]
 pub struct MemoryTransactionView {
     view_layer: MemoryStore,
@@ -67,6 +70,38 @@ impl MemoryTransactionView {
     }
 }
 
+impl MemoryTransactionView {
+    /// Returns the keys of the `column` modified by the transaction
+    /// along with their values in the underlying data source.
+    pub fn original_values(&self, column: Column) -> DatabaseResult<Vec<OriginalValue>> {
+        let changes: Vec<_> = self
+            .changes
+            .lock()
+            .expect("poisoned lock")
+            .values()
+            .filter_map(|change| match change {
+                WriteOperation::Insert(key, c, value) if *c == column => {
+                    Some((key.clone(), Some(value.clone())))
+                }
+                WriteOperation::Remove(key, c) if *c == column => {
+                    Some((key.clone(), None))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let mut original_values = Vec::with_capacity(changes.len());
+        for (key, value) in changes {
+            let original_value = self.data_source.get(&key, column)?;
+            // skip keys that were changed back to the original value
+            if original_value != value {
+                original_values.push((key, original_value));
+            }
+        }
+        Ok(original_values)
+    }
+}
+
 impl KeyValueStore for MemoryTransactionView {
     fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Vec<u8>>> {
         // try to fetch data from View layer if any changes to the key
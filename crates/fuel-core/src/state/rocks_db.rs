@@ -6,7 +6,13 @@ use crate::{
         Result as DatabaseResult,
     },
     state::{
+        rocks_db_config::{
+            ColumnOptions,
+            Compression,
+            RocksDbConfig,
+        },
         BatchOperations,
+        ColumnStats,
         IterDirection,
         KVItem,
         KeyValueStore,
@@ -21,7 +27,11 @@ use fuel_core_storage::iter::{
     IntoBoxedIter,
 };
 use rocksdb::{
+    checkpoint::Checkpoint,
+    properties,
+    BlockBasedOptions,
     BoundColumnFamily,
+    Cache,
     ColumnFamilyDescriptor,
     DBCompressionType,
     DBWithThreadMode,
@@ -33,6 +43,7 @@ use rocksdb::{
     WriteBatch,
 };
 use std::{
+    fs,
     iter,
     path::Path,
     sync::Arc,
@@ -46,30 +57,34 @@ pub struct RocksDb {
 
 impl RocksDb {
     pub fn default_open<P: AsRef<Path>>(path: P) -> DatabaseResult<RocksDb> {
-        Self::open(path, enum_iterator::all::<Column>().collect::<Vec<_>>())
+        Self::open(
+            path,
+            enum_iterator::all::<Column>().collect::<Vec<_>>(),
+            &RocksDbConfig::default(),
+        )
     }
 
     pub fn open<P: AsRef<Path>>(
         path: P,
         columns: Vec<Column>,
+        config: &RocksDbConfig,
     ) -> DatabaseResult<RocksDb> {
-        let cf_descriptors: Vec<_> = columns
-            .clone()
-            .into_iter()
-            .map(|i| ColumnFamilyDescriptor::new(RocksDb::col_name(i), Self::cf_opts(i)))
-            .collect();
+        let columns_options = Self::columns_options(&columns, config)?;
+        let cf_descriptors = Self::cf_descriptors(&columns_options);
 
         let mut opts = Options::default();
         opts.create_if_missing(true);
         opts.set_compression_type(DBCompressionType::Lz4);
+        if let Some(max_open_files) = config.max_open_files {
+            opts.set_max_open_files(max_open_files);
+        }
         let db = match DB::open_cf_descriptors(&opts, &path, cf_descriptors) {
             Err(_) => {
                 // setup cfs
                 match DB::open_cf(&opts, &path, &[] as &[&str]) {
                     Ok(db) => {
-                        for i in columns {
-                            let opts = Self::cf_opts(i);
-                            db.create_cf(RocksDb::col_name(i), &opts)
+                        for (column, opts) in &columns_options {
+                            db.create_cf(RocksDb::col_name(*column), opts)
                                 .map_err(|e| DatabaseError::Other(e.into()))?;
                         }
                         Ok(db)
@@ -84,25 +99,167 @@ impl RocksDb {
         Ok(rocks_db)
     }
 
+    /// Opens the existing database at `path` in the read-only mode.
+    /// The instance sees the state of the database at the moment of opening,
+    /// all writes fail.
+    pub fn open_read_only<P: AsRef<Path>>(
+        path: P,
+        columns: Vec<Column>,
+        config: &RocksDbConfig,
+    ) -> DatabaseResult<RocksDb> {
+        let columns_options = Self::columns_options(&columns, config)?;
+        let mut opts = Options::default();
+        if let Some(max_open_files) = config.max_open_files {
+            opts.set_max_open_files(max_open_files);
+        }
+        let db = DB::open_cf_descriptors_read_only(
+            &opts,
+            &path,
+            Self::cf_descriptors(&columns_options),
+            false,
+        )
+        .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(RocksDb { db })
+    }
+
+    /// Opens the database at `primary_path` as a secondary instance. The instance is
+    /// read-only and can be used concurrently with the primary instance. It follows
+    /// the primary with [`TransactableStorage::try_catch_up_with_primary`].
+    /// The `secondary_path` is used by the instance for its own info logs.
+    pub fn open_secondary<P: AsRef<Path>, S: AsRef<Path>>(
+        primary_path: P,
+        secondary_path: S,
+        columns: Vec<Column>,
+        config: &RocksDbConfig,
+    ) -> DatabaseResult<RocksDb> {
+        let columns_options = Self::columns_options(&columns, config)?;
+        let mut opts = Options::default();
+        // The secondary instance must keep all files open to follow the primary,
+        // so the `max_open_files` of the config is ignored.
+        opts.set_max_open_files(-1);
+        let db = DB::open_cf_descriptors_as_secondary(
+            &opts,
+            primary_path.as_ref(),
+            secondary_path.as_ref(),
+            Self::cf_descriptors(&columns_options),
+        )
+        .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(RocksDb { db })
+    }
+
+    /// Restores the database at `path` from the `checkpoint` and opens it.
+    /// The `path` should be empty or not exist.
+    pub fn restore_checkpoint<C: AsRef<Path>, P: AsRef<Path>>(
+        checkpoint: C,
+        path: P,
+    ) -> DatabaseResult<RocksDb> {
+        let (checkpoint, path) = (checkpoint.as_ref(), path.as_ref());
+        let is_empty = match fs::read_dir(path) {
+            Ok(mut entries) => entries.next().is_none(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => true,
+            Err(e) => return Err(DatabaseError::Other(e.into())),
+        };
+        if !is_empty {
+            return Err(DatabaseError::Other(anyhow::anyhow!(
+                "The directory {} is not empty",
+                path.display()
+            )))
+        }
+
+        fs::create_dir_all(path).map_err(|e| DatabaseError::Other(e.into()))?;
+        // The checkpoint is a regular database directory without nested directories.
+        for entry in
+            fs::read_dir(checkpoint).map_err(|e| DatabaseError::Other(e.into()))?
+        {
+            let entry = entry.map_err(|e| DatabaseError::Other(e.into()))?;
+            fs::copy(entry.path(), path.join(entry.file_name()))
+                .map_err(|e| DatabaseError::Other(e.into()))?;
+        }
+
+        Self::default_open(path)
+    }
+
     fn cf(&self, column: Column) -> Arc<BoundColumnFamily> {
         self.db
             .cf_handle(&RocksDb::col_name(column))
             .expect("invalid column state")
     }
 
+    /// Returns the options of the `columns` configured by the `config`.
+    /// The columns without their own block cache share one cache.
+    fn columns_options(
+        columns: &[Column],
+        config: &RocksDbConfig,
+    ) -> DatabaseResult<Vec<(Column, Options)>> {
+        let new_cache = |size: usize| {
+            Cache::new_lru_cache(size).map_err(|e| DatabaseError::Other(e.into()))
+        };
+        let shared_cache = config.columns.cache_size.map(new_cache).transpose()?;
+
+        columns
+            .iter()
+            .map(|column| {
+                let cache = if config.has_own_cache(*column) {
+                    config
+                        .column_options(*column)
+                        .cache_size
+                        .map(new_cache)
+                        .transpose()?
+                } else {
+                    shared_cache.clone()
+                };
+                Ok((*column, Self::cf_opts(*column, config, cache.as_ref())))
+            })
+            .collect()
+    }
+
+    fn cf_descriptors(
+        columns_options: &[(Column, Options)],
+    ) -> Vec<ColumnFamilyDescriptor> {
+        columns_options
+            .iter()
+            .map(|(column, opts)| {
+                ColumnFamilyDescriptor::new(RocksDb::col_name(*column), opts.clone())
+            })
+            .collect()
+    }
+
     fn col_name(column: Column) -> String {
         format!("column-{}", column as u32)
     }
 
-    fn cf_opts(column: Column) -> Options {
+    fn cf_opts(column: Column, config: &RocksDbConfig, cache: Option<&Cache>) -> Options {
+        let options = config.column_options(column);
         let mut opts = Options::default();
         opts.create_if_missing(true);
-        opts.set_compression_type(DBCompressionType::Lz4);
+        let compression = options
+            .compression
+            .unwrap_or(ColumnOptions::DEFAULT_COMPRESSION);
+        opts.set_compression_type(match compression {
+            Compression::None => DBCompressionType::None,
+            Compression::Lz4 => DBCompressionType::Lz4,
+            Compression::Lz4hc => DBCompressionType::Lz4hc,
+        });
+        if let Some(write_buffer_size) = options.write_buffer_size {
+            opts.set_write_buffer_size(write_buffer_size);
+        }
+
+        let mut block_opts = BlockBasedOptions::default();
+        if let Some(cache) = cache {
+            block_opts.set_block_cache(cache);
+        }
+        if let Some(bits_per_key) = options.bloom_filter_bits_per_key {
+            block_opts.set_bloom_filter(bits_per_key, false);
+        }
+        opts.set_block_based_table_factory(&block_opts);
 
         // All double-keys should be configured here
         match column {
             Column::OwnedCoins
             | Column::TransactionsByOwnerBlockIdx
+            | Column::ReceiptsByContractBlockIdx
+            | Column::TransactionsByContractBlockIdx
+            | Column::TransactionsByAssetBlockIdx
             | Column::OwnedMessageIds
             | Column::ContractsAssets
             | Column::ContractsState => {
@@ -387,7 +544,43 @@ impl BatchOperations for RocksDb {
     }
 }
 
-impl TransactableStorage for RocksDb {}
+impl TransactableStorage for RocksDb {
+    fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        Checkpoint::new(&self.db)
+            .and_then(|checkpoint| checkpoint.create_checkpoint(path))
+            .map_err(|e| DatabaseError::Other(e.into()))
+    }
+
+    fn try_catch_up_with_primary(&self) -> DatabaseResult<()> {
+        self.db
+            .try_catch_up_with_primary()
+            .map_err(|e| DatabaseError::Other(e.into()))
+    }
+
+    fn column_stats(&self, column: Column) -> DatabaseResult<ColumnStats> {
+        let cf = self.cf(column);
+        let int_property = |name| {
+            self.db
+                .property_int_value_cf(&cf, name)
+                .map(Option::unwrap_or_default)
+                .map_err(|e| DatabaseError::Other(e.into()))
+        };
+
+        let keys = int_property(properties::ESTIMATE_NUM_KEYS)?;
+        let bytes = int_property(properties::TOTAL_SST_FILES_SIZE)?
+            + int_property(properties::CUR_SIZE_ALL_MEM_TABLES)?;
+        let internal = self
+            .db
+            .property_value_cf(&cf, properties::CFSTATS_NO_FILE_HISTOGRAM)
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+
+        Ok(ColumnStats {
+            keys,
+            bytes,
+            internal,
+        })
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -399,6 +592,140 @@ mod tests {
         (RocksDb::default_open(tmp_dir.path()).unwrap(), tmp_dir)
     }
 
+    #[test]
+    fn can_restore_from_checkpoint() {
+        let key = vec![0xA, 0xB, 0xC];
+        let (db, _tmp) = create_db();
+        db.put(&key, Column::Metadata, vec![1, 2, 3]).unwrap();
+
+        let checkpoints = TempDir::new().unwrap();
+        let checkpoint = checkpoints.path().join("checkpoint");
+        db.checkpoint(&checkpoint).unwrap();
+        // changes after the checkpoint are not a part of it
+        db.put(&key, Column::Metadata, vec![4, 5, 6]).unwrap();
+
+        let restored = TempDir::new().unwrap();
+        let restored_db =
+            RocksDb::restore_checkpoint(&checkpoint, restored.path()).unwrap();
+        assert_eq!(
+            restored_db.get(&key, Column::Metadata).unwrap().unwrap(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn restore_checkpoint_fails_for_not_empty_directory() {
+        let (db, _tmp) = create_db();
+        let checkpoints = TempDir::new().unwrap();
+        let checkpoint = checkpoints.path().join("checkpoint");
+        db.checkpoint(&checkpoint).unwrap();
+
+        let (_, existing) = create_db();
+        assert!(RocksDb::restore_checkpoint(&checkpoint, existing.path()).is_err());
+    }
+
+    #[test]
+    fn column_stats_reports_keys_of_column() {
+        let (db, _tmp) = create_db();
+        for key in 0u8..3 {
+            db.put(&[key], Column::Metadata, vec![key; 32]).unwrap();
+        }
+
+        let stats = db.column_stats(Column::Metadata).unwrap();
+
+        assert_eq!(stats.keys, 3);
+        assert!(stats.bytes > 0);
+        assert!(stats.internal.is_some());
+        assert_eq!(db.column_stats(Column::Coins).unwrap().keys, 0);
+    }
+
+    #[test]
+    fn read_only_instance_reads_but_does_not_write() {
+        let key = vec![0xA, 0xB, 0xC];
+        let (db, tmp) = create_db();
+        db.put(&key, Column::Metadata, vec![1, 2, 3]).unwrap();
+        drop(db);
+
+        let read_only = RocksDb::open_read_only(
+            tmp.path(),
+            enum_iterator::all::<Column>().collect(),
+            &RocksDbConfig::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            read_only.get(&key, Column::Metadata).unwrap().unwrap(),
+            vec![1, 2, 3]
+        );
+        assert!(read_only
+            .put(&key, Column::Metadata, vec![4, 5, 6])
+            .is_err());
+    }
+
+    #[test]
+    fn secondary_instance_catches_up_with_primary() {
+        let key = vec![0xA, 0xB, 0xC];
+        let (db, tmp) = create_db();
+        db.put(&key, Column::Metadata, vec![1, 2, 3]).unwrap();
+
+        let secondary_tmp = TempDir::new().unwrap();
+        let secondary = RocksDb::open_secondary(
+            tmp.path(),
+            secondary_tmp.path(),
+            enum_iterator::all::<Column>().collect(),
+            &RocksDbConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            secondary.get(&key, Column::Metadata).unwrap().unwrap(),
+            vec![1, 2, 3]
+        );
+
+        db.put(&key, Column::Metadata, vec![4, 5, 6]).unwrap();
+        secondary.try_catch_up_with_primary().unwrap();
+
+        assert_eq!(
+            secondary.get(&key, Column::Metadata).unwrap().unwrap(),
+            vec![4, 5, 6]
+        );
+        assert!(secondary.put(&key, Column::Metadata, vec![7]).is_err());
+    }
+
+    #[test]
+    fn can_open_with_tuned_columns() {
+        let key = vec![0xA, 0xB, 0xC];
+        let config = RocksDbConfig {
+            max_open_files: Some(64),
+            columns: ColumnOptions {
+                cache_size: Some(1024 * 1024),
+                compression: Some(Compression::None),
+                bloom_filter_bits_per_key: Some(10.0),
+                write_buffer_size: Some(1024 * 1024),
+            },
+            column_overrides: [(
+                Column::ContractsState,
+                ColumnOptions {
+                    cache_size: Some(2 * 1024 * 1024),
+                    compression: Some(Compression::Lz4hc),
+                    ..Default::default()
+                },
+            )]
+            .into_iter()
+            .collect(),
+        };
+        let tmp = TempDir::new().unwrap();
+        let columns: Vec<_> = enum_iterator::all::<Column>().collect();
+        let db = RocksDb::open(tmp.path(), columns.clone(), &config).unwrap();
+        db.put(&key, Column::ContractsState, vec![1, 2, 3]).unwrap();
+        drop(db);
+
+        let db = RocksDb::open(tmp.path(), columns, &config).unwrap();
+        assert_eq!(
+            db.get(&key, Column::ContractsState).unwrap().unwrap(),
+            vec![1, 2, 3]
+        );
+    }
+
     #[test]
     fn can_put_and_read() {
         let key = vec![0xA, 0xB, 0xC];
@@ -0,0 +1,120 @@
+use crate::database::Column;
+use clap::ValueEnum;
+use std::collections::HashMap;
+use strum_macros::{
+    Display,
+    EnumString,
+    EnumVariantNames,
+};
+
+/// The compression algorithms available for the database columns.
+#[derive(
+    Copy, Clone, Debug, Display, Eq, PartialEq, EnumString, EnumVariantNames, ValueEnum,
+)]
+#[strum(serialize_all = "kebab_case")]
+pub enum Compression {
+    None,
+    Lz4,
+    Lz4hc,
+}
+
+/// The options of the database column. The unset options use the defaults of RocksDB.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ColumnOptions {
+    /// The size of the block cache in bytes.
+    pub cache_size: Option<usize>,
+    /// The compression of the column, `Lz4` if not set.
+    pub compression: Option<Compression>,
+    /// The number of bits per key of the bloom filter. The bloom filter is disabled if not set.
+    pub bloom_filter_bits_per_key: Option<f64>,
+    /// The size of the memtable in bytes.
+    pub write_buffer_size: Option<usize>,
+}
+
+impl ColumnOptions {
+    pub const DEFAULT_COMPRESSION: Compression = Compression::Lz4;
+
+    /// Returns the options where the options set in `overrides` replace the current ones.
+    pub fn merge(&self, overrides: &ColumnOptions) -> ColumnOptions {
+        ColumnOptions {
+            cache_size: overrides.cache_size.or(self.cache_size),
+            compression: overrides.compression.or(self.compression),
+            bloom_filter_bits_per_key: overrides
+                .bloom_filter_bits_per_key
+                .or(self.bloom_filter_bits_per_key),
+            write_buffer_size: overrides.write_buffer_size.or(self.write_buffer_size),
+        }
+    }
+}
+
+/// The tuning of the RocksDB database.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RocksDbConfig {
+    /// The maximum number of the files opened by the database, `-1` means no limit.
+    pub max_open_files: Option<i32>,
+    /// The options of all columns. The block cache of the `cache_size`
+    /// is shared by the columns that don't override it.
+    pub columns: ColumnOptions,
+    /// The options of the specific columns that replace the options of all columns.
+    /// The column with the overridden `cache_size` gets its own block cache.
+    pub column_overrides: HashMap<Column, ColumnOptions>,
+}
+
+impl RocksDbConfig {
+    /// Returns the options used for the `column`.
+    pub fn column_options(&self, column: Column) -> ColumnOptions {
+        match self.column_overrides.get(&column) {
+            Some(overrides) => self.columns.merge(overrides),
+            None => self.columns.clone(),
+        }
+    }
+
+    /// Returns `true` if the `column` uses its own block cache
+    /// instead of the one shared by all columns.
+    pub fn has_own_cache(&self, column: Column) -> bool {
+        self.column_overrides
+            .get(&column)
+            .map(|overrides| overrides.cache_size.is_some())
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_overrides_replace_only_the_set_options() {
+        let config = RocksDbConfig {
+            max_open_files: None,
+            columns: ColumnOptions {
+                cache_size: Some(1024),
+                compression: Some(Compression::Lz4hc),
+                bloom_filter_bits_per_key: Some(10.0),
+                write_buffer_size: None,
+            },
+            column_overrides: [(
+                Column::ContractsState,
+                ColumnOptions {
+                    compression: Some(Compression::None),
+                    write_buffer_size: Some(2048),
+                    ..Default::default()
+                },
+            )]
+            .into_iter()
+            .collect(),
+        };
+
+        assert_eq!(config.column_options(Column::Coins), config.columns);
+        assert_eq!(
+            config.column_options(Column::ContractsState),
+            ColumnOptions {
+                cache_size: Some(1024),
+                compression: Some(Compression::None),
+                bloom_filter_bits_per_key: Some(10.0),
+                write_buffer_size: Some(2048),
+            }
+        );
+        assert!(!config.has_own_cache(Column::ContractsState));
+    }
+}
@@ -2,6 +2,8 @@ use lazy_static::lazy_static;
 use prometheus_client::{
     metrics::{
         counter::Counter,
+        family::Family,
+        gauge::Gauge,
         histogram::Histogram,
     },
     registry::Registry,
@@ -14,8 +16,13 @@ pub struct DatabaseMetrics {
     pub read_meter: Counter,
     pub bytes_written: Histogram,
     pub bytes_read: Histogram,
+    pub column_keys: Family<ColumnLabels, Gauge>,
+    pub column_bytes: Family<ColumnLabels, Gauge>,
 }
 
+/// The labels of the per column metrics, contain the name of the column.
+pub type ColumnLabels = Vec<(String, String)>;
+
 impl DatabaseMetrics {
     fn new() -> Self {
         let registry = Registry::default();
@@ -35,6 +42,8 @@ impl DatabaseMetrics {
             read_meter,
             bytes_read: bytes_read_histogram,
             bytes_written: bytes_written_histogram,
+            column_keys: Family::default(),
+            column_bytes: Family::default(),
         }
     }
 }
@@ -60,6 +69,16 @@ pub fn init(mut metrics: DatabaseMetrics) -> DatabaseMetrics {
         "Histogram containing values of amount of bytes written per operation",
         Box::new(metrics.bytes_written.clone()),
     );
+    metrics.registry.register(
+        "Database_Column_Keys",
+        "Number of keys in each column of the database, estimated for RocksDB",
+        Box::new(metrics.column_keys.clone()),
+    );
+    metrics.registry.register(
+        "Database_Column_Bytes",
+        "Approximate size in bytes of each column of the database",
+        Box::new(metrics.column_bytes.clone()),
+    );
 
     metrics
 }
@@ -1,4 +1,5 @@
 use crate::{
+    core_metrics::DATABASE_METRICS,
     p2p_metrics::P2P_METRICS,
     txpool_metrics::TXPOOL_METRICS,
 };
@@ -35,6 +36,12 @@ pub fn encode_metrics_response() -> impl IntoResponse {
             .body(Body::from(""))
             .unwrap()
     }
+    if encode(&mut encoded, &DATABASE_METRICS.registry).is_err() {
+        return Response::builder()
+            .status(503)
+            .body(Body::from(""))
+            .unwrap()
+    }
 
     Response::builder()
         .status(200)
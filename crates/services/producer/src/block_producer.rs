@@ -29,6 +29,8 @@ use fuel_core_types::{
     fuel_types::Bytes32,
     services::executor::{
         ExecutionBlock,
+        Result as ExecutorResult,
+        StateOverrides,
         UncommittedResult,
     },
     tai64::Tai64,
@@ -122,15 +124,41 @@ where
     // TODO: Support custom `block_time` for `dry_run`.
     /// Simulate a transaction without altering any state. Does not aquire the production lock
     /// since it is basically a "read only" operation and shouldn't get in the way of normal
-    /// production.
+    /// production. The `overrides` are applied to the forked state before the execution.
     pub async fn dry_run(
         &self,
         transaction: Transaction,
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
+        overrides: StateOverrides,
     ) -> anyhow::Result<Vec<Receipt>> {
-        // setup the block with the provided tx and optional height
-        // dry_run execute tx on the executor
+        let is_script = transaction.is_script();
+        let res = self
+            .dry_run_many(vec![transaction], height, utxo_validation, overrides)
+            .await?
+            .into_iter()
+            .next()
+            .transpose()?
+            .unwrap_or_default();
+        if is_script && res.is_empty() {
+            return Err(anyhow!("Expected at least one set of receipts"))
+        }
+        Ok(res)
+    }
+
+    /// Simulate the sequence of transactions in one block without altering any state.
+    /// The transactions are executed in order, so later transactions can spend
+    /// the outputs of earlier ones. Returns the receipts of each transaction,
+    /// or the reason why the transaction is invalid in the block.
+    pub async fn dry_run_many(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
+        overrides: StateOverrides,
+    ) -> anyhow::Result<Vec<ExecutorResult<Vec<Receipt>>>> {
+        // setup the block with the provided txs and optional height
+        // dry_run execute txs on the executor
         // return the receipts
         let _permit = self.dry_run_semaphore.acquire().await;
 
@@ -139,24 +167,21 @@ where
             Some(height) => height,
         } + 1u64.into();
 
-        let is_script = transaction.is_script();
         let header = self.new_header(height, Tai64::now()).await?;
-        let block =
-            PartialFuelBlock::new(header, vec![transaction].into_iter().collect());
+        let block = PartialFuelBlock::new(header, transactions);
 
         let executor = self.executor.clone();
         // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
-        let res: Vec<_> = spawn_blocking(move || -> anyhow::Result<Vec<Receipt>> {
-            Ok(executor
-                .dry_run(ExecutionBlock::Production(block), utxo_validation)?
-                .into_iter()
-                .flatten()
-                .collect())
-        })
+        let res = spawn_blocking(
+            move || -> anyhow::Result<Vec<ExecutorResult<Vec<Receipt>>>> {
+                Ok(executor.dry_run(
+                    ExecutionBlock::Production(block),
+                    utxo_validation,
+                    overrides,
+                )?)
+            },
+        )
         .await??;
-        if is_script && res.is_empty() {
-            return Err(anyhow!("Expected at least one set of receipts"))
-        }
         Ok(res)
     }
 }
@@ -31,6 +31,7 @@ use fuel_core_types::{
             ExecutionBlock,
             ExecutionResult,
             Result as ExecutorResult,
+            StateOverrides,
             UncommittedResult,
         },
         txpool::ArcPoolTx,
@@ -148,7 +149,8 @@ impl Executor<MockDb> for MockExecutor {
         &self,
         _block: ExecutionBlock,
         _utxo_validation: Option<bool>,
-    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        _overrides: StateOverrides,
+    ) -> ExecutorResult<Vec<ExecutorResult<Vec<Receipt>>>> {
         Ok(Default::default())
     }
 }
@@ -184,7 +186,8 @@ impl Executor<MockDb> for FailingMockExecutor {
         &self,
         _block: ExecutionBlock,
         _utxo_validation: Option<bool>,
-    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        _overrides: StateOverrides,
+    ) -> ExecutorResult<Vec<ExecutorResult<Vec<Receipt>>>> {
         let mut err = self.0.lock().unwrap();
         if let Some(err) = err.take() {
             Err(err)
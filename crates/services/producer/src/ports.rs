@@ -19,6 +19,7 @@ use fuel_core_types::{
         executor::{
             ExecutionBlock,
             Result as ExecutorResult,
+            StateOverrides,
             UncommittedResult,
         },
         txpool::ArcPoolTx,
@@ -68,10 +69,12 @@ pub trait Executor<Database>: Send + Sync {
 
     /// Executes the block without committing it to the database. During execution collects the
     /// receipts to return them. The `utxo_validation` field can be used to disable the validation
-    /// of utxos during execution.
+    /// of utxos during execution. The `overrides` are applied to the state before the execution.
+    /// Returns the receipts of each transaction of the block, or the reason why it was skipped.
     fn dry_run(
         &self,
         block: ExecutionBlock,
         utxo_validation: Option<bool>,
-    ) -> ExecutorResult<Vec<Vec<Receipt>>>;
+        overrides: StateOverrides,
+    ) -> ExecutorResult<Vec<ExecutorResult<Vec<Receipt>>>>;
 }
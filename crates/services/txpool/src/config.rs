@@ -1,5 +1,12 @@
+use crate::transaction_selector::{
+    GasPriceStrategy,
+    TransactionSelectionStrategy,
+};
 use fuel_core_chain_config::ChainConfig;
-use std::time::Duration;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -17,6 +24,27 @@ pub struct Config {
     pub metrics: bool,
     /// Transaction TTL
     pub transaction_ttl: Duration,
+    /// Journal the accepted transactions in the database to restore them after the restart.
+    /// The restored transactions are validated again, and their TTL is counted
+    /// from the initial submission.
+    pub journal: bool,
+    /// The strategy of selecting the transactions for the next block
+    pub selection_strategy: Arc<dyn TransactionSelectionStrategy>,
+    /// The maximum number of pending transactions spending the coins or messages
+    /// of one owner, not limited if `None`.
+    pub max_txs_per_owner: Option<usize>,
+    /// The maximum number of pending transactions gossiped by one P2P peer,
+    /// not limited if `None`.
+    pub max_txs_per_peer: Option<usize>,
+    /// The maximum number of pending transactions submitted by one GraphQL client,
+    /// identified by the known API key or the IP address, not limited if `None`.
+    pub max_txs_per_client: Option<usize>,
+    /// The minimum increase of the gas price, in percent, required from the transaction
+    /// to replace the colliding transactions.
+    pub min_price_bump_percent: u64,
+    /// The maximum number of transactions, including dependent ones, that can be
+    /// evicted from the pool by one replacement.
+    pub max_evicted_by_replacement: usize,
 }
 
 impl Default for Config {
@@ -54,6 +82,13 @@ impl Config {
             chain_config,
             metrics,
             transaction_ttl,
+            journal: false,
+            selection_strategy: Arc::new(GasPriceStrategy),
+            max_txs_per_owner: None,
+            max_txs_per_peer: None,
+            max_txs_per_client: None,
+            min_price_bump_percent: 0,
+            max_evicted_by_replacement: 100,
         }
     }
 }
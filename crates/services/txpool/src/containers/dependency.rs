@@ -37,6 +37,13 @@ use std::collections::{
 };
 use tracing::warn;
 
+/// The fee paid by the transaction for its max gas. Unlike the `TransactionFee`
+/// it isn't rounded by the `gas_price_factor`, so the fees of the transactions
+/// can be compared precisely.
+fn fee(tx: &ArcPoolTx) -> u128 {
+    tx.price() as u128 * tx.max_gas() as u128
+}
+
 /// Check and hold dependency between inputs and outputs. Be mindful
 /// about depth of connection
 #[derive(Debug, Clone)]
@@ -51,6 +58,10 @@ pub struct Dependency {
     max_depth: usize,
     /// utxo-validation feature flag
     utxo_validation: bool,
+    /// minimum gas price increase in percent required to replace a transaction
+    min_price_bump_percent: u64,
+    /// max number of transactions evicted by one replacement
+    max_evicted: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -96,13 +107,20 @@ pub struct MessageState {
 }
 
 impl Dependency {
-    pub fn new(max_depth: usize, utxo_validation: bool) -> Self {
+    pub fn new(
+        max_depth: usize,
+        utxo_validation: bool,
+        min_price_bump_percent: u64,
+        max_evicted: usize,
+    ) -> Self {
         Self {
             coins: HashMap::new(),
             contracts: HashMap::new(),
             messages: HashMap::new(),
             max_depth,
             utxo_validation,
+            min_price_bump_percent,
+            max_evicted,
         }
     }
 
@@ -543,9 +561,139 @@ impl Dependency {
             // collision of other outputs is not possible.
         }
 
+        self.check_replacement(txs, tx, &collided)?;
+
         Ok((max_depth, db_coins, db_contracts, db_messages, collided))
     }
 
+    /// Check the replace-by-fee rules for the transaction colliding with `collided`:
+    /// 1. It bumps the gas price of every collided transaction by `min_price_bump_percent`.
+    /// 2. It evicts at most `max_evicted` transactions, counting the dependent ones.
+    /// 3. Its fee is higher than the sum of the fees of all evicted transactions.
+    fn check_replacement(
+        &self,
+        txs: &HashMap<TxId, TxInfo>,
+        tx: &ArcPoolTx,
+        collided: &[TxId],
+    ) -> anyhow::Result<()> {
+        if collided.is_empty() {
+            return Ok(())
+        }
+
+        for replaced in collided {
+            let replaced_tx = txs
+                .get(replaced)
+                .expect("Collided should be present in txpool");
+            let min_gas_price = self.min_replacement_price(replaced_tx.price());
+            if tx.price() < min_gas_price {
+                return Err(Error::NotInsertedReplacementUnderpriced {
+                    replaced: *replaced,
+                    min_gas_price,
+                }
+                .into())
+            }
+        }
+
+        let evicted = self.find_evicted(txs, collided)?;
+        let evicted_fee: u128 = evicted
+            .iter()
+            .map(|id| {
+                let evicted_tx = txs.get(id).expect("Tx should be present in txs");
+                fee(evicted_tx.tx())
+            })
+            .sum();
+        let fee = fee(tx);
+        if fee <= evicted_fee {
+            return Err(Error::NotInsertedReplacementFeeTooLow { fee, evicted_fee }.into())
+        }
+
+        Ok(())
+    }
+
+    /// The gas price required to replace the transaction with the `price`.
+    fn min_replacement_price(&self, price: GasPrice) -> GasPrice {
+        let price = price as u128;
+        let bump = (price * self.min_price_bump_percent as u128 + 99) / 100;
+        GasPrice::try_from(price + bump).unwrap_or(GasPrice::MAX)
+    }
+
+    /// Find the `collided` transactions and all transactions depending on them.
+    /// Fails if the number of found transactions exceeds `max_evicted`.
+    fn find_evicted(
+        &self,
+        txs: &HashMap<TxId, TxInfo>,
+        collided: &[TxId],
+    ) -> anyhow::Result<HashSet<TxId>> {
+        let mut evicted = HashSet::new();
+        let mut check = collided.to_vec();
+        while let Some(tx_id) = check.pop() {
+            if !evicted.insert(tx_id) {
+                continue
+            }
+
+            let tx = txs.get(&tx_id).expect("Tx should be present in txs");
+            for (index, output) in tx.outputs().iter().enumerate() {
+                match output {
+                    Output::Coin { .. }
+                    | Output::Change { .. }
+                    | Output::Variable { .. } => {
+                        let utxo = UtxoId::new(tx_id, index as u8);
+                        if let Some(spend_by) =
+                            self.coins.get(&utxo).and_then(|state| state.is_spend_by)
+                        {
+                            check.push(spend_by);
+                        }
+                    }
+                    Output::ContractCreated { contract_id, .. } => {
+                        if let Some(state) = self.contracts.get(contract_id) {
+                            check.extend(state.used_by.iter().copied());
+                        }
+                    }
+                    Output::Contract { .. } => {}
+                }
+            }
+        }
+
+        if evicted.len() > self.max_evicted {
+            return Err(Error::NotInsertedReplacementEvictsTooMany {
+                evicted: evicted.len(),
+                limit: self.max_evicted,
+            }
+            .into())
+        }
+
+        Ok(evicted)
+    }
+
+    /// Returns the transactions removed from txpool by the insertion of the `tx`:
+    /// the collided transactions and all transactions depending on them.
+    /// It doesn't change any dependency.
+    pub(crate) fn find_replaced<DB>(
+        &self,
+        txs: &HashMap<TxId, TxInfo>,
+        db: &DB,
+        tx: &ArcPoolTx,
+    ) -> anyhow::Result<Vec<ArcPoolTx>>
+    where
+        DB: TxPoolDb,
+    {
+        let (_, _, _, _, collided) = self.check_for_collision(txs, db, tx)?;
+        if collided.is_empty() {
+            return Ok(Vec::new())
+        }
+        let replaced = self
+            .find_evicted(txs, &collided)?
+            .into_iter()
+            .map(|id| {
+                txs.get(&id)
+                    .expect("Tx should be present in txs")
+                    .tx()
+                    .clone()
+            })
+            .collect();
+        Ok(replaced)
+    }
+
     /// insert tx inside dependency
     /// return list of transactions that are removed from txpool
     pub(crate) fn insert<'a, DB>(
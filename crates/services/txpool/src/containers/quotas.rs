@@ -0,0 +1,113 @@
+use crate::{
+    types::*,
+    Config,
+    Error,
+};
+use fuel_core_types::{
+    fuel_tx::Address,
+    services::txpool::{
+        ArcPoolTx,
+        Quota,
+        TxSource,
+    },
+};
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    hash::Hash,
+};
+
+/// Counts the pending transactions of each owner and each source
+/// to limit their share of the pool.
+#[derive(Debug, Default, Clone)]
+pub struct Quotas {
+    by_owner: HashMap<Address, usize>,
+    by_source: HashMap<TxSource, usize>,
+    sources: HashMap<TxId, TxSource>,
+}
+
+impl Quotas {
+    /// Checks that the owners and the source of the transaction
+    /// have not reached their limits from the `config`. The `replaced` transactions
+    /// are removed by the insertion, so they don't count.
+    pub fn check(
+        &self,
+        config: &Config,
+        tx: &PoolTransaction,
+        source: Option<&TxSource>,
+        replaced: &[ArcPoolTx],
+    ) -> Result<(), Error> {
+        if let Some(limit) = config.max_txs_per_owner {
+            for owner in owners(tx) {
+                let replaced = replaced
+                    .iter()
+                    .filter(|replaced| owners(replaced).contains(&owner))
+                    .count();
+                if count(&self.by_owner, &owner).saturating_sub(replaced) >= limit {
+                    return Err(Error::NotInsertedQuotaExceeded {
+                        quota: Quota::Owner(owner),
+                        limit,
+                    })
+                }
+            }
+        }
+
+        let limit = match source {
+            Some(TxSource::Client(_)) => config.max_txs_per_client,
+            Some(TxSource::Peer(_)) => config.max_txs_per_peer,
+            None => None,
+        };
+        if let (Some(source), Some(limit)) = (source, limit) {
+            let replaced = replaced
+                .iter()
+                .filter(|replaced| self.sources.get(&replaced.id()) == Some(source))
+                .count();
+            if count(&self.by_source, source).saturating_sub(replaced) >= limit {
+                return Err(Error::NotInsertedQuotaExceeded {
+                    quota: source.clone().into(),
+                    limit,
+                })
+            }
+        }
+        Ok(())
+    }
+
+    pub fn insert(&mut self, tx: &PoolTransaction, source: Option<TxSource>) {
+        for owner in owners(tx) {
+            *self.by_owner.entry(owner).or_default() += 1;
+        }
+        if let Some(source) = source {
+            *self.by_source.entry(source.clone()).or_default() += 1;
+            self.sources.insert(tx.id(), source);
+        }
+    }
+
+    pub fn remove(&mut self, tx: &PoolTransaction) {
+        for owner in owners(tx) {
+            decrement(&mut self.by_owner, &owner);
+        }
+        if let Some(source) = self.sources.remove(&tx.id()) {
+            decrement(&mut self.by_source, &source);
+        }
+    }
+}
+
+/// The transaction with several inputs of one owner counts once.
+fn owners(tx: &PoolTransaction) -> HashSet<Address> {
+    tx.senders().copied().collect()
+}
+
+fn count<K: Eq + Hash>(counts: &HashMap<K, usize>, key: &K) -> usize {
+    counts.get(key).copied().unwrap_or_default()
+}
+
+fn decrement<K: Eq + Hash>(counts: &mut HashMap<K, usize>, key: &K) {
+    if let Some(count) = counts.get_mut(key) {
+        *count -= 1;
+        if *count == 0 {
+            counts.remove(key);
+        }
+    }
+}
@@ -17,23 +17,15 @@ pub type TimeSort = Sort<TimeSortKey>;
 #[derive(Clone, Debug)]
 pub struct TimeSortKey {
     time: Duration,
-    created: tokio::time::Instant,
     tx_id: TxId,
 }
 
-impl TimeSortKey {
-    pub fn created(&self) -> &tokio::time::Instant {
-        &self.created
-    }
-}
-
 impl SortableKey for TimeSortKey {
     type Value = Duration;
 
     fn new(info: &TxInfo) -> Self {
         Self {
             time: info.submitted_time(),
-            created: info.created(),
             tx_id: info.tx().id(),
         }
     }
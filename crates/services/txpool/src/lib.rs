@@ -10,7 +10,7 @@ pub mod config;
 mod containers;
 pub mod ports;
 pub mod service;
-mod transaction_selector;
+pub mod transaction_selector;
 pub mod txpool;
 pub mod types;
 
@@ -38,21 +38,18 @@ fuel_core_trace::enable_tracing!();
 pub struct TxInfo {
     tx: ArcPoolTx,
     submitted_time: Duration,
-    creation_instant: tokio::time::Instant,
 }
 
 #[allow(missing_docs)]
 impl TxInfo {
     pub fn new(tx: ArcPoolTx) -> Self {
-        let since_epoch = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .expect("Now is bellow of the `UNIX_EPOCH`");
+        Self::with_submitted_time(tx, since_epoch())
+    }
 
-        Self {
-            tx,
-            submitted_time: since_epoch,
-            creation_instant: tokio::time::Instant::now(),
-        }
+    /// Creates the info of the transaction submitted at `submitted_time` since the `UNIX_EPOCH`,
+    /// like the transaction restored from the journal after the restart.
+    pub fn with_submitted_time(tx: ArcPoolTx, submitted_time: Duration) -> Self {
+        Self { tx, submitted_time }
     }
 
     pub fn tx(&self) -> &ArcPoolTx {
@@ -62,10 +59,13 @@ impl TxInfo {
     pub fn submitted_time(&self) -> Duration {
         self.submitted_time
     }
+}
 
-    pub fn created(&self) -> tokio::time::Instant {
-        self.creation_instant
-    }
+/// Returns the current time since the `UNIX_EPOCH`.
+pub(crate) fn since_epoch() -> Duration {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("Now is bellow of the `UNIX_EPOCH`")
 }
 
 impl Deref for TxInfo {
@@ -12,6 +12,8 @@ use fuel_core_types::{
     fuel_tx::{
         Contract,
         ContractId,
+        Transaction,
+        TxId,
         UtxoId,
     },
     fuel_types::Nonce,
@@ -25,6 +27,7 @@ use std::{
         Arc,
         Mutex,
     },
+    time::Duration,
 };
 
 #[derive(Default)]
@@ -33,6 +36,7 @@ pub struct Data {
     pub contracts: HashMap<ContractId, Contract>,
     pub messages: HashMap<Nonce, Message>,
     pub spent_messages: HashSet<Nonce>,
+    pub journal: HashMap<TxId, (Transaction, Duration)>,
 }
 
 #[derive(Clone, Default)]
@@ -93,4 +97,34 @@ impl TxPoolDb for MockDb {
     fn current_block_height(&self) -> StorageResult<BlockHeight> {
         Ok(Default::default())
     }
+
+    fn journaled_transactions(&self) -> StorageResult<Vec<(Transaction, Duration)>> {
+        Ok(self
+            .data
+            .lock()
+            .unwrap()
+            .journal
+            .values()
+            .cloned()
+            .collect())
+    }
+
+    fn journal_transaction(
+        &self,
+        tx_id: &TxId,
+        transaction: &Transaction,
+        submitted_time: Duration,
+    ) -> StorageResult<()> {
+        self.data
+            .lock()
+            .unwrap()
+            .journal
+            .insert(*tx_id, (transaction.clone(), submitted_time));
+        Ok(())
+    }
+
+    fn remove_journaled_transaction(&self, tx_id: &TxId) -> StorageResult<()> {
+        self.data.lock().unwrap().journal.remove(tx_id);
+        Ok(())
+    }
 }
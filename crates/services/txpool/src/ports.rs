@@ -8,6 +8,7 @@ use fuel_core_types::{
     },
     fuel_tx::{
         Transaction,
+        TxId,
         UtxoId,
     },
     fuel_types::{
@@ -23,7 +24,10 @@ use fuel_core_types::{
         },
     },
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 pub trait PeerToPeer: Send + Sync {
     type GossipedTransaction: NetworkData<Transaction>;
@@ -57,4 +61,19 @@ pub trait TxPoolDb: Send + Sync {
     fn is_message_spent(&self, message_id: &Nonce) -> StorageResult<bool>;
 
     fn current_block_height(&self) -> StorageResult<BlockHeight>;
+
+    /// Returns the transactions of the journal with the time of their submission
+    /// since the `UNIX_EPOCH`.
+    fn journaled_transactions(&self) -> StorageResult<Vec<(Transaction, Duration)>>;
+
+    /// Adds the transaction accepted by the pool to the journal.
+    fn journal_transaction(
+        &self,
+        tx_id: &TxId,
+        transaction: &Transaction,
+        submitted_time: Duration,
+    ) -> StorageResult<()>;
+
+    /// Removes the transaction that left the pool from the journal.
+    fn remove_journaled_transaction(&self, tx_id: &TxId) -> StorageResult<()>;
 }
@@ -4,7 +4,6 @@ use crate::{
         PeerToPeer,
         TxPoolDb,
     },
-    transaction_selector::select_transactions,
     Config,
     Error as TxPoolError,
     TxInfo,
@@ -20,6 +19,7 @@ use fuel_core_services::{
 use fuel_core_types::{
     blockchain::primitives::BlockHeight,
     fuel_tx::{
+        Address,
         Transaction,
         TxId,
         UniqueIdentifier,
@@ -37,6 +37,7 @@ use fuel_core_types::{
             ArcPoolTx,
             Error,
             InsertionResult,
+            TxSource,
             TxStatus,
         },
     },
@@ -70,13 +71,13 @@ impl TxStatusChange {
     pub fn send_complete(&self, id: Bytes32, block_height: &BlockHeight) {
         tracing::info!("Transaction {id} successfully included in block {block_height}");
         let _ = self.status_sender.send(TxStatus::Completed);
-        self.updated(id);
+        let _ = self.update_sender.send(TxUpdate::completed(id));
     }
 
     pub fn send_submitted(&self, id: Bytes32) {
         tracing::info!("Transaction {id} successfully submitted to the tx pool");
         let _ = self.status_sender.send(TxStatus::Submitted);
-        self.updated(id);
+        let _ = self.update_sender.send(TxUpdate::submitted(id));
     }
 
     pub fn send_squeezed_out(&self, id: Bytes32, reason: TxPoolError) {
@@ -86,10 +87,6 @@ impl TxStatusChange {
         });
         let _ = self.update_sender.send(TxUpdate::squeezed_out(id, reason));
     }
-
-    fn updated(&self, id: Bytes32) {
-        let _ = self.update_sender.send(TxUpdate::updated(id));
-    }
 }
 
 pub struct SharedState<P2P, DB> {
@@ -131,6 +128,10 @@ where
     }
 
     async fn into_task(mut self, _: &StateWatcher) -> anyhow::Result<Self::Task> {
+        let restored = self.shared.txpool.lock().restore_journal()?;
+        if restored > 0 {
+            tracing::info!("Restored {restored} transactions from the journal");
+        }
         self.ttl_timer.reset();
         Ok(self)
     }
@@ -178,7 +179,8 @@ where
                         .in_scope(|| {
                             self.shared.txpool.lock().insert(
                                 &self.shared.tx_status_sender,
-                                &txs
+                                &txs,
+                                Some(TxSource::Peer(peer_id.clone())),
                             )
                         });
 
@@ -210,8 +212,7 @@ where
     async fn shutdown(self) -> anyhow::Result<()> {
         // Nothing to shut down because we don't have any temporary state that should be dumped,
         // and we don't spawn any sub-tasks that we need to finish or await.
-        // Maybe we will save and load the previous list of transactions in the future to
-        // avoid losing them.
+        // The journaled transactions are already in the database.
         Ok(())
     }
 }
@@ -248,10 +249,34 @@ where
         self.txpool.lock().find_dependent(&ids)
     }
 
+    /// Returns the pending transactions the transaction depends on, or `None`
+    /// if the transaction is not in the pool.
+    pub fn find_dependencies(&self, id: TxId) -> Option<Vec<ArcPoolTx>> {
+        let txpool = self.txpool.lock();
+        txpool.find_one(&id)?;
+        let dependencies = txpool
+            .find_dependent(&[id])
+            .into_iter()
+            .filter(|tx| tx.id() != id)
+            .collect();
+        Some(dependencies)
+    }
+
+    pub fn find_by_owner(&self, owner: &Address) -> Vec<ArcPoolTx> {
+        self.txpool.lock().find_by_owner(owner)
+    }
+
+    pub fn sorted_includable(&self) -> Vec<ArcPoolTx> {
+        self.txpool.lock().sorted_includable()
+    }
+
+    pub fn sorted_by_time(&self) -> Vec<ArcPoolTx> {
+        self.txpool.lock().sorted_by_time()
+    }
+
     pub fn select_transactions(&self, max_gas: u64) -> Vec<ArcPoolTx> {
         let mut guard = self.txpool.lock();
-        let txs = guard.includable();
-        let sorted_txs = select_transactions(txs, max_gas);
+        let sorted_txs = guard.select_transactions(max_gas);
 
         for tx in sorted_txs.iter() {
             guard.remove_committed_tx(&tx.id());
@@ -281,8 +306,13 @@ where
     pub fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
+        source: Option<TxSource>,
     ) -> Vec<anyhow::Result<InsertionResult>> {
-        let insert = { self.txpool.lock().insert(&self.tx_status_sender, &txs) };
+        let insert = {
+            self.txpool
+                .lock()
+                .insert(&self.tx_status_sender, &txs, source)
+        };
 
         for (ret, tx) in insert.iter().zip(txs.into_iter()) {
             match ret {
@@ -306,21 +336,28 @@ where
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TxUpdate {
     tx_id: Bytes32,
-    squeezed_out: Option<TxPoolError>,
+    status: TxStatus,
 }
 
 impl TxUpdate {
-    pub fn updated(tx_id: Bytes32) -> Self {
+    pub fn submitted(tx_id: Bytes32) -> Self {
         Self {
             tx_id,
-            squeezed_out: None,
+            status: TxStatus::Submitted,
+        }
+    }
+
+    pub fn completed(tx_id: Bytes32) -> Self {
+        Self {
+            tx_id,
+            status: TxStatus::Completed,
         }
     }
 
     pub fn squeezed_out(tx_id: Bytes32, reason: TxPoolError) -> Self {
         Self {
             tx_id,
-            squeezed_out: Some(reason),
+            status: TxStatus::SqueezedOut { reason },
         }
     }
 
@@ -328,12 +365,20 @@ impl TxUpdate {
         &self.tx_id
     }
 
+    /// The status of the transaction after the update.
+    pub fn status(&self) -> &TxStatus {
+        &self.status
+    }
+
     pub fn was_squeezed_out(&self) -> bool {
-        self.squeezed_out.is_some()
+        matches!(self.status, TxStatus::SqueezedOut { .. })
     }
 
     pub fn into_squeezed_out_reason(self) -> Option<TxPoolError> {
-        self.squeezed_out
+        match self.status {
+            TxStatus::SqueezedOut { reason } => Some(reason),
+            _ => None,
+        }
     }
 }
 
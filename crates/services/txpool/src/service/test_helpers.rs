@@ -1,6 +1,7 @@
 use super::*;
 use crate::{
     ports::BlockImporter,
+    test_helpers::UnsetInput,
     MockDb,
 };
 use fuel_core_services::{
@@ -16,6 +17,7 @@ use fuel_core_types::{
     },
     fuel_tx::{
         Input,
+        Output,
         Transaction,
         TransactionBuilder,
         Word,
@@ -53,6 +55,10 @@ impl TestContext {
     pub fn setup_coin(&self) -> (Coin, Input) {
         crate::test_helpers::setup_coin(&mut self.rng.borrow_mut(), Some(&self.mock_db))
     }
+
+    pub fn create_output_and_input(&self, amount: Word) -> (Output, UnsetInput) {
+        crate::test_helpers::create_output_and_input(&mut self.rng.borrow_mut(), amount)
+    }
 }
 
 mockall::mock! {
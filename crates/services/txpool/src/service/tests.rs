@@ -5,7 +5,11 @@ use crate::service::test_helpers::{
 };
 use fuel_core_services::Service as ServiceTrait;
 use fuel_core_types::{
-    fuel_tx::UniqueIdentifier,
+    fuel_tx::{
+        TransactionBuilder,
+        UniqueIdentifier,
+        UtxoId,
+    },
     services::txpool::Error as TxpoolError,
 };
 use std::time::Duration;
@@ -33,7 +37,7 @@ async fn test_find() {
 
     let service = ctx.service();
 
-    let out = service.shared.insert(vec![tx1.clone(), tx2.clone()]);
+    let out = service.shared.insert(vec![tx1.clone(), tx2.clone()], None);
 
     assert_eq!(out.len(), 2, "Should be len 2:{out:?}");
     assert!(out[0].is_ok(), "Tx1 should be OK, got err:{out:?}");
@@ -68,7 +72,7 @@ async fn test_prune_transactions() {
 
     let out = service
         .shared
-        .insert(vec![tx1.clone(), tx2.clone(), tx3.clone()]);
+        .insert(vec![tx1.clone(), tx2.clone(), tx3.clone()], None);
 
     // Check that we have all transactions after insertion.
     assert_eq!(out.len(), 3, "Should be len 3:{out:?}");
@@ -113,11 +117,11 @@ async fn test_prune_transactions_the_oldest() {
 
     let service = ctx.service();
 
-    let out = service.shared.insert(vec![tx1.clone()]);
+    let out = service.shared.insert(vec![tx1.clone()], None);
     assert!(out[0].is_ok(), "Tx1 should be OK, got err:{out:?}");
 
     tokio::time::sleep(Duration::from_secs(TIMEOUT - DELAY)).await;
-    let out = service.shared.insert(vec![tx2.clone()]);
+    let out = service.shared.insert(vec![tx2.clone()], None);
     assert!(out[0].is_ok(), "Tx2 should be OK, got err:{out:?}");
 
     let out = service.shared.find(vec![tx1.id(), tx2.id(), tx3.id()]);
@@ -125,7 +129,7 @@ async fn test_prune_transactions_the_oldest() {
     assert!(out[1].is_some(), "Tx2 should exist");
 
     tokio::time::sleep(Duration::from_secs(TIMEOUT)).await;
-    let out = service.shared.insert(vec![tx3.clone()]);
+    let out = service.shared.insert(vec![tx3.clone()], None);
     assert!(out[0].is_ok(), "Tx3 should be OK, got err:{out:?}");
 
     let out = service.shared.find(vec![tx1.id(), tx2.id(), tx3.id()]);
@@ -154,7 +158,7 @@ async fn simple_insert_removal_subscription() {
     let mut subscribe_status = service.shared.tx_status_subscribe();
     let mut subscribe_update = service.shared.tx_update_subscribe();
 
-    let out = service.shared.insert(vec![tx1.clone(), tx2.clone()]);
+    let out = service.shared.insert(vec![tx1.clone(), tx2.clone()], None);
 
     if let Ok(tx) = &out[0] {
         assert_eq!(
@@ -223,3 +227,53 @@ async fn simple_insert_removal_subscription() {
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn removal_of_dependencies_is_notified() {
+    let ctx = TestContext::new().await;
+
+    let (_, gas_coin) = ctx.setup_coin();
+    let (output, unset_input) = ctx.create_output_and_input(1);
+    let tx1 = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(10)
+            .gas_limit(1000)
+            .add_input(gas_coin)
+            .add_output(output)
+            .finalize_as_transaction(),
+    );
+    let (_, gas_coin) = ctx.setup_coin();
+    let input = unset_input.into_input(UtxoId::new(tx1.id(), 0));
+    let tx2 = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(10)
+            .gas_limit(1000)
+            .add_input(input)
+            .add_input(gas_coin)
+            .finalize_as_transaction(),
+    );
+
+    let service = ctx.service();
+    let out = service.shared.insert(vec![tx1.clone(), tx2.clone()], None);
+    assert!(out.iter().all(Result::is_ok), "{out:?}");
+
+    let mut subscribe_update = service.shared.tx_update_subscribe();
+    let removed = service.shared.remove(vec![tx1.id()]);
+    assert_eq!(removed.len(), 2, "Tx2 should be removed with tx1");
+
+    for tx in [&tx1, &tx2] {
+        let update =
+            tokio::time::timeout(Duration::from_secs(2), subscribe_update.recv())
+                .await
+                .unwrap()
+                .unwrap();
+        assert_eq!(*update.tx_id(), tx.id());
+        assert!(update.was_squeezed_out());
+    }
+
+    // The unknown transactions are not notified.
+    assert!(service.shared.remove(vec![tx1.id()]).is_empty());
+    assert!(subscribe_update.try_recv().is_err());
+
+    service.stop_and_await().await.unwrap();
+}
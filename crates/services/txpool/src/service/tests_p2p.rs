@@ -59,7 +59,7 @@ async fn insert_from_local_broadcasts_to_p2p() {
     let mut subscribe_status = service.shared.tx_status_subscribe();
     let mut subscribe_update = service.shared.tx_update_subscribe();
 
-    let out = service.shared.insert(vec![Arc::new(tx1.clone())]);
+    let out = service.shared.insert(vec![Arc::new(tx1.clone())], None);
 
     if let Ok(result) = &out[0] {
         // we are sure that included tx are already broadcasted.
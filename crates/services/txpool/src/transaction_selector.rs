@@ -1,11 +1,185 @@
+//! The strategies of the block producer selecting the transactions from the pool
+//! for the next block.
+
+use crate::TxInfo;
 use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        ContractId,
+        Output,
+        TxId,
+    },
     fuel_types::Word,
     services::txpool::ArcPoolTx,
 };
-use std::cmp::Reverse;
+use std::{
+    cmp::Reverse,
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    fmt::Debug,
+};
+
+/// The strategy of the block building. The block producer may customize the
+/// block building with its own strategy, e.g. with alternative priorities
+/// besides gas fees.
+pub trait TransactionSelectionStrategy: Debug + Send + Sync {
+    /// Selects the transactions that fit into `max_gas` from the includable
+    /// transactions of the pool. The transactions are returned in the order
+    /// of their inclusion into the block, the dependent transactions after
+    /// the transactions they depend on.
+    fn select(&self, includable_txs: Vec<TxInfo>, max_gas: u64) -> Vec<ArcPoolTx>;
+}
+
+/// Selects the transactions with the highest gas price first.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GasPriceStrategy;
+
+impl TransactionSelectionStrategy for GasPriceStrategy {
+    fn select(&self, includable_txs: Vec<TxInfo>, max_gas: u64) -> Vec<ArcPoolTx> {
+        select_transactions(
+            includable_txs
+                .into_iter()
+                .map(|info| info.tx().clone())
+                .collect(),
+            max_gas,
+        )
+    }
+}
+
+/// Selects the transactions in the order of their arrival into the pool.
+/// The transaction pulls in the transactions it depends on, or is skipped
+/// if those don't fit.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FifoStrategy;
+
+impl TransactionSelectionStrategy for FifoStrategy {
+    fn select(&self, mut includable_txs: Vec<TxInfo>, max_gas: u64) -> Vec<ArcPoolTx> {
+        includable_txs.sort_by_key(|info| info.submitted_time());
+        let txs: Vec<ArcPoolTx> = includable_txs
+            .into_iter()
+            .map(|info| info.tx().clone())
+            .collect();
+        let dependencies = Dependencies::new(&txs);
+        fill_block_with_dependencies(txs, &dependencies, max_gas)
+    }
+}
+
+/// Selects the set of transactions with the maximum sum of fees(`price * max_gas`).
+///
+/// The gas of the transactions is measured in the units of `max_gas / resolution`
+/// rounded up, so the selection is optimal up to that granularity. The gas left
+/// unused because of the rounding is filled with the highest gas price transactions.
+/// The solution takes `O(transactions * resolution)` time and memory.
+#[derive(Debug, Clone, Copy)]
+pub struct KnapsackStrategy {
+    /// The number of the gas units the block is split into.
+    pub resolution: usize,
+}
+
+impl Default for KnapsackStrategy {
+    fn default() -> Self {
+        Self { resolution: 1000 }
+    }
+}
+
+impl TransactionSelectionStrategy for KnapsackStrategy {
+    fn select(&self, includable_txs: Vec<TxInfo>, max_gas: u64) -> Vec<ArcPoolTx> {
+        let txs: Vec<ArcPoolTx> = includable_txs
+            .into_iter()
+            .map(|info| info.tx().clone())
+            .collect();
+        let dependencies = Dependencies::new(&txs);
+        let mut txs: Vec<ArcPoolTx> = txs
+            .into_iter()
+            .filter(|tx| tx.max_gas() <= max_gas)
+            .collect();
+        txs.sort_by_key(|tx| Reverse(tx.price()));
+
+        let resolution = self.resolution.max(1) as u64;
+        let unit = div_ceil(max_gas, resolution).max(1);
+        let capacity = (max_gas / unit) as usize;
+        let weights: Vec<usize> = txs
+            .iter()
+            .map(|tx| div_ceil(tx.max_gas(), unit) as usize)
+            .collect();
+
+        // `best[c]` is the maximum fee of the transactions fitting into `c` units,
+        // `taken[i][c]` tracks whether the transaction `i` is a part of that solution.
+        let mut best = vec![0u128; capacity + 1];
+        let mut taken = vec![false; txs.len() * (capacity + 1)];
+        for (i, (tx, weight)) in txs.iter().zip(weights.iter()).enumerate() {
+            let fee = tx.price() as u128 * tx.max_gas() as u128;
+            for c in (*weight..=capacity).rev() {
+                if best[c - weight] + fee > best[c] {
+                    best[c] = best[c - weight] + fee;
+                    taken[i * (capacity + 1) + c] = true;
+                }
+            }
+        }
+
+        let mut selected = vec![false; txs.len()];
+        let mut c = capacity;
+        for i in (0..txs.len()).rev() {
+            if taken[i * (capacity + 1) + c] {
+                selected[i] = true;
+                c -= weights[i];
+            }
+        }
+
+        // The chosen transactions pull in the transactions they depend on,
+        // or are skipped if those don't fit.
+        let (chosen, rest): (Vec<_>, Vec<_>) = txs
+            .into_iter()
+            .zip(selected)
+            .partition(|(_, selected)| *selected);
+        fill_block_with_dependencies(
+            chosen.into_iter().chain(rest).map(|(tx, _)| tx),
+            &dependencies,
+            max_gas,
+        )
+    }
+}
+
+fn div_ceil(a: u64, b: u64) -> u64 {
+    a / b + u64::from(a % b != 0)
+}
+
+/// Selects the transactions of the whitelisted senders first. The senders of
+/// the transaction are the owners of its coins and the recipients of its messages.
+/// The transactions within each lane are selected by the highest gas price.
+/// The prioritized transactions pull in the transactions they depend on from any lane.
+#[derive(Debug, Default, Clone)]
+pub struct PriorityLanesStrategy {
+    /// The whitelisted senders.
+    pub senders: HashSet<Address>,
+}
+
+impl PriorityLanesStrategy {
+    fn is_prioritized(&self, tx: &ArcPoolTx) -> bool {
+        tx.senders().any(|sender| self.senders.contains(sender))
+    }
+}
 
-// transaction selection could use a plugin based approach in the
-// future for block producers to customize block building (e.g. alternative priorities besides gas fees)
+impl TransactionSelectionStrategy for PriorityLanesStrategy {
+    fn select(&self, includable_txs: Vec<TxInfo>, max_gas: u64) -> Vec<ArcPoolTx> {
+        let txs: Vec<ArcPoolTx> = includable_txs
+            .into_iter()
+            .map(|info| info.tx().clone())
+            .collect();
+        let dependencies = Dependencies::new(&txs);
+        let (mut prioritized, mut rest): (Vec<_>, Vec<_>) =
+            txs.into_iter().partition(|tx| self.is_prioritized(tx));
+        prioritized.sort_by_key(|tx| Reverse(tx.price()));
+        rest.sort_by_key(|tx| Reverse(tx.price()));
+        fill_block_with_dependencies(
+            prioritized.into_iter().chain(rest),
+            &dependencies,
+            max_gas,
+        )
+    }
+}
 
 pub fn select_transactions(
     mut includable_txs: Vec<ArcPoolTx>,
@@ -15,14 +189,18 @@ pub fn select_transactions(
     //
     // Future improvements to this algorithm may take into account the parallel nature of
     // transactions to maximize throughput.
-    let mut used_block_space: Word = 0;
 
     // Sort transactions by gas price, highest first
     includable_txs.sort_by_key(|a| Reverse(a.price()));
 
-    // Pick as many transactions as we can fit into the block (greedy)
-    includable_txs
-        .into_iter()
+    fill_block(includable_txs, max_gas)
+}
+
+/// Picks as many transactions in the given order as we can fit into the block (greedy).
+fn fill_block(txs: impl IntoIterator<Item = ArcPoolTx>, max_gas: u64) -> Vec<ArcPoolTx> {
+    let mut used_block_space: Word = 0;
+
+    txs.into_iter()
         .filter(|tx| {
             let tx_block_space = tx.max_gas();
             if let Some(new_used_space) = used_block_space.checked_add(tx_block_space) {
@@ -39,6 +217,107 @@ pub fn select_transactions(
         .collect()
 }
 
+/// The dependencies between the includable transactions. The transaction depends
+/// on the transactions creating its coins and contracts.
+struct Dependencies {
+    txs: HashMap<TxId, ArcPoolTx>,
+    contract_creators: HashMap<ContractId, TxId>,
+}
+
+impl Dependencies {
+    fn new(txs: &[ArcPoolTx]) -> Self {
+        let mut contract_creators = HashMap::new();
+        for tx in txs {
+            for output in tx.outputs() {
+                if let Output::ContractCreated { contract_id, .. } = output {
+                    contract_creators.insert(*contract_id, tx.id());
+                }
+            }
+        }
+        Self {
+            txs: txs.iter().map(|tx| (tx.id(), tx.clone())).collect(),
+            contract_creators,
+        }
+    }
+
+    /// The includable transactions that the `tx` directly depends on.
+    fn parents(&self, tx: &ArcPoolTx) -> Vec<&ArcPoolTx> {
+        let mut parents = Vec::new();
+        for input in tx.inputs() {
+            if let Some(utxo_id) = input.utxo_id() {
+                parents.extend(self.txs.get(utxo_id.tx_id()));
+            }
+            if let Some(contract_id) = input.contract_id() {
+                parents.extend(
+                    self.contract_creators
+                        .get(contract_id)
+                        .and_then(|creator| self.txs.get(creator)),
+                );
+            }
+        }
+        parents
+    }
+
+    /// Returns the `tx` with all its ancestors that are not `included` yet,
+    /// the ancestors first.
+    fn with_missing_ancestors(
+        &self,
+        tx: &ArcPoolTx,
+        included: &HashSet<TxId>,
+    ) -> Vec<ArcPoolTx> {
+        let mut visited = HashSet::new();
+        let mut chain = Vec::new();
+        self.visit(tx, included, &mut visited, &mut chain);
+        chain
+    }
+
+    fn visit(
+        &self,
+        tx: &ArcPoolTx,
+        included: &HashSet<TxId>,
+        visited: &mut HashSet<TxId>,
+        chain: &mut Vec<ArcPoolTx>,
+    ) {
+        if included.contains(&tx.id()) || !visited.insert(tx.id()) {
+            return
+        }
+        for parent in self.parents(tx) {
+            self.visit(parent, included, visited, chain);
+        }
+        chain.push(tx.clone());
+    }
+}
+
+/// Picks the transactions in the given order as long as they fit into the block,
+/// together with the transactions they depend on, which are placed before them.
+/// The transaction is skipped if it doesn't fit together with its dependencies.
+fn fill_block_with_dependencies(
+    txs: impl IntoIterator<Item = ArcPoolTx>,
+    dependencies: &Dependencies,
+    max_gas: u64,
+) -> Vec<ArcPoolTx> {
+    let mut used_block_space: Word = 0;
+    let mut included = HashSet::new();
+    let mut selected = Vec::new();
+
+    for tx in txs {
+        let chain = dependencies.with_missing_ancestors(&tx, &included);
+        let new_used_space = chain
+            .iter()
+            .try_fold(used_block_space, |used, tx| used.checked_add(tx.max_gas()));
+        match new_used_space {
+            Some(new_used_space) if new_used_space <= max_gas => {
+                used_block_space = new_used_space;
+                included.extend(chain.iter().map(|tx| tx.id()));
+                selected.extend(chain);
+            }
+            _ => {}
+        }
+    }
+
+    selected
+}
+
 #[cfg(test)]
 mod tests {
     use fuel_core_txpool as _;
@@ -55,11 +334,15 @@ mod tests {
             ConsensusParameters,
             Output,
             TransactionBuilder,
+            UtxoId,
         },
         fuel_vm::checked_transaction::builder::TransactionBuilderExt,
     };
     use itertools::Itertools;
-    use std::sync::Arc;
+    use std::{
+        sync::Arc,
+        time::Duration,
+    };
 
     use super::*;
 
@@ -69,48 +352,59 @@ mod tests {
         pub limit: u64,
     }
 
-    /// A test helper that generates set of txs with given gas prices and limits and runs
-    /// `select_transactions` against that, returning the list of selected gas price, limit pairs
-    fn make_txs_and_select(txs: &[TxGas], block_gas_limit: Word) -> Vec<TxGas> {
+    /// A test helper that generates set of txs with given gas prices and limits
+    fn make_txs(txs: &[TxGas]) -> Vec<ArcPoolTx> {
         let mut rng = thread_rng();
 
-        let txs = txs
-            .iter()
-            .map(|tx_gas| {
-                TransactionBuilder::script(
-                    vec![op::ret(RegId::ONE)].into_iter().collect(),
-                    vec![],
-                )
-                .gas_price(tx_gas.price)
-                .gas_limit(tx_gas.limit)
-                .add_unsigned_coin_input(
-                    rng.gen(),
-                    rng.gen(),
-                    1_000_000,
-                    Default::default(),
-                    Default::default(),
-                    0,
-                )
-                .add_output(Output::Change {
-                    to: Default::default(),
-                    amount: 0,
-                    asset_id: Default::default(),
-                })
-                // The block producer assumes transactions are already checked
-                // so it doesn't need to compute valid sigs for tests
-                .finalize_checked_basic(
-                    0,
-                    &ConsensusParameters {
-                        gas_price_factor: 1,
-                        ..ConsensusParameters::default()
-                    },
-                ).into()
-            })
-            .map(Arc::new)
-            .collect();
+        txs.iter()
+            .map(|tx_gas| make_tx(tx_gas, rng.gen()))
+            .collect()
+    }
 
-        select_transactions(txs, block_gas_limit)
-            .into_iter()
+    /// A test helper that generates the tx spending the coin `utxo_id`
+    fn make_tx(tx_gas: &TxGas, utxo_id: UtxoId) -> ArcPoolTx {
+        let tx = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .gas_price(tx_gas.price)
+        .gas_limit(tx_gas.limit)
+        .add_unsigned_coin_input(
+            thread_rng().gen(),
+            utxo_id,
+            1_000_000,
+            Default::default(),
+            Default::default(),
+            0,
+        )
+        .add_output(Output::Change {
+            to: Default::default(),
+            amount: 0,
+            asset_id: Default::default(),
+        })
+        // The block producer assumes transactions are already checked
+        // so it doesn't need to compute valid sigs for tests
+        .finalize_checked_basic(
+            0,
+            &ConsensusParameters {
+                gas_price_factor: 1,
+                ..ConsensusParameters::default()
+            },
+        )
+        .into();
+        Arc::new(tx)
+    }
+
+    /// Wraps the txs into the pool infos, submitted in the order of the txs
+    fn make_infos(txs: Vec<ArcPoolTx>) -> Vec<TxInfo> {
+        txs.into_iter()
+            .enumerate()
+            .map(|(i, tx)| TxInfo::with_submitted_time(tx, Duration::from_secs(i as u64)))
+            .collect()
+    }
+
+    fn gas(txs: Vec<ArcPoolTx>) -> Vec<TxGas> {
+        txs.into_iter()
             .map(|tx| TxGas {
                 limit: tx.limit(),
                 price: tx.price(),
@@ -118,6 +412,12 @@ mod tests {
             .collect()
     }
 
+    /// A test helper that generates set of txs with given gas prices and limits and runs
+    /// `select_transactions` against that, returning the list of selected gas price, limit pairs
+    fn make_txs_and_select(txs: &[TxGas], block_gas_limit: Word) -> Vec<TxGas> {
+        gas(select_transactions(make_txs(txs), block_gas_limit))
+    }
+
     #[test]
     fn selector_works_with_empty_input() {
         let selected = make_txs_and_select(&[], 1_000_000);
@@ -200,4 +500,154 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn fifo_selects_txs_in_arrival_order() {
+        #[rustfmt::skip]
+        let original = [
+            TxGas { price: 3, limit: 2000 },
+            TxGas { price: 1, limit: 1000 },
+            TxGas { price: 4, limit: 3000 },
+        ];
+        let txs = make_txs(&original);
+        let block_gas_limit = txs[0].max_gas() + txs[1].max_gas();
+
+        let selected = FifoStrategy.select(make_infos(txs.clone()), Word::MAX);
+        assert_eq!(gas(selected), original.to_vec());
+
+        let selected = FifoStrategy.select(make_infos(txs), block_gas_limit);
+        assert_eq!(gas(selected), original[..2].to_vec());
+    }
+
+    #[test]
+    fn knapsack_maximizes_fees() {
+        #[rustfmt::skip]
+        let original = [
+            TxGas { price: 10, limit: 6000 },
+            TxGas { price: 9, limit: 5000 },
+            TxGas { price: 9, limit: 5000 },
+        ];
+        let txs = make_txs(&original);
+        let block_gas_limit = txs[1].max_gas() + txs[2].max_gas();
+
+        // The greedy selection takes the highest price tx, and the rest don't fit.
+        let selected = GasPriceStrategy.select(make_infos(txs.clone()), block_gas_limit);
+        assert_eq!(gas(selected), original[..1].to_vec());
+
+        let strategy = KnapsackStrategy {
+            resolution: block_gas_limit as usize,
+        };
+        let selected = strategy.select(make_infos(txs), block_gas_limit);
+        assert_eq!(gas(selected), original[1..].to_vec());
+    }
+
+    #[test]
+    fn knapsack_doesnt_exceed_max_gas_per_block() {
+        #[rustfmt::skip]
+        let original = [
+            TxGas { price: 3, limit: 2000 },
+            TxGas { price: 1, limit: 1000 },
+            TxGas { price: 4, limit: 3000 },
+            TxGas { price: 5, limit: 1000 },
+            TxGas { price: 2, limit: 1000 },
+        ];
+
+        for resolution in [1, 3, 1000] {
+            let strategy = KnapsackStrategy { resolution };
+            for gas_limit in [999, 1000, 2000, 2500, 3000, 5000, 6000, 10_000] {
+                let selected =
+                    strategy.select(make_infos(make_txs(&original)), gas_limit);
+                let total_gas: Word = selected.iter().map(|tx| tx.max_gas()).sum();
+                assert!(total_gas <= gas_limit);
+            }
+        }
+    }
+
+    #[test]
+    fn priority_lanes_select_whitelisted_senders_first() {
+        #[rustfmt::skip]
+        let original = [
+            TxGas { price: 5, limit: 1000 },
+            TxGas { price: 1, limit: 1000 },
+            TxGas { price: 3, limit: 1000 },
+        ];
+        let txs = make_txs(&original);
+        let sender = *txs[1].inputs()[0].input_owner().unwrap();
+        let strategy = PriorityLanesStrategy {
+            senders: [sender].into_iter().collect(),
+        };
+
+        let selected = strategy.select(make_infos(txs.clone()), Word::MAX);
+        assert_eq!(gas(selected), vec![original[1], original[0], original[2]]);
+
+        let selected = strategy.select(make_infos(txs.clone()), txs[1].max_gas());
+        assert_eq!(gas(selected), vec![original[1]]);
+    }
+
+    #[test]
+    fn dependent_txs_are_selected_after_their_parents() {
+        #[rustfmt::skip]
+        let original = [
+            TxGas { price: 1, limit: 1000 },
+            TxGas { price: 10, limit: 1000 },
+            TxGas { price: 5, limit: 1000 },
+        ];
+        let parent = make_tx(&original[0], thread_rng().gen());
+        let child = make_tx(&original[1], UtxoId::new(parent.id(), 0));
+        let other = make_tx(&original[2], thread_rng().gen());
+        let txs = vec![child.clone(), other.clone(), parent.clone()];
+        let max_gas = parent.max_gas();
+        let child_sender = *child.inputs()[0].input_owner().unwrap();
+
+        let priority_lanes = PriorityLanesStrategy {
+            senders: [child_sender].into_iter().collect(),
+        };
+        // The prioritized child pulls in its parent.
+        let selected = priority_lanes.select(make_infos(txs.clone()), Word::MAX);
+        assert_eq!(gas(selected), original.to_vec());
+        let selected = priority_lanes.select(make_infos(txs.clone()), 2 * max_gas);
+        assert_eq!(gas(selected), original[..2].to_vec());
+        // The child is skipped if its parent doesn't fit.
+        let selected = priority_lanes.select(make_infos(txs.clone()), max_gas);
+        assert_eq!(gas(selected), original[2..].to_vec());
+
+        let knapsack = KnapsackStrategy {
+            resolution: max_gas as usize,
+        };
+        for block_gas_limit in [max_gas, 2 * max_gas, 3 * max_gas] {
+            let selected = knapsack.select(make_infos(txs.clone()), block_gas_limit);
+            let parent_position = selected.iter().position(|tx| tx.id() == parent.id());
+            let child_position = selected.iter().position(|tx| tx.id() == child.id());
+            if let Some(child_position) = child_position {
+                assert!(parent_position.unwrap() < child_position);
+            }
+            let total_gas: Word = selected.iter().map(|tx| tx.max_gas()).sum();
+            assert!(total_gas <= block_gas_limit);
+        }
+    }
+
+    #[test]
+    fn fifo_skips_dependent_txs_if_their_parents_dont_fit() {
+        #[rustfmt::skip]
+        let original = [
+            TxGas { price: 1, limit: 5000 },
+            TxGas { price: 1, limit: 1000 },
+            TxGas { price: 1, limit: 1000 },
+        ];
+        let parent = make_tx(&original[0], thread_rng().gen());
+        let child = make_tx(&original[1], UtxoId::new(parent.id(), 0));
+        let other = make_tx(&original[2], thread_rng().gen());
+        let txs = vec![parent.clone(), child.clone(), other.clone()];
+        let block_gas_limit = child.max_gas() + other.max_gas();
+        assert!(parent.max_gas() > block_gas_limit);
+
+        // The parent is too big for the block, so the child is skipped with it.
+        let selected = FifoStrategy.select(make_infos(txs.clone()), block_gas_limit);
+        assert_eq!(gas(selected), original[2..].to_vec());
+
+        // The child arriving before its parent is placed after it.
+        let txs = vec![child, parent, other];
+        let selected = FifoStrategy.select(make_infos(txs), Word::MAX);
+        assert_eq!(gas(selected), original.to_vec());
+    }
 }
@@ -2,19 +2,24 @@ use crate::{
     containers::{
         dependency::Dependency,
         price_sort::PriceSort,
+        quotas::Quotas,
+        sort::SortableKey,
         time_sort::TimeSort,
     },
     ports::TxPoolDb,
     service::TxStatusChange,
+    since_epoch,
     types::*,
     Config,
     Error,
     TxInfo,
 };
 use fuel_core_metrics::txpool_metrics::TXPOOL_METRICS;
+use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
     blockchain::SealedBlock,
     fuel_tx::{
+        Address,
         Chargeable,
         Transaction,
         UniqueIdentifier,
@@ -26,6 +31,7 @@ use fuel_core_types::{
     services::txpool::{
         ArcPoolTx,
         InsertionResult,
+        TxSource,
     },
 };
 use std::{
@@ -33,6 +39,7 @@ use std::{
     collections::HashMap,
     ops::Deref,
     sync::Arc,
+    time::Duration,
 };
 
 #[derive(Debug, Clone)]
@@ -41,8 +48,13 @@ pub struct TxPool<DB> {
     by_gas_price: PriceSort,
     by_time: TimeSort,
     by_dependency: Dependency,
+    quotas: Quotas,
     config: Config,
     database: DB,
+    /// The time since the `UNIX_EPOCH` at the creation of the pool.
+    created_at: Duration,
+    /// The creation of the pool by the tokio clock, which measures the time after it.
+    created: tokio::time::Instant,
 }
 
 impl<DB> TxPool<DB>
@@ -56,12 +68,26 @@ where
             by_hash: HashMap::new(),
             by_gas_price: PriceSort::default(),
             by_time: TimeSort::default(),
-            by_dependency: Dependency::new(max_depth, config.utxo_validation),
+            by_dependency: Dependency::new(
+                max_depth,
+                config.utxo_validation,
+                config.min_price_bump_percent,
+                config.max_evicted_by_replacement,
+            ),
+            quotas: Quotas::default(),
             config,
             database,
+            created_at: since_epoch(),
+            created: tokio::time::Instant::now(),
         }
     }
 
+    /// Returns the current time since the `UNIX_EPOCH`. The time passed after the creation
+    /// of the pool is measured by the tokio clock, so the tests may control it.
+    fn since_epoch(&self) -> Duration {
+        self.created_at + self.created.elapsed()
+    }
+
     pub fn txs(&self) -> &HashMap<TxId, TxInfo> {
         &self.by_hash
     }
@@ -70,12 +96,20 @@ where
         &self.by_dependency
     }
 
+    #[cfg(test)]
+    fn insert_inner(&mut self, tx: Arc<Transaction>) -> anyhow::Result<InsertionResult> {
+        self.insert_submitted_at(tx, self.since_epoch(), None)
+    }
+
     #[tracing::instrument(level = "info", skip_all, fields(tx_id = %tx.id()), ret, err)]
-    // this is atomic operation. Return removed(pushed out/replaced) transactions
-    fn insert_inner(
+    // this is atomic operation. Return removed(pushed out/replaced) transactions.
+    // Inserts the transaction of the `source` submitted at `submitted_time` since the `UNIX_EPOCH`.
+    fn insert_submitted_at(
         &mut self,
         // TODO: Pass `&Transaction`
         tx: Arc<Transaction>,
+        submitted_time: Duration,
+        source: Option<TxSource>,
     ) -> anyhow::Result<InsertionResult> {
         let current_height = self.database.current_block_height()?;
 
@@ -128,6 +162,12 @@ where
             return Err(Error::NotInsertedTxKnown.into())
         }
 
+        let replaced =
+            self.by_dependency
+                .find_replaced(&self.by_hash, &self.database, &tx)?;
+        self.quotas
+            .check(&self.config, &tx, source.as_ref(), &replaced)?;
+
         let mut max_limit_hit = false;
         // check if we are hitting limit of pool
         if self.by_hash.len() >= self.config.max_tx {
@@ -151,10 +191,20 @@ where
         let rem = self
             .by_dependency
             .insert(&self.by_hash, &self.database, &tx)?;
-        let info = TxInfo::new(tx.clone());
+        let info = TxInfo::with_submitted_time(tx.clone(), submitted_time);
         self.by_gas_price.insert(&info);
         self.by_time.insert(&info);
         self.by_hash.insert(tx.id(), info);
+        self.quotas.insert(&tx, source);
+        if self.config.journal {
+            let transaction: Transaction = tx.as_ref().into();
+            if let Err(e) =
+                self.database
+                    .journal_transaction(&tx.id(), &transaction, submitted_time)
+            {
+                tracing::warn!("Failed to journal the transaction {}: {e}", tx.id());
+            }
+        }
 
         // if some transaction were removed so we don't need to check limit
         let removed = if rem.is_empty() {
@@ -191,6 +241,23 @@ where
             .collect()
     }
 
+    /// Return all transactions sorted by the time of submission, starting from the oldest.
+    pub fn sorted_by_time(&self) -> Vec<ArcPoolTx> {
+        self.by_time.sort.values().cloned().collect()
+    }
+
+    /// find all transactions spending the coins or messages of the `owner`,
+    /// sorted by the time of submission.
+    pub fn find_by_owner(&self, owner: &Address) -> Vec<ArcPoolTx> {
+        let mut txs: Vec<_> = self
+            .txs()
+            .values()
+            .filter(|info| info.senders().any(|sender| sender == owner))
+            .collect();
+        txs.sort_by_key(|info| info.submitted_time());
+        txs.into_iter().map(|info| info.tx().clone()).collect()
+    }
+
     pub fn remove_inner(&mut self, tx: &ArcPoolTx) -> Vec<ArcPoolTx> {
         self.remove_by_tx_id(&tx.id())
     }
@@ -215,6 +282,14 @@ where
         if let Some(info) = &info {
             self.by_time.remove(info);
             self.by_gas_price.remove(info);
+            self.quotas.remove(info.tx());
+            if self.config.journal {
+                if let Err(e) = self.database.remove_journaled_transaction(tx_id) {
+                    tracing::warn!(
+                        "Failed to remove the transaction {tx_id} from the journal: {e}"
+                    );
+                }
+            }
         }
 
         info
@@ -249,16 +324,22 @@ where
 
     #[tracing::instrument(level = "info", skip_all)]
     /// Import a set of transactions from network gossip or GraphQL endpoints.
+    /// The transactions of the `source` are limited by its quota from the config.
     pub fn insert(
         &mut self,
         tx_status_sender: &TxStatusChange,
         txs: &[Arc<Transaction>],
+        source: Option<TxSource>,
     ) -> Vec<anyhow::Result<InsertionResult>> {
         // Check if that data is okay (witness match input/output, and if recovered signatures ara valid).
         // should be done before transaction comes to txpool, or before it enters RwLocked region.
         let mut res = Vec::new();
         for tx in txs.iter() {
-            res.push(self.insert_inner(tx.clone()))
+            res.push(self.insert_submitted_at(
+                tx.clone(),
+                self.since_epoch(),
+                source.clone(),
+            ))
         }
         // announce to subscribers
         for ret in res.iter() {
@@ -329,6 +410,17 @@ where
         self.sorted_includable()
     }
 
+    /// Selects the transactions for the next block fitting into `max_gas`
+    /// with the strategy from the config.
+    pub fn select_transactions(&self, max_gas: u64) -> Vec<ArcPoolTx> {
+        let txs = self
+            .sorted_includable()
+            .iter()
+            .filter_map(|tx| self.by_hash.get(&tx.id()).cloned())
+            .collect();
+        self.config.selection_strategy.select(txs, max_gas)
+    }
+
     /// When block is updated we need to receive all spend outputs and remove them from txpool.
     pub fn block_update(
         &mut self,
@@ -342,7 +434,9 @@ where
         }
     }
 
-    /// remove transaction from pool needed on user demand. Low priority
+    /// remove transaction from pool needed on user demand. Low priority.
+    /// The subscribers are notified about each removed transaction, including
+    /// the dependent ones. The unknown transactions are skipped.
     pub fn remove(
         &mut self,
         tx_status_sender: &TxStatusChange,
@@ -351,21 +445,57 @@ where
         let mut removed = Vec::new();
         for tx_id in tx_ids {
             let rem = self.remove_by_tx_id(tx_id);
-            tx_status_sender.send_squeezed_out(*tx_id, Error::Removed);
+            for tx in rem.iter() {
+                tx_status_sender.send_squeezed_out(tx.id(), Error::Removed);
+            }
             removed.extend(rem.into_iter());
         }
         removed
     }
 
-    /// Remove all old transactions from the pool.
+    /// Restores the transactions journaled before the restart. The transactions are
+    /// validated against the current state as newly submitted ones, but keep the time
+    /// of the initial submission. The expired and invalid transactions are removed
+    /// from the journal. If the journal is disabled, the whole journal is cleared.
+    /// Returns the number of pending transactions after the restoration.
+    pub fn restore_journal(&mut self) -> StorageResult<usize> {
+        let mut journaled = self.database.journaled_transactions()?;
+        // Insert the oldest transactions first, so the dependencies precede the dependent ones.
+        journaled.sort_by_key(|(_, submitted_time)| *submitted_time);
+
+        let deadline = self
+            .since_epoch()
+            .saturating_sub(self.config.transaction_ttl);
+        for (tx, submitted_time) in journaled {
+            let tx_id = tx.id();
+            let result = if !self.config.journal {
+                Err(anyhow::anyhow!("The journal is disabled"))
+            } else if submitted_time <= deadline {
+                Err(Error::TTLReason.into())
+            } else {
+                self.insert_submitted_at(Arc::new(tx), submitted_time, None)
+            };
+
+            if let Err(e) = result {
+                tracing::debug!("The journaled transaction {tx_id} is dropped: {e}");
+                self.database.remove_journaled_transaction(&tx_id)?;
+            }
+        }
+        Ok(self.pending_number())
+    }
+
+    /// Remove all old transactions from the pool. The age of the transaction is measured
+    /// from its submission, so the restored transactions keep the age before the restart.
     pub fn prune_old_txs(&mut self) -> Vec<ArcPoolTx> {
-        let deadline = tokio::time::Instant::now() - self.config.transaction_ttl;
+        let deadline = self
+            .since_epoch()
+            .saturating_sub(self.config.transaction_ttl);
 
         let mut result = vec![];
 
         while let Some((oldest_time, oldest_tx)) = self.by_time.lowest() {
             let oldest_tx = oldest_tx.clone();
-            if oldest_time.created() <= &deadline {
+            if oldest_time.value() <= &deadline {
                 let removed = self.remove_inner(&oldest_tx);
                 result.extend(removed.into_iter());
             } else {
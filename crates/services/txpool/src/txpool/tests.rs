@@ -1,4 +1,6 @@
 use crate::{
+    ports::TxPoolDb,
+    service::TxStatusChange,
     test_helpers::{
         add_coin_to_state,
         create_output_and_input,
@@ -39,12 +41,18 @@ use fuel_core_types::{
         UniqueIdentifier,
         UtxoId,
     },
+    services::txpool::{
+        ClientId,
+        Quota,
+        TxSource,
+    },
 };
 use std::{
     cmp::Reverse,
     collections::HashMap,
     str::FromStr,
     sync::Arc,
+    time::Duration,
     vec,
 };
 
@@ -452,7 +460,7 @@ fn more_priced_tx3_removes_tx1_and_dependent_tx2() {
     );
     let tx3 = Arc::new(
         TransactionBuilder::script(vec![], vec![])
-            .gas_price(20)
+            .gas_price(30)
             .gas_limit(GAS_LIMIT)
             .add_input(gas_coin)
             .finalize_as_transaction(),
@@ -515,6 +523,158 @@ fn more_priced_tx2_removes_tx1_and_more_priced_tx3_removes_tx2() {
     );
 }
 
+#[test]
+fn replacement_without_min_price_bump_is_rejected() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            min_price_bump_percent: 10,
+            ..Default::default()
+        },
+        db,
+    );
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx = |gas_price| {
+        Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(gas_price)
+                .gas_limit(GAS_LIMIT)
+                .add_input(gas_coin.clone())
+                .finalize_as_transaction(),
+        )
+    };
+    let tx1 = tx(10);
+
+    txpool
+        .insert_inner(tx1.clone())
+        .expect("Tx1 should be OK, got Err");
+    let err = txpool
+        .insert_inner(tx(10))
+        .expect_err("Tx2 should be Err, got Ok");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedReplacementUnderpriced { replaced, min_gas_price: 11 })
+            if replaced == &tx1.id()
+    ));
+    let squeezed = txpool
+        .insert_inner(tx(11))
+        .expect("Tx3 should be OK, got Err");
+    assert_eq!(squeezed.removed[0].id(), tx1.id());
+}
+
+#[test]
+fn replacement_paying_less_than_evicted_fees_is_rejected() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db);
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let (output, unset_input) = create_output_and_input(&mut rng, 10);
+    let tx1 = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(10)
+            .gas_limit(GAS_LIMIT)
+            .add_input(gas_coin.clone())
+            .add_output(output)
+            .finalize_as_transaction(),
+    );
+    let input = unset_input.into_input(UtxoId::new(tx1.id(), 0));
+    let tx2 = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(10)
+            .gas_limit(GAS_LIMIT)
+            .add_input(input)
+            .finalize_as_transaction(),
+    );
+    // Outbids tx1, but not tx1 together with the dependent tx2.
+    let tx3 = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(11)
+            .gas_limit(GAS_LIMIT)
+            .add_input(gas_coin)
+            .finalize_as_transaction(),
+    );
+
+    txpool
+        .insert_inner(tx1.clone())
+        .expect("Tx1 should be OK, got Err");
+    txpool
+        .insert_inner(tx2.clone())
+        .expect("Tx2 should be OK, got Err");
+    let evicted_fee: u128 = [tx1.id(), tx2.id()]
+        .iter()
+        .map(|id| {
+            let tx = &txpool.txs()[id];
+            tx.price() as u128 * tx.max_gas() as u128
+        })
+        .sum();
+
+    let err = txpool
+        .insert_inner(tx3)
+        .expect_err("Tx3 should be Err, got Ok");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedReplacementFeeTooLow { fee, evicted_fee: evicted })
+            if fee < evicted && evicted == &evicted_fee
+    ));
+    assert_eq!(txpool.txs().len(), 2);
+}
+
+#[test]
+fn replacement_evicting_too_many_txs_is_rejected() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            max_evicted_by_replacement: 1,
+            ..Default::default()
+        },
+        db,
+    );
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let (output, unset_input) = create_output_and_input(&mut rng, 10);
+    let tx1 = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(10)
+            .gas_limit(GAS_LIMIT)
+            .add_input(gas_coin.clone())
+            .add_output(output)
+            .finalize_as_transaction(),
+    );
+    let input = unset_input.into_input(UtxoId::new(tx1.id(), 0));
+    let tx2 = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(10)
+            .gas_limit(GAS_LIMIT)
+            .add_input(input)
+            .finalize_as_transaction(),
+    );
+    let tx3 = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(100)
+            .gas_limit(GAS_LIMIT)
+            .add_input(gas_coin)
+            .finalize_as_transaction(),
+    );
+
+    txpool.insert_inner(tx1).expect("Tx1 should be OK, got Err");
+    txpool.insert_inner(tx2).expect("Tx2 should be OK, got Err");
+
+    let err = txpool
+        .insert_inner(tx3)
+        .expect_err("Tx3 should be Err, got Ok");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedReplacementEvictsTooMany {
+            evicted: 2,
+            limit: 1
+        })
+    ));
+}
+
 #[test]
 fn tx_limit_hit() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -718,6 +878,34 @@ async fn find_dependent_tx1_tx2() {
     assert_eq!(list[2].id(), tx3.id(), "Tx3 should be third.");
 }
 
+#[test]
+fn find_by_owner_returns_txs_spending_owner_coins() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db);
+
+    let mut tx = || {
+        let (coin, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+        let tx = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_limit(GAS_LIMIT)
+                .add_input(gas_coin)
+                .finalize_as_transaction(),
+        );
+        (coin.owner, tx)
+    };
+    let (owner1, tx1) = tx();
+    let (_, tx2) = tx();
+
+    txpool.insert_inner(tx1.clone()).expect("Tx1 should be OK");
+    txpool.insert_inner(tx2).expect("Tx2 should be OK");
+
+    let found = txpool.find_by_owner(&owner1);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].id(), tx1.id());
+    assert!(txpool.find_by_owner(&Address::default()).is_empty());
+}
+
 #[tokio::test]
 async fn tx_at_least_min_gas_price_is_insertable() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -935,8 +1123,8 @@ async fn higher_priced_tx_squeezes_out_lower_priced_tx_with_same_message_id() {
 #[tokio::test]
 async fn message_of_squeezed_out_tx_can_be_resubmitted_at_lower_gas_price() {
     // tx1 (message 1, message 2) gas_price 2
-    // tx2 (message 1) gas_price 3
-    //   squeezes tx1 with higher gas price
+    // tx2 (message 1) gas_price 5
+    //   squeezes tx1 with higher fee
     // tx3 (message 2) gas_price 1
     //   works since tx1 is no longer part of txpool state even though gas price is less
 
@@ -955,7 +1143,7 @@ async fn message_of_squeezed_out_tx_can_be_resubmitted_at_lower_gas_price() {
 
     let tx_2 = Arc::new(
         TransactionBuilder::script(vec![], vec![])
-            .gas_price(3)
+            .gas_price(5)
             .gas_limit(GAS_LIMIT)
             .add_input(message_input_1)
             .finalize_as_transaction(),
@@ -1069,3 +1257,261 @@ fn predicate_that_returns_false_is_invalid() {
         "unexpected error: {err}",
     )
 }
+
+#[test]
+fn journaled_transactions_are_restored_with_submitted_time() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let config = Config {
+        journal: true,
+        ..Default::default()
+    };
+    let mut txpool = TxPool::new(config.clone(), db.clone());
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&db));
+    let tx = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_limit(GAS_LIMIT)
+            .add_input(gas_coin)
+            .finalize_as_transaction(),
+    );
+    txpool
+        .insert_inner(tx.clone())
+        .expect("Transaction should be OK, got Err");
+    let submitted_time = txpool.find_one(&tx.id()).unwrap().submitted_time();
+
+    // The pool of the restarted node is empty until the journal is restored.
+    let mut txpool = TxPool::new(config, db);
+    assert_eq!(txpool.pending_number(), 0);
+    assert_eq!(txpool.restore_journal().unwrap(), 1);
+
+    let restored = txpool
+        .find_one(&tx.id())
+        .expect("Transaction should be restored");
+    assert_eq!(restored.submitted_time(), submitted_time);
+}
+
+#[test]
+fn expired_and_invalid_journaled_transactions_are_dropped() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let config = Config {
+        journal: true,
+        ..Default::default()
+    };
+    let mut txpool = TxPool::new(config.clone(), db.clone());
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&db));
+    let expired = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    // The coin of the transaction is absent in the state.
+    let (_, gas_coin) = setup_coin(&mut rng, None);
+    let invalid = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let now = crate::since_epoch();
+    db.journal_transaction(
+        &expired.id(),
+        &expired,
+        now - config.transaction_ttl - Duration::from_secs(1),
+    )
+    .unwrap();
+    db.journal_transaction(&invalid.id(), &invalid, now)
+        .unwrap();
+
+    assert_eq!(txpool.restore_journal().unwrap(), 0);
+    assert!(db.journaled_transactions().unwrap().is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn restored_transactions_expire_by_their_submitted_time() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    // The age of the restored transaction is longer than the uptime of any host.
+    let config = Config {
+        journal: true,
+        transaction_ttl: Duration::from_secs(10 * 365 * 24 * 60 * 60),
+        ..Default::default()
+    };
+    let mut txpool = TxPool::new(config.clone(), db.clone());
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&db));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    db.journal_transaction(
+        &tx.id(),
+        &tx,
+        crate::since_epoch() - config.transaction_ttl + Duration::from_secs(10),
+    )
+    .unwrap();
+    assert_eq!(txpool.restore_journal().unwrap(), 1);
+
+    tokio::time::advance(Duration::from_secs(5)).await;
+    assert!(txpool.prune_old_txs().is_empty());
+    assert!(txpool.find_one(&tx.id()).is_some());
+
+    tokio::time::advance(Duration::from_secs(10)).await;
+    let pruned = txpool.prune_old_txs();
+    assert_eq!(pruned.len(), 1);
+    assert_eq!(pruned[0].id(), tx.id());
+    assert!(txpool.find_one(&tx.id()).is_none());
+}
+
+#[test]
+fn removed_transactions_leave_the_journal() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let config = Config {
+        journal: true,
+        ..Default::default()
+    };
+    let mut txpool = TxPool::new(config, db.clone());
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&db));
+    let tx = Arc::new(
+        TransactionBuilder::script(vec![], vec![])
+            .gas_limit(GAS_LIMIT)
+            .add_input(gas_coin)
+            .finalize_as_transaction(),
+    );
+    txpool
+        .insert_inner(tx.clone())
+        .expect("Transaction should be OK, got Err");
+    assert_eq!(db.journaled_transactions().unwrap().len(), 1);
+
+    txpool.remove_committed_tx(&tx.id());
+    assert!(db.journaled_transactions().unwrap().is_empty());
+}
+
+#[test]
+fn owner_quota_limits_pending_txs_of_one_owner() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let config = Config {
+        max_txs_per_owner: Some(1),
+        ..Default::default()
+    };
+    let mut txpool = TxPool::new(config, db.clone());
+
+    // Both coins belong to the owner of the predicate.
+    let predicate: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+    let mut owner_tx = || {
+        let coin = custom_predicate(
+            &mut rng,
+            AssetId::BASE,
+            TEST_COIN_AMOUNT,
+            predicate.clone(),
+            None,
+        );
+        let (_, gas_coin) = add_coin_to_state(coin, Some(&db));
+        Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_limit(GAS_LIMIT)
+                .add_input(gas_coin)
+                .finalize_as_transaction(),
+        )
+    };
+    let tx1 = owner_tx();
+    let tx2 = owner_tx();
+    let tx3 = owner_tx();
+
+    txpool
+        .insert_inner(tx1.clone())
+        .expect("Tx1 should be OK, got Err");
+    let err = txpool
+        .insert_inner(tx2)
+        .expect_err("Tx2 should be Err, got Ok");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedQuotaExceeded {
+            quota: Quota::Owner(_),
+            limit: 1
+        })
+    ));
+
+    // The quota is released when the transaction leaves the pool.
+    txpool.remove_committed_tx(&tx1.id());
+    txpool.insert_inner(tx3).expect("Tx3 should be OK, got Err");
+}
+
+#[test]
+fn source_quota_limits_pending_txs_of_one_client() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let config = Config {
+        max_txs_per_client: Some(1),
+        ..Default::default()
+    };
+    let mut txpool = TxPool::new(config, db.clone());
+    let tx_status_sender = TxStatusChange::new(100);
+    let client = ClientId::Ip([10, 0, 0, 1].into());
+    let other_client = TxSource::Client(ClientId::ApiKey("key".to_string()));
+
+    let mut tx = || {
+        let (_, gas_coin) = setup_coin(&mut rng, Some(&db));
+        Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_limit(GAS_LIMIT)
+                .add_input(gas_coin)
+                .finalize_as_transaction(),
+        )
+    };
+    let (tx1, tx2, tx3, tx4) = (tx(), tx(), tx(), tx());
+
+    let source = Some(TxSource::Client(client.clone()));
+    let result = txpool.insert(&tx_status_sender, &[tx1], source.clone());
+    assert!(result[0].is_ok());
+    let result = txpool.insert(&tx_status_sender, &[tx2], source);
+    let err = result[0].as_ref().expect_err("Tx2 should be Err, got Ok");
+    assert_eq!(
+        err.downcast_ref::<Error>(),
+        Some(&Error::NotInsertedQuotaExceeded {
+            quota: Quota::Client(client),
+            limit: 1
+        })
+    );
+
+    // The quota is per client, and the transactions without the source aren't limited.
+    let result = txpool.insert(&tx_status_sender, &[tx3], Some(other_client));
+    assert!(result[0].is_ok());
+    txpool.insert_inner(tx4).expect("Tx4 should be OK, got Err");
+}
+
+#[test]
+fn replaced_txs_do_not_count_in_the_quota() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let config = Config {
+        max_txs_per_owner: Some(1),
+        max_txs_per_client: Some(1),
+        ..Default::default()
+    };
+    let mut txpool = TxPool::new(config, db.clone());
+    let tx_status_sender = TxStatusChange::new(100);
+    let source = Some(TxSource::Client(ClientId::Ip([10, 0, 0, 1].into())));
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&db));
+    let tx = |gas_price| {
+        Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(gas_price)
+                .gas_limit(GAS_LIMIT)
+                .add_input(gas_coin.clone())
+                .finalize_as_transaction(),
+        )
+    };
+    let tx1 = tx(10);
+
+    let result = txpool.insert(&tx_status_sender, &[tx1.clone()], source.clone());
+    assert!(result[0].is_ok());
+    // The replacement spends the same coin of the same owner from the same client.
+    let result = txpool.insert(&tx_status_sender, &[tx(20)], source);
+    let squeezed = result[0].as_ref().expect("Tx2 should be OK, got Err");
+    assert_eq!(squeezed.removed[0].id(), tx1.id());
+}
@@ -8,6 +8,11 @@ use crate::{
         },
         primitives::BlockId,
     },
+    entities::{
+        coins::coin::Coin,
+        message::Message,
+    },
+    fuel_asm::Word,
     fuel_tx::{
         CheckError,
         Transaction,
@@ -15,6 +20,7 @@ use crate::{
         UtxoId,
     },
     fuel_types::{
+        AssetId,
         Bytes32,
         ContractId,
         Nonce,
@@ -72,6 +78,34 @@ pub enum TransactionExecutionResult {
     },
 }
 
+/// The changes of the state applied before the dry run of the transactions.
+/// The changes are discarded with the results of the dry run.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone)]
+pub struct StateOverrides {
+    /// The coins inserted into the state, the existing coins with the same utxo ids are replaced.
+    pub coins: Vec<Coin>,
+    /// The messages inserted into the state, the existing messages with the same nonces are replaced.
+    pub messages: Vec<Message>,
+    /// The values of the storage slots of the contracts.
+    pub contract_state: Vec<(ContractId, Bytes32, Bytes32)>,
+    /// The balances of the contracts.
+    pub contract_balances: Vec<(ContractId, AssetId, Word)>,
+    /// The bytecode of the contracts. The contract is created if it doesn't exist.
+    pub contract_code: Vec<(ContractId, Vec<u8>)>,
+}
+
+impl StateOverrides {
+    /// Returns `true` if there are no changes of the state.
+    pub fn is_empty(&self) -> bool {
+        self.coins.is_empty()
+            && self.messages.is_empty()
+            && self.contract_state.is_empty()
+            && self.contract_balances.is_empty()
+            && self.contract_code.is_empty()
+    }
+}
+
 /// Execution wrapper where the types
 /// depend on the type of execution.
 #[derive(Debug, Clone, Copy)]
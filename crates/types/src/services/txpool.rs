@@ -8,6 +8,7 @@ use crate::{
             Inputs,
             Outputs,
         },
+        Address,
         Cacheable,
         Chargeable,
         Create,
@@ -28,8 +29,13 @@ use crate::{
         checked_transaction::Checked,
         ProgramState,
     },
+    services::p2p::PeerId,
+};
+use std::{
+    fmt,
+    net::IpAddr,
+    sync::Arc,
 };
-use std::sync::Arc;
 use tai64::Tai64;
 
 /// The alias for transaction pool result.
@@ -112,6 +118,72 @@ impl PoolTransaction {
             PoolTransaction::Create(create) => create.metadata().fee.max_gas(),
         }
     }
+
+    /// Returns the owners of the coins and the recipients of the messages
+    /// spent by the transaction.
+    pub fn senders(&self) -> impl Iterator<Item = &Address> {
+        self.inputs()
+            .iter()
+            .filter_map(|input| input.input_owner().or_else(|| input.recipient()))
+    }
+}
+
+/// The origin of the transaction submitted to the transaction pool.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TxSource {
+    /// Submitted by the GraphQL client.
+    Client(ClientId),
+    /// Gossiped by the P2P peer.
+    Peer(PeerId),
+}
+
+/// The identity of the GraphQL client submitting the transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClientId {
+    /// The client with the API key known to the node.
+    ApiKey(String),
+    /// The client without the known API key is identified by its IP address.
+    Ip(IpAddr),
+}
+
+/// The quota of the pending transactions in the transaction pool.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Quota {
+    /// The quota of the owner of the inputs of the transaction.
+    Owner(Address),
+    /// The quota of the P2P peer gossiping the transaction.
+    Peer(PeerId),
+    /// The quota of the GraphQL client submitting the transaction.
+    Client(ClientId),
+}
+
+impl From<TxSource> for Quota {
+    fn from(source: TxSource) -> Self {
+        match source {
+            TxSource::Client(client) => Quota::Client(client),
+            TxSource::Peer(peer_id) => Quota::Peer(peer_id),
+        }
+    }
+}
+
+impl fmt::Display for Quota {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Quota::Owner(owner) => write!(f, "owner {owner:#x}"),
+            Quota::Peer(peer_id) => {
+                write!(f, "P2P peer 0x")?;
+                for byte in peer_id.as_ref() {
+                    write!(f, "{byte:02x}")?;
+                }
+                Ok(())
+            }
+            // The API key is a secret, so it is not a part of the message.
+            Quota::Client(ClientId::ApiKey(_)) => {
+                write!(f, "GraphQL client with the API key")
+            }
+            Quota::Client(ClientId::Ip(ip)) => write!(f, "GraphQL client {ip}"),
+        }
+    }
 }
 
 impl From<&PoolTransaction> for Transaction {
@@ -262,6 +334,14 @@ pub enum Error {
     NotInsertedMaxDepth,
     #[error("Transaction exceeds the max gas per block limit. Tx gas: {tx_gas}, block limit {block_limit}")]
     NotInsertedMaxGasLimit { tx_gas: Word, block_limit: Word },
+    #[error("Transaction is not inserted. The {quota} reached the limit of {limit} pending transactions")]
+    NotInsertedQuotaExceeded { quota: Quota, limit: usize },
+    #[error("Transaction is not inserted. The replacement of tx {replaced:#x} requires the gas price of at least {min_gas_price}")]
+    NotInsertedReplacementUnderpriced { replaced: TxId, min_gas_price: Word },
+    #[error("Transaction is not inserted. The replacement fee {fee} doesn't exceed the fee {evicted_fee} of the evicted transactions")]
+    NotInsertedReplacementFeeTooLow { fee: u128, evicted_fee: u128 },
+    #[error("Transaction is not inserted. The replacement evicts {evicted} transactions, the limit is {limit}")]
+    NotInsertedReplacementEvictsTooMany { evicted: usize, limit: usize },
     // small todo for now it can pass but in future we should include better messages
     #[error("Transaction removed.")]
     Removed,
@@ -0,0 +1,102 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::secrecy::Secret;
+use serde_json::json;
+use std::net::{
+    Ipv4Addr,
+    SocketAddr,
+};
+
+#[tokio::test]
+async fn privileged_operations_are_not_served_by_public_api() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let url = format!("http://{}/graphql", srv.bound_address);
+
+    let response: serde_json::Value = reqwest::Client::new()
+        .post(url)
+        .json(&json!({ "query": "mutation { startSession }" }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let error = response["errors"][0]["message"].as_str().unwrap();
+    assert!(error.contains("Unknown field \"startSession\""), "{error}");
+}
+
+#[tokio::test]
+async fn admin_api_is_served_on_admin_address_without_token() {
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let admin_address = srv.admin_bound_address.unwrap();
+
+    let client = FuelClient::from(srv.bound_address);
+    assert!(client.start_session().await.is_err());
+
+    let client = FuelClient::from(srv.bound_address).with_admin_address(admin_address);
+    client.start_session().await.unwrap();
+    assert_eq!(client.produce_blocks(1, None).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn admin_api_is_disabled_without_token_and_admin_address() {
+    let mut config = Config::local_node();
+    config.admin_addr = None;
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    assert_eq!(srv.admin_bound_address, None);
+    let client = FuelClient::from(srv.bound_address);
+    assert!(client.start_session().await.is_err());
+}
+
+#[tokio::test]
+async fn admin_api_requires_configured_token() {
+    let mut config = Config::local_node();
+    config.admin_addr = None;
+    config.admin_token = Some(Secret::new("secret".to_string()));
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    let client = FuelClient::from(srv.bound_address);
+    let err = client.start_session().await.unwrap_err();
+    assert!(err.to_string().contains("bearer token"), "{err}");
+
+    let client = FuelClient::from(srv.bound_address).with_admin_token("wrong");
+    let err = client.start_session().await.unwrap_err();
+    assert!(err.to_string().contains("bearer token"), "{err}");
+
+    let client = FuelClient::from(srv.bound_address).with_admin_token("secret");
+    client.start_session().await.unwrap();
+    // The public API doesn't require the token.
+    client.health().await.unwrap();
+}
+
+#[tokio::test]
+async fn admin_api_without_token_is_refused_on_public_address() {
+    let mut config = Config::local_node();
+    config.admin_addr = Some(SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), 0));
+
+    let err = FuelService::new_node(config.clone())
+        .await
+        .err()
+        .expect("The admin API without the token should be refused");
+    assert!(
+        err.to_string().contains("requires the admin token"),
+        "{err}"
+    );
+
+    config.admin_token = Some(Secret::new("secret".to_string()));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let admin_port = srv.admin_bound_address.unwrap().port();
+    let admin_address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), admin_port);
+
+    let client = FuelClient::from(srv.bound_address).with_admin_address(admin_address);
+    assert!(client.start_session().await.is_err());
+    let client = client.with_admin_token("secret");
+    client.start_session().await.unwrap();
+}
@@ -0,0 +1,214 @@
+use fuel_core::service::{
+    config::{
+        ApiLimits,
+        RateLimitConfig,
+    },
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    FuelClient,
+    PageDirection,
+    PaginationRequest,
+};
+
+async fn client_with_limits(limits: ApiLimits) -> (FuelService, FuelClient) {
+    let mut config = Config::local_node();
+    config.api_limits = limits;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    (srv, client)
+}
+
+#[tokio::test]
+async fn page_size_is_limited() {
+    let (_srv, client) = client_with_limits(ApiLimits {
+        max_page_size: 10,
+        ..Default::default()
+    })
+    .await;
+    let request = |results| PaginationRequest {
+        cursor: None,
+        results,
+        direction: PageDirection::Forward,
+    };
+
+    client.blocks(request(10)).await.unwrap();
+    let err = client.blocks(request(11)).await.unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("exceeds the maximum page size `10`"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn query_depth_is_limited() {
+    let (_srv, client) = client_with_limits(ApiLimits {
+        max_depth: 2,
+        ..Default::default()
+    })
+    .await;
+
+    let err = client.chain_info().await.unwrap_err();
+
+    assert!(err.to_string().contains("nested too deep"), "{err}");
+}
+
+#[tokio::test]
+async fn query_complexity_is_limited() {
+    let (_srv, client) = client_with_limits(ApiLimits {
+        max_complexity: 5,
+        ..Default::default()
+    })
+    .await;
+
+    let err = client.node_info().await.unwrap_err();
+
+    assert!(err.to_string().contains("too complex"), "{err}");
+}
+
+#[tokio::test]
+async fn request_body_size_is_limited() {
+    let (_srv, client) = client_with_limits(ApiLimits {
+        request_body_bytes_limit: 16,
+        ..Default::default()
+    })
+    .await;
+
+    let err = client.node_info().await.unwrap_err();
+
+    assert!(err.to_string().contains("size limit"), "{err}");
+}
+
+#[tokio::test]
+async fn requests_are_rate_limited() {
+    let (_srv, client) = client_with_limits(ApiLimits {
+        rate_limit: Some(RateLimitConfig {
+            requests_per_second: 1,
+            burst: 2,
+            api_keys: Default::default(),
+        }),
+        ..Default::default()
+    })
+    .await;
+
+    client.node_info().await.unwrap();
+    client.node_info().await.unwrap();
+    let err = client.node_info().await.unwrap_err();
+
+    assert!(err.to_string().contains("rate limit"), "{err}");
+}
+
+#[tokio::test]
+async fn batch_size_is_limited() {
+    let (srv, _client) = client_with_limits(ApiLimits {
+        max_batch_size: 2,
+        ..Default::default()
+    })
+    .await;
+    let url = format!("http://{}/graphql", srv.bound_address);
+    let query = serde_json::json!({ "query": "{ health }" });
+
+    let response = reqwest::Client::new()
+        .post(url)
+        .json(&vec![query; 3])
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+    let response: serde_json::Value = response.json().await.unwrap();
+    let error = response["errors"][0]["message"].as_str().unwrap();
+    assert!(error.contains("maximum batch size `2`"), "{error}");
+}
+
+#[tokio::test]
+async fn nested_lists_of_big_pages_are_too_complex() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let url = format!("http://{}/graphql", srv.bound_address);
+    let query = |first: usize| {
+        serde_json::json!({
+            "query": format!(
+                "{{ blocks(first: {first}) {{ nodes {{ transactions {{ \
+                    id inputs {{ __typename }} outputs {{ __typename }} \
+                    receipts {{ rawPayload }} status {{ __typename }} \
+                }} }} }} }}"
+            )
+        })
+    };
+    let post = |query| {
+        let url = url.clone();
+        async move {
+            let response: serde_json::Value = reqwest::Client::new()
+                .post(url)
+                .json(&query)
+                .send()
+                .await
+                .unwrap()
+                .json()
+                .await
+                .unwrap();
+            response["errors"].clone()
+        }
+    };
+
+    assert!(post(query(10)).await.is_null());
+    let errors = post(query(1000)).await;
+    let error = errors[0]["message"].as_str().unwrap();
+    assert!(error.contains("too complex"), "{error}");
+}
+
+#[tokio::test]
+async fn each_query_of_the_batch_is_rate_limited() {
+    let (srv, _client) = client_with_limits(ApiLimits {
+        rate_limit: Some(RateLimitConfig {
+            requests_per_second: 1,
+            burst: 2,
+            api_keys: Default::default(),
+        }),
+        ..Default::default()
+    })
+    .await;
+    let url = format!("http://{}/graphql", srv.bound_address);
+    let query = serde_json::json!({ "query": "{ health }" });
+
+    let response = reqwest::Client::new()
+        .post(url)
+        .json(&vec![query; 3])
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), reqwest::StatusCode::TOO_MANY_REQUESTS);
+    let response: serde_json::Value = response.json().await.unwrap();
+    let error = response["errors"][0]["message"].as_str().unwrap();
+    assert!(error.contains("rate limit"), "{error}");
+}
+
+#[tokio::test]
+async fn complexity_of_the_batch_is_limited() {
+    let (srv, _client) = client_with_limits(ApiLimits {
+        max_complexity: 2,
+        ..Default::default()
+    })
+    .await;
+    let url = format!("http://{}/graphql", srv.bound_address);
+    let query = serde_json::json!({ "query": "{ health }" });
+
+    let response: serde_json::Value = reqwest::Client::new()
+        .post(url)
+        .json(&vec![query; 3])
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert!(response[0]["errors"].is_null());
+    assert!(response[1]["errors"].is_null());
+    let error = response[2]["errors"][0]["message"].as_str().unwrap();
+    assert!(error.contains("maximum complexity `2`"), "{error}");
+}
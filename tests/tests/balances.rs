@@ -244,3 +244,98 @@ async fn first_5_balances() {
         assert_eq!(balances[i].amount.0, 300);
     }
 }
+
+#[tokio::test]
+async fn balance_at_past_block_height() {
+    let owner = Address::default();
+    let recipient = Address::new([1u8; 32]);
+    let asset_id = AssetId::BASE;
+
+    let mut config = Config::local_node();
+    config.state_history = true;
+    config.chain_conf.initial_state = Some(StateConfig {
+        height: None,
+        contracts: None,
+        coins: Some(vec![CoinConfig {
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner,
+            amount: 100,
+            asset_id,
+        }]),
+        messages: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let coin = client
+        .coins_to_spend(
+            format!("{owner:#x}").as_str(),
+            vec![(format!("{asset_id:#x}").as_str(), 1, None)],
+            None,
+        )
+        .await
+        .unwrap()
+        .remove(0)
+        .remove(0);
+    let coin = match coin {
+        CoinType::Coin(coin) => coin,
+        _ => panic!("Expected the genesis coin"),
+    };
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(1_000_000)
+        .add_input(Input::coin_signed(
+            coin.utxo_id.into(),
+            coin.owner.into(),
+            coin.amount.into(),
+            coin.asset_id.into(),
+            Default::default(),
+            0,
+            coin.maturity.into(),
+        ))
+        .add_output(Output::coin(recipient, 30, asset_id))
+        .add_output(Output::change(owner, 0, asset_id))
+        .add_witness(Default::default())
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    // The historical state is queried through the `blockHeight` argument.
+    let balance = |owner: Address, block_height: Option<&str>| {
+        let url = format!("http://{}/graphql", srv.bound_address);
+        let body = serde_json::json!({
+            "query": "query($owner: Address!, $assetId: AssetId!, $height: U64) { \
+                balance(owner: $owner, assetId: $assetId, blockHeight: $height) { amount } \
+            }",
+            "variables": {
+                "owner": format!("{owner:#x}"),
+                "assetId": format!("{asset_id:#x}"),
+                "height": block_height,
+            },
+        });
+        async move {
+            let response: serde_json::Value = reqwest::Client::new()
+                .post(url)
+                .json(&body)
+                .send()
+                .await
+                .unwrap()
+                .json()
+                .await
+                .unwrap();
+            response["data"]["balance"]["amount"]
+                .as_str()
+                .unwrap_or_else(|| panic!("Unexpected response {response}"))
+                .parse::<u64>()
+                .unwrap()
+        }
+    };
+
+    assert_eq!(balance(owner, Some("0")).await, 100);
+    assert_eq!(balance(recipient, Some("0")).await, 0);
+    assert_eq!(balance(owner, Some("1")).await, 70);
+    assert_eq!(balance(recipient, Some("1")).await, 30);
+    assert_eq!(balance(recipient, None).await, 30);
+}
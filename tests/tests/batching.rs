@@ -0,0 +1,115 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    FuelClient,
+    PageDirection,
+    PaginationRequest,
+};
+use fuel_core_types::fuel_crypto::Hasher;
+use serde_json::{
+    json,
+    Value,
+};
+use std::time::Duration;
+
+async fn post(srv: &FuelService, body: Value) -> Value {
+    let url = format!("http://{}/graphql", srv.bound_address);
+    reqwest::Client::new()
+        .post(url)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn batched_requests_are_served_in_order() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+
+    let response = post(
+        &srv,
+        json!([
+            { "query": "{ chain { name } }" },
+            { "query": "{ health }" },
+            { "query": "{ unknownField }" },
+        ]),
+    )
+    .await;
+
+    let responses = response.as_array().unwrap();
+    assert_eq!(responses.len(), 3);
+    assert_eq!(
+        responses[0]["data"]["chain"]["name"],
+        json!("local_testnet")
+    );
+    assert_eq!(responses[1]["data"]["health"], json!(true));
+    assert!(responses[2]["errors"].is_array());
+}
+
+#[tokio::test]
+async fn persisted_query_is_served_by_hash() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let query = "{ health }";
+    let extensions = json!({
+        "persistedQuery": {
+            "version": 1,
+            "sha256Hash": format!("{:x}", Hasher::hash(query.as_bytes())),
+        }
+    });
+
+    // The node doesn't know the query until it is sent with its hash once.
+    let response = post(&srv, json!({ "extensions": extensions })).await;
+    assert_eq!(
+        response["errors"][0]["message"],
+        json!("PersistedQueryNotFound")
+    );
+
+    let response = post(&srv, json!({ "query": query, "extensions": extensions })).await;
+    assert_eq!(response["data"]["health"], json!(true));
+
+    let response = post(&srv, json!({ "extensions": extensions })).await;
+    assert_eq!(response["data"]["health"], json!(true));
+}
+
+#[tokio::test]
+async fn client_batches_concurrent_queries() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address)
+        .with_query_batching(10, Duration::from_millis(10));
+
+    let (health, chain, block, missing) = tokio::join!(
+        client.health(),
+        client.chain_info(),
+        client.block_by_height(0),
+        client.transaction(&format!("{:#x}", Hasher::hash([0u8]))),
+    );
+
+    assert!(health.unwrap());
+    assert_eq!(chain.unwrap().name, "local_testnet");
+    assert_eq!(block.unwrap().unwrap().header.height.0, 0);
+    assert!(missing.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn client_batches_report_errors_to_each_query() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address)
+        .with_query_batching(10, Duration::from_millis(10));
+
+    let too_big_page = PaginationRequest {
+        cursor: None,
+        results: 1025,
+        direction: PageDirection::Forward,
+    };
+
+    let (health, blocks) = tokio::join!(client.health(), client.blocks(too_big_page));
+
+    assert!(health.unwrap());
+    let err = blocks.unwrap_err();
+    assert!(err.to_string().contains("maximum page size"), "{err}");
+}
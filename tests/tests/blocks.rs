@@ -7,6 +7,7 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::{
+    schema,
     types::TransactionStatus,
     FuelClient,
     PageDirection,
@@ -25,10 +26,15 @@ use fuel_core_types::{
         block::CompressedBlock,
         consensus::Consensus,
     },
+    fuel_asm::{
+        op,
+        RegId,
+    },
     fuel_tx::*,
     secrecy::ExposeSecret,
     tai64::Tai64,
 };
+use futures::StreamExt;
 use itertools::{
     rev,
     Itertools,
@@ -139,7 +145,8 @@ async fn produce_block_manually() {
         .await
         .unwrap();
 
-    let client = FuelClient::from(srv.bound_address);
+    let client = FuelClient::from(srv.bound_address)
+        .with_admin_address(srv.admin_bound_address.unwrap());
 
     let new_height = client.produce_blocks(1, None).await.unwrap();
 
@@ -164,7 +171,8 @@ async fn produce_block_negative() {
         .await
         .unwrap();
 
-    let client = FuelClient::from(srv.bound_address);
+    let client = FuelClient::from(srv.bound_address)
+        .with_admin_address(srv.admin_bound_address.unwrap());
 
     let new_height = client.produce_blocks(5, None).await;
 
@@ -215,7 +223,8 @@ async fn produce_block_custom_time() {
         .await
         .unwrap();
 
-    let client = FuelClient::from(srv.bound_address);
+    let client = FuelClient::from(srv.bound_address)
+        .with_admin_address(srv.admin_bound_address.unwrap());
     let start_timestamp = Tai64::UNIX_EPOCH.0 + 100u64;
     let new_height = client
         .produce_blocks(5, Some(start_timestamp))
@@ -243,7 +252,8 @@ async fn produce_block_bad_start_time() {
         .await
         .unwrap();
 
-    let client = FuelClient::from(srv.bound_address);
+    let client = FuelClient::from(srv.bound_address)
+        .with_admin_address(srv.admin_bound_address.unwrap());
 
     // produce block with current timestamp
     let _ = client.produce_blocks(1, None).await.unwrap();
@@ -273,7 +283,8 @@ async fn produce_block_overflow_time() {
         .await
         .unwrap();
 
-    let client = FuelClient::from(srv.bound_address);
+    let client = FuelClient::from(srv.bound_address)
+        .with_admin_address(srv.admin_bound_address.unwrap());
 
     let err = client
         .produce_blocks(2, Some(u64::MAX))
@@ -297,7 +308,8 @@ async fn block_connection_5(
     let srv = FuelService::from_database(Default::default(), config)
         .await
         .unwrap();
-    let client = FuelClient::from(srv.bound_address);
+    let client = FuelClient::from(srv.bound_address)
+        .with_admin_address(srv.admin_bound_address.unwrap());
     // setup test data in the node
     client.produce_blocks(9, None).await.unwrap();
 
@@ -340,3 +352,117 @@ async fn block_connection_5(
         }
     };
 }
+
+#[tokio::test]
+async fn new_blocks_subscription_resumes_from_height() {
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address)
+        .with_admin_address(srv.admin_bound_address.unwrap());
+
+    // The blocks committed before the subscription are streamed too.
+    client.produce_blocks(3, None).await.unwrap();
+
+    let heights = tokio::spawn({
+        let client = client.clone();
+        async move {
+            client
+                .subscribe_new_blocks(Some(2))
+                .await
+                .unwrap()
+                .take(4)
+                .map(|block| block.unwrap().header.height.0)
+                .collect::<Vec<_>>()
+                .await
+        }
+    });
+    client.produce_blocks(2, None).await.unwrap();
+
+    let heights = tokio::time::timeout(Duration::from_secs(10), heights)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(heights, vec![2, 3, 4, 5]);
+}
+
+#[tokio::test]
+async fn new_blocks_subscriptions_share_websocket_connection() {
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address)
+        .with_admin_address(srv.admin_bound_address.unwrap())
+        .with_websocket_subscriptions();
+
+    client.produce_blocks(3, None).await.unwrap();
+
+    let first = client.subscribe_new_blocks(Some(1)).await.unwrap();
+    let second = client.subscribe_new_blocks(Some(3)).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+
+    let heights = |blocks: Vec<std::io::Result<schema::block::Block>>| {
+        blocks
+            .into_iter()
+            .map(|block| block.unwrap().header.height.0)
+            .collect::<Vec<_>>()
+    };
+    let (first, second) = tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::join!(
+            first.take(2).collect::<Vec<_>>(),
+            second.take(2).collect::<Vec<_>>()
+        )
+    })
+    .await
+    .unwrap();
+    assert_eq!(heights(first), vec![1, 2]);
+    assert_eq!(heights(second), vec![3, 4]);
+}
+
+#[tokio::test]
+async fn lookups_by_id_of_pruned_block_and_transaction_fail() {
+    let mut config = Config::local_node();
+    config.blocks_retention = Some(1);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = Transaction::default();
+    client.submit_and_await_commit(&tx).await.unwrap();
+    let block_id = client.block_by_height(1).await.unwrap().unwrap().id;
+
+    // The next block moves the first one out of the retention window.
+    let next_tx =
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_limit(1_000)
+            .finalize_as_transaction();
+    client.submit_and_await_commit(&next_tx).await.unwrap();
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while client.block_by_height(1).await.is_ok() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+
+    let err = client.block(&block_id.to_string()).await.unwrap_err();
+    assert!(err.to_string().contains("pruned"), "{err}");
+    let err = client
+        .transaction(&format!("{:#x}", tx.id()))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("pruned"), "{err}");
+
+    // The unknown ids are still resolved to `None`.
+    assert!(client
+        .block(&BlockId::from(Bytes32::zeroed()).to_string())
+        .await
+        .unwrap()
+        .is_none());
+    assert!(client
+        .transaction(&format!("{:#x}", Bytes32::zeroed()))
+        .await
+        .unwrap()
+        .is_none());
+}
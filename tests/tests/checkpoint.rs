@@ -0,0 +1,46 @@
+use fuel_core::{
+    database::Database,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::FuelClient;
+
+#[tokio::test]
+async fn create_checkpoint_fails_without_checkpoints_dir() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address)
+        .with_admin_address(srv.admin_bound_address.unwrap());
+
+    let result = client.create_checkpoint().await;
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "default")]
+#[tokio::test]
+async fn checkpoint_of_running_node_can_be_restored() {
+    use tempfile::TempDir;
+
+    let db_dir = TempDir::new().unwrap();
+    let checkpoints_dir = TempDir::new().unwrap();
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    config.checkpoints_dir = Some(checkpoints_dir.path().to_path_buf());
+
+    let database = Database::open(db_dir.path()).unwrap();
+    let srv = FuelService::from_database(database, config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address)
+        .with_admin_address(srv.admin_bound_address.unwrap());
+    client.produce_blocks(5, None).await.unwrap();
+
+    let checkpoint = client.create_checkpoint().await.unwrap();
+    client.produce_blocks(5, None).await.unwrap();
+
+    let restored_dir = TempDir::new().unwrap();
+    let restored =
+        Database::restore_checkpoint(checkpoint.as_ref(), restored_dir.path()).unwrap();
+    assert_eq!(restored.latest_height().unwrap(), 5u32.into());
+}
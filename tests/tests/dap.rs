@@ -9,7 +9,8 @@ use std::convert::TryInto;
 #[tokio::test]
 async fn start_session() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
-    let client = FuelClient::from(srv.bound_address);
+    let client = FuelClient::from(srv.bound_address)
+        .with_admin_address(srv.admin_bound_address.unwrap());
 
     let session = client.start_session().await.unwrap();
     let session_p = client.start_session().await.unwrap();
@@ -23,7 +24,8 @@ async fn start_session() {
 #[tokio::test]
 async fn end_session() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
-    let client = FuelClient::from(srv.bound_address);
+    let client = FuelClient::from(srv.bound_address)
+        .with_admin_address(srv.admin_bound_address.unwrap());
 
     let session = client.start_session().await.unwrap();
     let id = session.as_str();
@@ -35,7 +37,8 @@ async fn end_session() {
 #[tokio::test]
 async fn reset() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
-    let client = FuelClient::from(srv.bound_address);
+    let client = FuelClient::from(srv.bound_address)
+        .with_admin_address(srv.admin_bound_address.unwrap());
 
     let session = client.start_session().await.unwrap();
     let id = session.as_str();
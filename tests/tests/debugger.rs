@@ -15,7 +15,8 @@ use fuel_core_types::{
 #[tokio::test]
 async fn debugger_integration() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
-    let client = FuelClient::from(srv.bound_address);
+    let client = FuelClient::from(srv.bound_address)
+        .with_admin_address(srv.admin_bound_address.unwrap());
 
     let session = client.start_session().await.unwrap();
     let session_id = session.as_str();
@@ -1,8 +1,12 @@
 #![deny(unused_must_use)]
 
+mod admin_api;
+mod api_limits;
 mod balances;
+mod batching;
 mod blocks;
 mod chain;
+mod checkpoint;
 mod coin;
 mod coins;
 mod contract;
@@ -13,6 +17,8 @@ mod helpers;
 mod messages;
 mod node_info;
 mod poa;
+#[cfg(feature = "default")]
+mod read_only;
 #[cfg(feature = "relayer")]
 mod relayer;
 mod snapshot;
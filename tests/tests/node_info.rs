@@ -28,3 +28,63 @@ async fn node_info() {
     assert_eq!(max_depth, node_config.txpool.max_depth.into());
     assert_eq!(max_tx, node_config.txpool.max_tx.into());
 }
+
+#[tokio::test]
+async fn database_stats() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address)
+        .with_admin_address(srv.admin_bound_address.unwrap());
+
+    let stats = client.database_stats().await.unwrap();
+
+    let metadata = stats
+        .iter()
+        .find(|column| column.column == "metadata")
+        .unwrap();
+    assert!(metadata.keys.0 > 0);
+    assert!(metadata.bytes.0 > 0);
+    assert_eq!(metadata.internal_stats, None);
+}
+
+#[cfg(feature = "default")]
+#[tokio::test]
+async fn node_info_reports_database_options() {
+    use fuel_core::service::config::{
+        ColumnOptions,
+        Compression,
+    };
+    use tempfile::TempDir;
+
+    let db_dir = TempDir::new().unwrap();
+    let mut node_config = Config::local_node();
+    node_config.database_path = db_dir.path().to_path_buf();
+    node_config.rocksdb.max_open_files = Some(128);
+    node_config.rocksdb.columns.cache_size = Some(1024 * 1024);
+    node_config.rocksdb.column_overrides.insert(
+        fuel_core::database::Column::ContractsState,
+        ColumnOptions {
+            compression: Some(Compression::None),
+            ..Default::default()
+        },
+    );
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let options = client.node_info().await.unwrap().database_options.unwrap();
+
+    assert_eq!(options.max_open_files, Some(128));
+    let contracts_state = options
+        .columns
+        .iter()
+        .find(|column| column.column == "contracts_state")
+        .unwrap();
+    assert_eq!(contracts_state.compression, "none");
+    assert_eq!(contracts_state.cache_size, Some((1024u64 * 1024).into()));
+    let coins = options
+        .columns
+        .iter()
+        .find(|column| column.column == "coins")
+        .unwrap();
+    assert_eq!(coins.compression, "lz4");
+    assert_eq!(coins.bloom_filter_bits_per_key, None);
+}
@@ -0,0 +1,58 @@
+use fuel_core::{
+    database::Database,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::fuel_tx::Transaction;
+use std::time::Duration;
+use tempfile::TempDir;
+
+#[tokio::test]
+async fn read_only_node_follows_the_producing_node() {
+    let db_dir = TempDir::new().unwrap();
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    let database = Database::open(db_dir.path()).unwrap();
+    let producer = FuelService::from_database(database, config).await.unwrap();
+    let producer_client = FuelClient::from(producer.bound_address)
+        .with_admin_address(producer.admin_bound_address.unwrap());
+    producer_client.produce_blocks(3, None).await.unwrap();
+
+    let mut config = Config::local_node();
+    config.database_path = db_dir.path().to_path_buf();
+    config.read_only_db = true;
+    config.manual_blocks_enabled = true;
+    let replica = FuelService::new_node(config).await.unwrap();
+    let replica_client = FuelClient::from(replica.bound_address)
+        .with_admin_address(replica.admin_bound_address.unwrap());
+
+    let height = replica_client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+    assert_eq!(height.0, 3);
+
+    producer_client.produce_blocks(2, None).await.unwrap();
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    let height = replica_client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+    assert_eq!(height.0, 5);
+
+    // the read-only node doesn't modify the database
+    assert!(replica_client.produce_blocks(1, None).await.is_err());
+    assert!(replica_client
+        .submit(&Transaction::default())
+        .await
+        .is_err());
+}
@@ -1,17 +1,35 @@
-use crate::helpers::TestContext;
+use crate::helpers::{
+    TestContext,
+    TestSetupBuilder,
+};
 use fuel_core::{
     database::Database,
     executor::Executor,
     service::{
         adapters::MaybeRelayerAdapter,
+        config::{
+            RateLimitConfig,
+            Trigger,
+        },
         Config,
         FuelService,
     },
 };
 use fuel_core_client::client::{
-    types::TransactionStatus,
+    schema::{
+        tx::{
+            transparent_receipt::ReceiptType,
+            ReceiptFilterInput,
+        },
+        U64,
+    },
+    types::{
+        TransactionResponse,
+        TransactionStatus,
+    },
     FuelClient,
     PageDirection,
+    PaginatedResult,
     PaginationRequest,
 };
 use fuel_core_types::{
@@ -22,9 +40,16 @@ use fuel_core_types::{
             PartialBlockHeader,
         },
     },
+    entities::coins::coin::Coin,
     fuel_asm::*,
+    fuel_crypto::SecretKey,
     fuel_tx::*,
-    services::executor::ExecutionBlock,
+    fuel_types::bytes::SerializableVec,
+    fuel_vm::Call,
+    services::executor::{
+        ExecutionBlock,
+        StateOverrides,
+    },
     tai64::Tai64,
 };
 use itertools::Itertools;
@@ -41,6 +66,8 @@ use std::{
 mod predicates;
 mod tx_pointer;
 mod txn_status_subscription;
+mod txpool_inspection;
+mod txpool_subscriptions;
 mod utxo_validation;
 
 #[test]
@@ -150,6 +177,354 @@ async fn dry_run_create() {
     assert_eq!(err.kind(), NotFound);
 }
 
+#[tokio::test]
+async fn estimate_gas() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let owner = Input::predicate_owner(&predicate);
+    let script = vec![
+        op::addi(0x10, RegId::ZERO, 0xca),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let mut tx = TransactionBuilder::script(script, vec![])
+        .gas_price(1)
+        .gas_limit(0)
+        .add_input(Input::coin_predicate(
+            Default::default(),
+            owner,
+            1000,
+            AssetId::BASE,
+            Default::default(),
+            0,
+            predicate,
+            vec![],
+        ))
+        .finalize();
+
+    let estimate = client.estimate_gas(&tx.clone().into()).await.unwrap();
+
+    assert!(estimate.gas_used > 0);
+    assert_eq!(estimate.predicate_gas_used.len(), 1);
+    assert_eq!(estimate.predicate_gas_used[0].input_index, 0);
+    assert!(estimate.predicate_gas_used[0].gas_used > 0);
+    assert_eq!(
+        estimate.gas_limit,
+        estimate
+            .gas_used
+            .max(estimate.predicate_gas_used[0].gas_used)
+    );
+    assert!(estimate.min_fee > 0);
+    assert!(estimate.min_fee <= estimate.max_fee);
+    assert!(matches!(
+        estimate.receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+
+    // The transaction succeeds with the estimated gas limit.
+    *field::GasLimit::gas_limit_mut(&mut tx) = estimate.gas_limit;
+    let receipts = client.dry_run(&tx.into()).await.unwrap();
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            gas_used,
+        }) if *gas_used == estimate.gas_used
+    ));
+}
+
+#[tokio::test]
+async fn dry_run_many_shares_state_between_transactions() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let contract_code: Vec<u8> = [
+        op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let salt = Salt::zeroed();
+    let contract = Contract::from(contract_code.clone());
+    let state_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &contract.root(), &state_root);
+    let deploy = TransactionBuilder::create(contract_code.into(), salt, vec![])
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+
+    let script: Vec<u8> = [
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    let call = TransactionBuilder::script(script, script_data)
+        .gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    let results = client
+        .dry_run_many(&[deploy.clone(), call.clone()], None)
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].transaction_id, deploy.id());
+    assert_eq!(results[1].transaction_id, call.id());
+    assert!(results.iter().all(|result| result.success), "{results:?}");
+    assert!(results[1].receipts.iter().any(|receipt| matches!(
+        receipt,
+        Receipt::Log { id, ra: 1, .. } if id == &contract_id
+    )));
+
+    // The call before the deployment is invalid, but the deployment is still executed.
+    let results = client
+        .dry_run_many(&[call.clone(), deploy.clone()], None)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].transaction_id, call.id());
+    assert!(!results[0].executed);
+    assert!(!results[0].success);
+    assert!(results[0].reason.is_some());
+    assert!(results[0].receipts.is_empty());
+    assert_eq!(results[1].transaction_id, deploy.id());
+    assert!(results[1].executed);
+    assert!(results[1].success);
+
+    // The dry run doesn't change the state.
+    assert!(client
+        .contract(&format!("{contract_id:#x}"))
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn dry_run_with_state_overrides() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The contract logs the storage slot and the balance keyed by its own id.
+    let contract_code: Vec<u8> = [
+        op::srw(0x10, 0x11, RegId::FP),
+        op::bal(0x12, RegId::FP, RegId::FP),
+        op::log(0x10, 0x11, 0x12, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let contract_id = ContractId::from([7; 32]);
+    let mut slot_value = Bytes32::zeroed();
+    slot_value[..8].copy_from_slice(&42u64.to_be_bytes());
+
+    let script: Vec<u8> = [
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    let call = TransactionBuilder::script(script, script_data)
+        .gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    let overrides = StateOverrides {
+        contract_code: vec![(contract_id, contract_code)],
+        contract_state: vec![(contract_id, Bytes32::new(*contract_id), slot_value)],
+        contract_balances: vec![(contract_id, AssetId::new(*contract_id), 100)],
+        ..Default::default()
+    };
+    let receipts = client
+        .dry_run_with_overrides(&call, None, &overrides)
+        .await
+        .unwrap();
+    assert!(
+        receipts.iter().any(|receipt| matches!(
+            receipt,
+            Receipt::Log { id, ra: 42, rb: 1, rc: 100, .. } if id == &contract_id
+        )),
+        "{receipts:?}"
+    );
+
+    // The dry run doesn't change the state.
+    assert!(client
+        .contract(&format!("{contract_id:#x}"))
+        .await
+        .unwrap()
+        .is_none());
+
+    // The fake coin can be spent with the utxo validation.
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let coin = Coin {
+        utxo_id: rng.gen(),
+        owner: Input::owner(&secret.public_key()),
+        amount: 1000,
+        asset_id: AssetId::BASE,
+        maturity: Default::default(),
+        tx_pointer: Default::default(),
+    };
+    let transfer =
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_limit(1_000_000)
+            .add_unsigned_coin_input(
+                secret,
+                coin.utxo_id,
+                coin.amount,
+                coin.asset_id,
+                Default::default(),
+                0,
+            )
+            .add_output(Output::change(rng.gen(), 0, AssetId::BASE))
+            .finalize_as_transaction();
+
+    let result = client.dry_run_opt(&transfer, Some(true)).await;
+    assert!(result.is_err());
+
+    let overrides = StateOverrides {
+        coins: vec![coin],
+        ..Default::default()
+    };
+    let receipts = client
+        .dry_run_with_overrides(&transfer, Some(true), &overrides)
+        .await
+        .unwrap();
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn submit_is_limited_by_client_quota() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.txpool.max_txs_per_client = Some(1);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut transfer = || {
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_limit(1_000_000)
+            .add_unsigned_coin_input(
+                SecretKey::random(&mut rng),
+                rng.gen(),
+                1000,
+                AssetId::BASE,
+                Default::default(),
+                0,
+            )
+            .finalize_as_transaction()
+    };
+    let (first, second) = (transfer(), transfer());
+
+    client.submit(&first).await.unwrap();
+    let err = client.submit(&second).await.unwrap_err();
+    assert!(
+        err.to_string().contains("limit of 1 pending transactions"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn client_quota_is_keyed_by_the_api_key() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.txpool.max_txs_per_client = Some(1);
+    config.api_limits.rate_limit = Some(RateLimitConfig {
+        requests_per_second: 100,
+        burst: 100,
+        api_keys: ["first".to_string(), "second".to_string()].into(),
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let url = format!("http://{}/graphql", srv.bound_address);
+
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut transfer = || {
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_limit(1_000_000)
+            .add_unsigned_coin_input(
+                SecretKey::random(&mut rng),
+                rng.gen(),
+                1000,
+                AssetId::BASE,
+                Default::default(),
+                0,
+            )
+            .finalize_as_transaction()
+    };
+    let submit = |api_key: &'static str, tx: Transaction| {
+        let url = url.clone();
+        async move {
+            let tx: String = tx.to_bytes().iter().map(|b| format!("{b:02x}")).collect();
+            let response: serde_json::Value = reqwest::Client::new()
+                .post(url)
+                .header("x-api-key", api_key)
+                .json(&serde_json::json!({
+                    "query": "mutation($tx: HexString!) { submit(tx: $tx) { id } }",
+                    "variables": { "tx": format!("0x{tx}") },
+                }))
+                .send()
+                .await
+                .unwrap()
+                .json()
+                .await
+                .unwrap();
+            response["errors"].clone()
+        }
+    };
+
+    // The clients share the IP address, but not the API key.
+    assert!(submit("first", transfer()).await.is_null());
+    let errors = submit("first", transfer()).await;
+    let error = errors[0]["message"].as_str().unwrap();
+    assert!(error.contains("limit of 1 pending transactions"), "{error}");
+    assert!(submit("second", transfer()).await.is_null());
+}
+
+#[tokio::test]
+async fn estimate_gas_rejects_mint() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = Transaction::mint(Default::default(), vec![]).into();
+    let err = client.estimate_gas(&tx).await.unwrap_err();
+
+    assert!(err.to_string().contains("can't be estimated"), "{err}");
+}
+
 #[tokio::test]
 async fn submit() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
@@ -221,6 +596,165 @@ async fn receipts() {
     assert!(receipts.is_some());
 }
 
+fn log_script(ra: u16, rb: u16) -> Transaction {
+    let script = vec![
+        op::addi(0x10, RegId::ZERO, ra),
+        op::addi(0x11, RegId::ZERO, rb),
+        op::log(0x10, 0x11, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    Transaction::script(0, 1_000_000, 0, script, vec![], vec![], vec![], vec![]).into()
+}
+
+#[tokio::test]
+async fn receipts_by_contract() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let first = log_script(1, 0xba);
+    let second = log_script(2, 0xca);
+    let third = log_script(3, 0xba);
+    for tx in [&first, &second, &third] {
+        client.submit_and_await_commit(tx).await.unwrap();
+    }
+
+    // The receipts of the scripts belong to the zeroed contract id.
+    let filter = ReceiptFilterInput {
+        contract: Default::default(),
+        receipt_types: Some(vec![ReceiptType::Log]),
+        rb: Some(U64(0xba)),
+        from_height: None,
+        to_height: None,
+    };
+    let request = PaginationRequest {
+        cursor: None,
+        results: 1,
+        direction: PageDirection::Forward,
+    };
+    let page = client
+        .receipts_by_contract(filter.clone(), request)
+        .await
+        .unwrap();
+    assert!(page.has_next_page);
+    assert_eq!(page.results.len(), 1);
+    assert_eq!(page.results[0].transaction_id, first.id());
+    assert!(matches!(
+        page.results[0].receipt,
+        Receipt::Log {
+            ra: 1,
+            rb: 0xba,
+            ..
+        }
+    ));
+
+    let request = PaginationRequest {
+        cursor: page.cursor,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+    let page = client
+        .receipts_by_contract(filter.clone(), request)
+        .await
+        .unwrap();
+    assert!(!page.has_next_page);
+    assert_eq!(page.results.len(), 1);
+    assert_eq!(page.results[0].transaction_id, third.id());
+
+    // The block range excludes the block of the first transaction.
+    let filter = ReceiptFilterInput {
+        rb: None,
+        from_height: Some(U64(2)),
+        ..filter
+    };
+    let request = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+    let page = client.receipts_by_contract(filter, request).await.unwrap();
+    let ids: Vec<_> = page.results.iter().map(|r| r.transaction_id).collect();
+    assert_eq!(ids, vec![second.id(), third.id()]);
+}
+
+#[tokio::test]
+async fn transactions_by_contract_and_asset() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    let (_, contract_id) = test_builder.setup_contract(vec![], None, None, None);
+
+    let asset_id: AssetId = rng.gen();
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let owner = Input::predicate_owner(&predicate);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(10000)
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            1000,
+            asset_id,
+            Default::default(),
+            0,
+            predicate,
+            vec![],
+        ))
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::change(rng.gen(), 0, asset_id))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .finalize();
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder
+        .config_coin_inputs_from_transactions(&[&tx])
+        .finalize()
+        .await;
+    let tx: Transaction = tx.into();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let request = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+    let ids = |page: PaginatedResult<TransactionResponse, String>| {
+        page.results
+            .iter()
+            .map(|response| response.transaction.id())
+            .collect_vec()
+    };
+
+    let by_contract = client
+        .transactions_by_contract(&format!("{contract_id:#x}"), request.clone())
+        .await
+        .unwrap();
+    assert_eq!(ids(by_contract), vec![tx.id()]);
+
+    let by_asset = client
+        .transactions_by_asset(&format!("{asset_id:#x}"), request.clone())
+        .await
+        .unwrap();
+    assert_eq!(ids(by_asset), vec![tx.id()]);
+
+    // The coinbase without fee doesn't move the base asset.
+    let by_base_asset = client
+        .transactions_by_asset(&format!("{:#x}", AssetId::BASE), request)
+        .await
+        .unwrap();
+    assert!(by_base_asset.results.is_empty());
+}
+
 #[tokio::test]
 async fn get_transaction_by_id() {
     // setup test data in the node
@@ -0,0 +1,141 @@
+use fuel_core::service::{
+    config::Trigger,
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    schema::txpool::TxPoolOrder,
+    types::TransactionResponse,
+    FuelClient,
+    PageDirection,
+    PaginationRequest,
+};
+use fuel_core_types::{
+    fuel_asm::*,
+    fuel_crypto::SecretKey,
+    fuel_tx::*,
+};
+use rand::{
+    prelude::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+fn ids(txs: Vec<TransactionResponse>) -> Vec<TxId> {
+    txs.into_iter().map(|tx| tx.transaction.id()).collect()
+}
+
+fn request(results: usize, cursor: Option<String>) -> PaginationRequest<String> {
+    PaginationRequest {
+        cursor,
+        results,
+        direction: PageDirection::Forward,
+    }
+}
+
+#[tokio::test]
+async fn txpool_can_be_inspected_and_cleaned() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut rng = StdRng::seed_from_u64(2322);
+    let parent_owner = SecretKey::random(&mut rng);
+    let child_owner = SecretKey::random(&mut rng);
+    let amount = 1000;
+
+    // The parent creates the coin spent by the child.
+    let parent =
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_price(1)
+            .gas_limit(1_000_000)
+            .add_unsigned_coin_input(
+                parent_owner,
+                rng.gen(),
+                amount,
+                AssetId::BASE,
+                Default::default(),
+                0,
+            )
+            .add_output(Output::coin(
+                Input::owner(&child_owner.public_key()),
+                amount,
+                AssetId::BASE,
+            ))
+            .finalize_as_transaction();
+    let child =
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_price(2)
+            .gas_limit(1_000_000)
+            .add_unsigned_coin_input(
+                child_owner,
+                UtxoId::new(parent.id(), 0),
+                amount,
+                AssetId::BASE,
+                Default::default(),
+                0,
+            )
+            .finalize_as_transaction();
+    client.submit(&parent).await.unwrap();
+    client.submit(&child).await.unwrap();
+
+    let stats = client.txpool_stats().await.unwrap();
+    assert_eq!(stats.pending_count.0, 2);
+    assert_eq!(stats.consumable_gas.0, 2_000_000);
+
+    // The child pays more, so it goes first by price.
+    let by_price = client
+        .txpool_transactions(TxPoolOrder::Price, request(1, None))
+        .await
+        .unwrap();
+    assert!(by_price.has_next_page);
+    assert_eq!(ids(by_price.results), vec![child.id()]);
+    let by_price = client
+        .txpool_transactions(TxPoolOrder::Price, request(1, by_price.cursor))
+        .await
+        .unwrap();
+    assert!(!by_price.has_next_page);
+    assert_eq!(ids(by_price.results), vec![parent.id()]);
+
+    let by_time = client
+        .txpool_transactions(TxPoolOrder::Time, request(10, None))
+        .await
+        .unwrap();
+    assert_eq!(ids(by_time.results), vec![parent.id(), child.id()]);
+
+    let parent_address = format!("{:#x}", Input::owner(&parent_owner.public_key()));
+    let owned = client
+        .txpool_transactions_by_owner(&parent_address)
+        .await
+        .unwrap();
+    assert_eq!(ids(owned), vec![parent.id()]);
+
+    let dependencies = client
+        .txpool_dependencies(&format!("{:#x}", child.id()))
+        .await
+        .unwrap();
+    assert_eq!(dependencies.map(ids), Some(vec![parent.id()]));
+    let dependencies = client
+        .txpool_dependencies(&format!("{:#x}", parent.id()))
+        .await
+        .unwrap();
+    assert_eq!(dependencies.map(ids), Some(vec![]));
+    let unknown = client
+        .txpool_dependencies(&format!("{:#x}", TxId::zeroed()))
+        .await
+        .unwrap();
+    assert!(unknown.is_none());
+
+    // Removing the parent removes the dependent child too.
+    let removed = client
+        .remove_transactions(&[&format!("{:#x}", parent.id())])
+        .await
+        .unwrap();
+    let mut removed: Vec<TxId> = removed.into_iter().map(|id| id.0 .0).collect();
+    removed.sort();
+    let mut expected = vec![parent.id(), child.id()];
+    expected.sort();
+    assert_eq!(removed, expected);
+    assert_eq!(client.txpool_stats().await.unwrap().pending_count.0, 0);
+}
@@ -0,0 +1,87 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    schema::tx::TxPoolEventKind,
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::*,
+};
+use futures::StreamExt;
+use std::time::Duration;
+use tokio::time::timeout;
+
+#[tokio::test]
+async fn subscribe_new_transactions_and_txpool_events() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut new_transactions =
+        Box::pin(client.subscribe_new_transactions().await.unwrap());
+    let mut txpool_events = Box::pin(client.subscribe_txpool_events().await.unwrap());
+
+    // The subscriptions are established in the background, so the transactions are
+    // submitted until both streams yield their first item.
+    let wait = Duration::from_millis(100);
+    let (mut new_transactions_ready, mut txpool_events_ready) = (false, false);
+    for script_data in 0u8.. {
+        assert!(script_data < 100, "The subscriptions are not established");
+        if new_transactions_ready && txpool_events_ready {
+            break
+        }
+        let tx = Transaction::script(
+            0,
+            ConsensusParameters::DEFAULT.max_gas_per_tx,
+            0,
+            op::ret(RegId::ONE).to_bytes().to_vec(),
+            vec![script_data],
+            vec![],
+            vec![],
+            vec![],
+        );
+        client.submit(&tx.into()).await.unwrap();
+        new_transactions_ready |= timeout(wait, new_transactions.next()).await.is_ok();
+        txpool_events_ready |= timeout(wait, txpool_events.next()).await.is_ok();
+    }
+
+    let tx = Transaction::default();
+    let id = tx.id();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let submitted = timeout(Duration::from_secs(10), async {
+        loop {
+            let submitted = new_transactions.next().await.unwrap().unwrap();
+            if submitted.transaction.id() == id {
+                break submitted
+            }
+        }
+    })
+    .await
+    .unwrap();
+    assert_eq!(submitted.transaction.id(), id);
+
+    let events: Vec<_> = timeout(
+        Duration::from_secs(10),
+        txpool_events
+            .map(|event| event.unwrap())
+            .map(|event| (event.transaction_id.0 .0, event.kind))
+            .filter(|(tx_id, _)| futures::future::ready(*tx_id == id))
+            .take(2)
+            .collect(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        events,
+        vec![
+            (id, TxPoolEventKind::Submitted),
+            (id, TxPoolEventKind::Completed)
+        ]
+    );
+}
@@ -1,5 +1,8 @@
 use clap::Parser;
-use fuel_core::schema::build_schema;
+use fuel_core::schema::{
+    build_admin_schema,
+    build_schema,
+};
 use std::{
     env,
     fs::{
@@ -12,15 +15,23 @@ use std::{
 
 // stored in the root of the workspace
 const SCHEMA_URL: &str = "../crates/client/assets/schema.sdl";
+const ADMIN_SCHEMA_URL: &str = "../crates/client/assets/admin_schema.sdl";
 
 #[derive(Debug, Parser)]
 pub struct DumpCommand {}
 
 pub fn dump_schema() -> Result<(), Box<dyn std::error::Error>> {
+    write_schema(SCHEMA_URL, build_schema().finish().sdl());
+    write_schema(ADMIN_SCHEMA_URL, build_admin_schema().finish().sdl());
+
+    Ok(())
+}
+
+fn write_schema(url: &str, sdl: String) {
     let assets = env::var("CARGO_MANIFEST_DIR")
         .map(PathBuf::from)
         .map(|f| {
-            let f = f.as_path().join(SCHEMA_URL);
+            let f = f.as_path().join(url);
             let dir = f.parent().expect("Failed to read assets dir");
             fs::create_dir_all(dir).expect("Failed to create assets dir");
 
@@ -30,12 +41,10 @@ pub fn dump_schema() -> Result<(), Box<dyn std::error::Error>> {
 
     File::create(assets)
         .and_then(|mut f| {
-            f.write_all(build_schema().finish().sdl().as_bytes())?;
+            f.write_all(sdl.as_bytes())?;
             f.sync_all()
         })
         .expect("Failed to write SDL schema to temporary file");
-
-    Ok(())
 }
 
 /// ensures that latest schema is always committed
@@ -44,3 +53,13 @@ fn is_latest_schema_committed() {
     let current_content = fs::read(SCHEMA_URL).unwrap();
     assert_eq!(current_content, build_schema().finish().sdl().as_bytes());
 }
+
+/// ensures that latest admin schema is always committed
+#[test]
+fn is_latest_admin_schema_committed() {
+    let current_content = fs::read(ADMIN_SCHEMA_URL).unwrap();
+    assert_eq!(
+        current_content,
+        build_admin_schema().finish().sdl().as_bytes()
+    );
+}